@@ -0,0 +1,59 @@
+//! Drives `HumanoidEnv` through `reset` and `step` with uniform random position targets:
+//! `cargo run --release --example random_policy -- [envs] [steps]`.
+use bevy_rapier_dfdx_humanoid::{
+    action::Action,
+    env::{EnvConfig, HumanoidEnv},
+    humanoid::HumanoidConfig,
+    observation::HUMANOID_DOF,
+    skeleton::{SkeletonDesc, HUMANOID_SKELETON_PATH},
+};
+use rand::Rng;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<usize> = std::env::args()
+        .skip(1)
+        .map(|a| a.parse().expect("envs and steps are numbers"))
+        .collect();
+    let num_envs = args.first().copied().unwrap_or(4);
+    let steps = args.get(1).copied().unwrap_or(1000);
+    let mut env = HumanoidEnv::new(
+        SkeletonDesc::load(HUMANOID_SKELETON_PATH)?,
+        HumanoidConfig::default(),
+        EnvConfig {
+            num_envs,
+            ..Default::default()
+        },
+    )?;
+    let mut rng = rand::thread_rng();
+    let observations = env.reset(0);
+    println!(
+        "{} envs, observations of {} floats",
+        observations.len(),
+        observations[0].len()
+    );
+    let mut returns = vec![0.; num_envs];
+    for _ in 0..steps {
+        let actions = (0..num_envs)
+            .map(|_| {
+                Action::Continuous(
+                    (0..HUMANOID_DOF)
+                        .map(|_| rng.gen_range(-1.0..1.0))
+                        .collect(),
+                )
+            })
+            .collect();
+        let (_, rewards, dones, infos) = env.step(actions);
+        for (index, info) in infos.iter().enumerate() {
+            returns[index] += rewards[index];
+            if dones[index] {
+                println!(
+                    "env {} episode ended: {:?} after {} steps, return {:.2}, terms {:?}",
+                    index, info.termination, info.steps, returns[index], info.reward_terms
+                );
+                returns[index] = 0.;
+            }
+        }
+    }
+    Ok(())
+}
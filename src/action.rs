@@ -0,0 +1,187 @@
+use bevy::prelude::*;
+use bevy_rapier3d::{prelude::*, rapier::prelude::JointAxis};
+
+use crate::{
+    env::{EnvStep, RandomizePose},
+    humanoid::{Humanoid, JointFrame, JointMotors},
+};
+
+/// Position target change of one discrete action by default, in radians.
+pub const DISCRETE_STEP: f32 = 0.05;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActionMode {
+    /// Continuous action rescaled from [-1, 1] into the joint limits as motor position targets.
+    Position,
+    /// Continuous action scaled by the motor `max_force` into torques applied with `ExternalForce`.
+    /// Motor stiffness and damping are switched off.
+    Torque,
+    /// Discrete action nudging one position target by `step` radians, see `Action::Discrete`.
+    Discrete { step: f32 },
+}
+
+impl ActionMode {
+    /// Mode of continuous policies, `Torque` or `Position`.
+    pub fn continuous(torque: bool) -> Self {
+        match torque {
+            true => ActionMode::Torque,
+            false => ActionMode::Position,
+        }
+    }
+}
+
+pub struct ActionConfig {
+    pub mode: ActionMode,
+}
+impl Default for ActionConfig {
+    fn default() -> Self {
+        Self {
+            mode: ActionMode::Position,
+        }
+    }
+}
+
+/// Policy output for a humanoid, one entry per free joint axis in `Humanoid::joints` order.
+#[derive(Component, Debug, Clone)]
+pub enum Action {
+    /// Values in [-1, 1], clamped.
+    Continuous(Vec<f32>),
+    /// 0 is a no-op, `2 * axis + 1` raises and `2 * axis + 2` lowers the target of an axis,
+    /// so it fits the `action: Int` column of the `Rb` table.
+    Discrete(i32),
+}
+impl Default for Action {
+    fn default() -> Self {
+        Action::Continuous(vec![])
+    }
+}
+
+pub fn get_discrete_actions(dof: usize) -> usize {
+    2 * dof + 1
+}
+
+/// Axis index and direction of the target change of an `Action::Discrete`, `None` for the no-op.
+pub fn decode_discrete(action: i32) -> Option<(usize, f32)> {
+    match action {
+        a if a <= 0 => None,
+        a => {
+            let a = a as usize;
+            Some(((a - 1) / 2, if a % 2 == 1 { 1. } else { -1. }))
+        }
+    }
+}
+
+/// Maps [-1, 1] onto the [min, max] range.
+pub fn rescale(a: f32, [min, max]: [f32; 2]) -> f32 {
+    min + (a.clamp(-1., 1.) + 1.) / 2. * (max - min)
+}
+
+/// Applies every changed `Action` to the joint motors. Humanoids not reset this frame act,
+/// held actions included, and count an env step in `EnvStep::steps`.
+pub fn action_system(
+    config: Res<ActionConfig>,
+    roots: Query<(&Humanoid, &Action), Changed<Action>>,
+    mut steps: Query<&mut EnvStep, (With<Humanoid>, Without<RandomizePose>)>,
+    mut joints: Query<(&mut JointMotors, &JointFrame)>,
+    transforms: Query<&Transform>,
+    mut forces: Query<&mut ExternalForce>,
+) {
+    for mut step in steps.iter_mut() {
+        step.steps += 1;
+    }
+    for (humanoid, action) in roots.iter() {
+        let mut axis_index: usize = 0;
+        if config.mode == ActionMode::Torque {
+            for part in humanoid.parts.iter() {
+                if let Ok(mut force) = forces.get_mut(*part) {
+                    *force = ExternalForce::default();
+                }
+            }
+        }
+        for joint in humanoid.joints.iter() {
+            let (mut motors, frame) = match joints.get_mut(*joint) {
+                Ok(j) => j,
+                Err(_) => continue,
+            };
+            for motor in motors.motors.iter_mut() {
+                match (config.mode, action) {
+                    (ActionMode::Position, Action::Continuous(a)) => {
+                        if let Some(a) = a.get(axis_index) {
+                            motor.target_pos = rescale(*a, motor.limits);
+                        }
+                    }
+                    (ActionMode::Torque, Action::Continuous(a)) => {
+                        motor.stiffness = 0.;
+                        motor.damping = 0.;
+                        let a = a.get(axis_index).map_or(0., |a| a.clamp(-1., 1.));
+                        let (direction, angular) = match motor.axis {
+                            JointAxis::X => (Vec3::X, false),
+                            JointAxis::Y => (Vec3::Y, false),
+                            JointAxis::Z => (Vec3::Z, false),
+                            JointAxis::AngX => (Vec3::X, true),
+                            JointAxis::AngY => (Vec3::Y, true),
+                            JointAxis::AngZ => (Vec3::Z, true),
+                        };
+                        if let Ok(parent) = transforms.get(frame.parent) {
+                            let effort = frame.rotation(parent) * direction * a * motor.max_force;
+                            for (part, sign) in [(*joint, 1.), (frame.parent, -1.)] {
+                                if let Ok(mut force) = forces.get_mut(part) {
+                                    match angular {
+                                        true => force.torque += sign * effort,
+                                        false => force.force += sign * effort,
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    (ActionMode::Discrete { step }, Action::Discrete(index)) => {
+                        if let Some((axis, sign)) = decode_discrete(*index) {
+                            if axis == axis_index {
+                                let [min, max] = motor.limits;
+                                motor.target_pos = (motor.target_pos + sign * step).clamp(min, max);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                axis_index += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discrete_actions_raise_then_lower_each_axis() {
+        assert_eq!(decode_discrete(0), None);
+        assert_eq!(decode_discrete(-3), None);
+        assert_eq!(decode_discrete(1), Some((0, 1.)));
+        assert_eq!(decode_discrete(2), Some((0, -1.)));
+        assert_eq!(decode_discrete(5), Some((2, 1.)));
+        let dof = 4;
+        let last = get_discrete_actions(dof) as i32 - 1;
+        assert_eq!(decode_discrete(last), Some((dof - 1, -1.)));
+        let decoded: Vec<_> = (1..=last).filter_map(decode_discrete).collect();
+        for axis in 0..dof {
+            assert!(decoded.contains(&(axis, 1.)) && decoded.contains(&(axis, -1.)));
+        }
+    }
+
+    #[test]
+    fn rescale_maps_onto_the_limits() {
+        assert_eq!(rescale(-1., [-0.5, 1.5]), -0.5);
+        assert_eq!(rescale(0., [-0.5, 1.5]), 0.5);
+        assert_eq!(rescale(1., [-0.5, 1.5]), 1.5);
+        assert_eq!(rescale(3., [-0.5, 1.5]), 1.5);
+        assert_eq!(rescale(-3., [-0.5, 1.5]), -0.5);
+    }
+
+    #[test]
+    fn continuous_mode_follows_torque() {
+        assert_eq!(ActionMode::continuous(true), ActionMode::Torque);
+        assert_eq!(ActionMode::continuous(false), ActionMode::Position);
+    }
+}
@@ -0,0 +1,155 @@
+use std::{error::Error, path::Path};
+
+use bevy::prelude::*;
+
+use crate::{
+    action::{Action, ActionConfig, ActionMode},
+    checkpoint::{reseed, resume_checkpoint, save_checkpoint, CheckpointConfig},
+    env::{EnvConfig, EnvIndex, EnvRng, EnvStep, EnvSystem, Observation},
+    replay::{ReplayBuffer, Transition},
+    termination::TerminationReason,
+};
+#[cfg(feature = "db")]
+use crate::{
+    db_client::{DbClientResource, RunRecord},
+    observation::HUMANOID_OBS,
+};
+
+/// Learner driving the humanoids, stepped once per frame by `agent_system`.
+/// dfdx tensors are not `Send`, agents live in non-send resources.
+pub trait Agent: 'static {
+    /// `--agent` name, recorded in checkpoints.
+    fn name(&self) -> &'static str;
+    /// Hyperparameters, `seed` included, recorded with the `Run` of a training process.
+    fn config(&self) -> serde_json::Value;
+    fn action_mode(&self) -> ActionMode;
+    /// One action per env, from the observations in `EnvIndex` order.
+    fn act(&mut self, observations: &[Observation]) -> Vec<Action>;
+    /// Like `act` without exploration noise, for evaluation.
+    fn act_deterministic(&mut self, observations: &[Observation]) -> Vec<Action>;
+    /// Transitions of the envs stepped since the last `act` with the index of their env,
+    /// agents learn here.
+    fn observe(&mut self, transitions: Vec<(usize, Transition)>);
+    /// Log-probability of the last action `act` chose for `env`, stored with its transition.
+    fn log_prob(&self, _env: usize) -> Option<f32> {
+        None
+    }
+    /// Off-policy agents expose their buffer, checkpointed with them and filled from the
+    /// `Rb` table on a fresh start.
+    fn replay_buffer(&mut self) -> Option<&mut ReplayBuffer> {
+        None
+    }
+    /// Writes every network and optimizer into `dir` and returns counters, schedules and the
+    /// RNG seed.
+    fn save(&mut self, dir: &Path) -> Result<serde_json::Value, Box<dyn Error>>;
+    fn load(&mut self, dir: &Path, state: serde_json::Value) -> Result<(), Box<dyn Error>>;
+}
+
+/// Sets the action mode of the agent and steps it after every env step.
+/// Resumes from `CheckpointConfig::resume` when set, records a `Run` when the db is enabled.
+/// The envs of a resumed run start new episodes from the seed saved with the checkpoint.
+pub fn add_agent<A: Agent>(app: &mut App, mut agent: A) {
+    let resume = app
+        .world
+        .get_resource::<CheckpointConfig>()
+        .and_then(|config| config.resume.clone());
+    if let Some(path) = resume {
+        let resumed = resume_checkpoint(&mut agent, &path).unwrap();
+        println!(
+            "resumed {} at step {} from {:?}",
+            agent.name(),
+            resumed.step,
+            path
+        );
+        app.world.resource_mut::<CheckpointConfig>().steps = resumed.step;
+        app.world
+            .get_resource_or_insert_with(EnvConfig::default)
+            .seed = resumed.env_seed;
+    }
+    #[cfg(feature = "db")]
+    if let Some(db) = app.world.get_resource::<DbClientResource>() {
+        let discrete = matches!(agent.action_mode(), ActionMode::Discrete { .. });
+        if let Some(buffer) = agent.replay_buffer().filter(|buffer| buffer.is_empty()) {
+            for transition in db.load_transitions(buffer.capacity, HUMANOID_OBS, discrete) {
+                buffer.push(transition);
+            }
+            println!("loaded {} transitions from the Rb table", buffer.len());
+        }
+        if let Some(run) = db.start_run(agent.name(), agent.config()) {
+            println!("recording run {}", run.id);
+            app.insert_resource(run);
+        }
+    }
+    app.insert_resource(ActionConfig {
+        mode: agent.action_mode(),
+    })
+    .insert_non_send_resource(agent)
+    .add_system_to_stage(
+        CoreStage::PostUpdate,
+        agent_system::<A>.after(EnvSystem::Termination),
+    );
+}
+
+/// Builds the transitions of the last step, stores them and the ended episodes and feeds them
+/// to the agent, then sets the next action of every humanoid and checkpoints the agent with
+/// a fresh seed of `EnvRng`. No transition links a terminated episode to the reset that follows.
+pub fn agent_system<A: Agent>(
+    mut agent: NonSendMut<A>,
+    checkpoint: Option<ResMut<CheckpointConfig>>,
+    mut env_rng: ResMut<EnvRng>,
+    mut last: Local<Vec<Option<(Observation, Action)>>>,
+    mut roots: Query<(&EnvIndex, &EnvStep, &mut Action)>,
+    #[cfg(feature = "db")] db: Option<Res<DbClientResource>>,
+    #[cfg(feature = "db")] run: Option<ResMut<RunRecord>>,
+) {
+    let mut envs: Vec<_> = roots.iter_mut().collect();
+    envs.sort_by_key(|(index, _, _)| **index);
+    last.resize(envs.len(), None);
+    let mut transitions = vec![];
+    for (index, step, _) in envs.iter() {
+        if let Some((state, action)) = last[index.0].take() {
+            let truncated = step.termination == Some(TerminationReason::Timeout);
+            transitions.push((
+                index.0,
+                Transition {
+                    state,
+                    action,
+                    reward: step.reward,
+                    next_state: step.observation.clone(),
+                    log_prob: agent.log_prob(index.0),
+                    done: step.done && !truncated,
+                    truncated,
+                },
+            ));
+        }
+    }
+    #[cfg(feature = "db")]
+    if let (Some(db), Some(mut run)) = (db, run) {
+        db.insert_transitions(&mut run, &transitions);
+        for (index, step, _) in envs.iter().filter(|(_, step, _)| step.done) {
+            db.end_episode(&mut run, index.0, step);
+        }
+    }
+    agent.observe(transitions);
+    let observations: Vec<Observation> = envs
+        .iter()
+        .map(|(_, step, _)| step.observation.clone())
+        .collect();
+    let actions = agent.act(&observations);
+    for ((index, step, action), next) in envs.iter_mut().zip(actions) {
+        if !step.done {
+            last[index.0] = Some((step.observation.clone(), next.clone()));
+        }
+        **action = next;
+    }
+    if let Some(mut config) = checkpoint {
+        config.steps += 1;
+        if config.every > 0 && config.steps % config.every == 0 {
+            let env_seed = reseed(&mut env_rng.0);
+            match save_checkpoint(&mut *agent, &config, env_seed) {
+                Ok(path) => println!("saved checkpoint {:?}", path),
+                Err(e) => eprintln!("failed to save checkpoint: {}", e),
+            }
+        }
+    }
+}
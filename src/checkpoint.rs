@@ -0,0 +1,294 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use dfdx::prelude::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::{agent::Agent, replay::ReplayBuffer};
+
+/// Bumped whenever the layout of a checkpoint changes, older checkpoints are refused.
+pub const CHECKPOINT_VERSION: u32 = 2;
+
+/// Checkpoints are directories `<dir>/<step>/` holding one `.npz` per network and per Adam
+/// moment, `state.json` and the replay buffer of off-policy agents in `replay.bin`.
+/// `<dir>/latest` names the last complete one.
+pub struct CheckpointConfig {
+    pub dir: PathBuf,
+    /// Agent steps between checkpoints, 0 disables saving.
+    pub every: usize,
+    /// Checkpoint directory, or a `dir` with a `latest` file, restored before training.
+    pub resume: Option<PathBuf>,
+    /// Agent steps so far, restored on resume.
+    pub steps: usize,
+}
+impl Default for CheckpointConfig {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from("checkpoints"),
+            every: 10_000,
+            resume: None,
+            steps: 0,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct StateFile {
+    version: u32,
+    agent: String,
+    step: usize,
+    /// Seed of the `ResetEvent` a resumed run starts from, see `EnvConfig::seed`.
+    env_seed: u64,
+    /// Counters, schedules and RNG seed of the agent, see `Agent::save`.
+    state: serde_json::Value,
+}
+
+/// Where a checkpoint left the training process.
+pub struct Resumed {
+    pub step: usize,
+    pub env_seed: u64,
+}
+
+/// Saves the agent into `<dir>/<step>/` and points `<dir>/latest` at it once complete.
+pub fn save_checkpoint<A: Agent>(
+    agent: &mut A,
+    config: &CheckpointConfig,
+    env_seed: u64,
+) -> Result<PathBuf, Box<dyn Error>> {
+    let path = config.dir.join(config.steps.to_string());
+    fs::create_dir_all(&path)?;
+    if let Some(buffer) = agent.replay_buffer() {
+        buffer.save(&path.join("replay.bin"))?;
+    }
+    let file = StateFile {
+        version: CHECKPOINT_VERSION,
+        agent: agent.name().to_string(),
+        step: config.steps,
+        env_seed,
+        state: agent.save(&path)?,
+    };
+    fs::write(
+        path.join("state.json"),
+        serde_json::to_string_pretty(&file)?,
+    )?;
+    fs::write(config.dir.join("latest"), config.steps.to_string())?;
+    Ok(path)
+}
+
+/// Restores the agent from a checkpoint directory, returns the agent step it was saved at.
+/// The replay buffer is left alone, evaluation has no use for it.
+pub fn load_checkpoint<A: Agent>(agent: &mut A, path: &Path) -> Result<usize, Box<dyn Error>> {
+    Ok(load_state(agent, &checkpoint_dir(path)?)?.step)
+}
+
+/// Restores the agent and its replay buffer to continue training.
+pub fn resume_checkpoint<A: Agent>(agent: &mut A, path: &Path) -> Result<Resumed, Box<dyn Error>> {
+    let path = checkpoint_dir(path)?;
+    let resumed = load_state(agent, &path)?;
+    if let Some(buffer) = agent.replay_buffer() {
+        let loaded = ReplayBuffer::load(&path.join("replay.bin"))?;
+        if loaded.capacity != buffer.capacity {
+            return Err(format!(
+                "replay buffer capacity {}, expected {}",
+                loaded.capacity, buffer.capacity
+            )
+            .into());
+        }
+        *buffer = loaded;
+    }
+    Ok(resumed)
+}
+
+/// `path` itself, or the checkpoint its `latest` file names.
+fn checkpoint_dir(path: &Path) -> Result<PathBuf, Box<dyn Error>> {
+    Ok(match path.join("latest").exists() {
+        true => path.join(fs::read_to_string(path.join("latest"))?.trim()),
+        false => path.to_path_buf(),
+    })
+}
+
+fn load_state<A: Agent>(agent: &mut A, path: &Path) -> Result<Resumed, Box<dyn Error>> {
+    let file: StateFile = serde_json::from_str(&fs::read_to_string(path.join("state.json"))?)?;
+    if file.version != CHECKPOINT_VERSION {
+        return Err(format!(
+            "checkpoint version {}, expected {}",
+            file.version, CHECKPOINT_VERSION
+        )
+        .into());
+    }
+    if file.agent != agent.name() {
+        return Err(format!("checkpoint of a {} agent, not {}", file.agent, agent.name()).into());
+    }
+    agent.load(path, file.state)?;
+    Ok(Resumed {
+        step: file.step,
+        env_seed: file.env_seed,
+    })
+}
+
+pub fn save_net<M: SaveToNpz>(dir: &Path, name: &str, net: &M) -> Result<(), Box<dyn Error>> {
+    net.save(dir.join(format!("{}.npz", name)))
+        .map_err(|e| format!("saving {}: {:?}", name, e).into())
+}
+
+pub fn load_net<M: LoadFromNpz>(dir: &Path, name: &str, net: &mut M) -> Result<(), Box<dyn Error>> {
+    net.load(dir.join(format!("{}.npz", name)))
+        .map_err(|e| format!("loading {}: {:?}", name, e).into())
+}
+
+/// `StdRng` state can't be written out, so the agent continues from a fresh seed drawn at every
+/// checkpoint and a resumed agent draws the same numbers as one that never stopped.
+/// The envs only restart from the `EnvRng` seed saved alongside: a resumed run begins new
+/// episodes from it, where the uninterrupted run carries on with the episodes it was in.
+pub fn reseed(rng: &mut StdRng) -> u64 {
+    let seed = rng.gen();
+    *rng = StdRng::seed_from_u64(seed);
+    seed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        action::Action,
+        dqn::{DqnAgent, DqnConfig, DQN_ACTIONS},
+        observation::HUMANOID_OBS,
+        replay::Transition,
+    };
+
+    fn dqn(seed: u64) -> DqnAgent {
+        DqnAgent::new(DqnConfig {
+            replay_capacity: 10,
+            seed,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn resume_restores_the_training_state() {
+        let dir = std::env::temp_dir().join(format!("checkpoint_resume_{}", std::process::id()));
+        let mut agent = dqn(1);
+        agent.env_steps = 5;
+        agent.train_steps = 3;
+        agent.optimizer.t = 3;
+        agent.optimizer.moment2.2.bias = Tensor1D::new([0.5; DQN_ACTIONS]);
+        for reward in 0..3 {
+            agent.replay.push(Transition {
+                state: vec![reward as f32; HUMANOID_OBS],
+                action: Action::Discrete(reward),
+                reward: reward as f32,
+                next_state: vec![0.; HUMANOID_OBS],
+                log_prob: None,
+                done: reward == 2,
+                truncated: false,
+            });
+        }
+        let config = CheckpointConfig {
+            dir: dir.clone(),
+            steps: 42,
+            ..Default::default()
+        };
+        let path = save_checkpoint(&mut agent, &config, 9).unwrap();
+        assert_eq!(path, dir.join("42"));
+        assert_eq!(fs::read_to_string(dir.join("latest")).unwrap(), "42");
+
+        let mut resumed = dqn(2);
+        let Resumed { step, env_seed } = resume_checkpoint(&mut resumed, &dir).unwrap();
+        assert_eq!((step, env_seed), (42, 9));
+        assert_eq!(resumed.q_net.2.weight.data(), agent.q_net.2.weight.data());
+        assert_eq!(
+            resumed.target_q_net.0 .0.bias.data(),
+            agent.target_q_net.0 .0.bias.data()
+        );
+        assert_eq!(resumed.optimizer.t, 3);
+        assert_eq!(resumed.optimizer.moment2.2.bias.data(), &[0.5; DQN_ACTIONS]);
+        assert_eq!((resumed.env_steps, resumed.train_steps), (5, 3));
+        assert_eq!(resumed.replay.len(), 3);
+        // both continue from the seed drawn at the checkpoint
+        assert_eq!(resumed.rng.gen::<u64>(), agent.rng.gen::<u64>());
+
+        // evaluation leaves the replay buffer alone
+        let mut evaluated = dqn(3);
+        assert_eq!(load_checkpoint(&mut evaluated, &path).unwrap(), 42);
+        assert!(evaluated.replay.is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn resumed_agents_continue_like_uninterrupted_ones() {
+        let dir = std::env::temp_dir().join(format!("checkpoint_same_{}", std::process::id()));
+        let new_agent = |seed| {
+            DqnAgent::new(DqnConfig {
+                replay_capacity: 10,
+                learning_starts: 2,
+                epsilon_start: 0.5,
+                epsilon_end: 0.5,
+                seed,
+                ..Default::default()
+            })
+        };
+        let transition = |step: i32| Transition {
+            state: vec![step as f32 / 10.; HUMANOID_OBS],
+            action: Action::Discrete(step),
+            reward: 1.,
+            next_state: vec![(step + 1) as f32 / 10.; HUMANOID_OBS],
+            log_prob: None,
+            done: false,
+            truncated: false,
+        };
+        // greedy and random actions alike, as indices
+        let act = |agent: &mut DqnAgent, step: i32| match &agent.act(&[transition(step).state])[0] {
+            Action::Discrete(action) => *action,
+            other => panic!("expected a discrete action, got {:?}", other),
+        };
+        let mut uninterrupted = new_agent(1);
+        for step in 0..3 {
+            act(&mut uninterrupted, step);
+            uninterrupted.observe(vec![(0, transition(step))]);
+        }
+        let config = CheckpointConfig {
+            dir: dir.clone(),
+            steps: 3,
+            ..Default::default()
+        };
+        save_checkpoint(&mut uninterrupted, &config, 0).unwrap();
+        let mut resumed = new_agent(2);
+        resume_checkpoint(&mut resumed, &dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        for step in 3..8 {
+            assert_eq!(act(&mut resumed, step), act(&mut uninterrupted, step));
+            resumed.observe(vec![(0, transition(step))]);
+            uninterrupted.observe(vec![(0, transition(step))]);
+        }
+        assert_eq!(resumed.train_steps, uninterrupted.train_steps);
+        assert_eq!(
+            resumed.q_net.0 .0.weight.data(),
+            uninterrupted.q_net.0 .0.weight.data()
+        );
+        assert_eq!(
+            resumed.q_net.2.bias.data(),
+            uninterrupted.q_net.2.bias.data()
+        );
+    }
+
+    #[test]
+    fn refuses_other_versions() {
+        let dir = std::env::temp_dir().join(format!("checkpoint_version_{}", std::process::id()));
+        let config = CheckpointConfig {
+            dir: dir.clone(),
+            ..Default::default()
+        };
+        let path = save_checkpoint(&mut dqn(1), &config, 0).unwrap();
+        let state = fs::read_to_string(path.join("state.json")).unwrap();
+        let mut state: serde_json::Value = serde_json::from_str(&state).unwrap();
+        state["version"] = (CHECKPOINT_VERSION - 1).into();
+        fs::write(path.join("state.json"), state.to_string()).unwrap();
+        assert!(load_checkpoint(&mut dqn(1), &dir).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
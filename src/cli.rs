@@ -0,0 +1,99 @@
+use crate::{
+    action::{ActionMode, DISCRETE_STEP},
+    humanoid::JointMode,
+    observation::Orientation,
+    skeleton::HUMANOID_SKELETON_PATH,
+};
+
+/// `[skeleton path] [--multibody] [--self-collision] [--headless] [--reward=<path>] [--envs=<n>] [--agent=dqn|ppo|sac|td3|ddpg]`
+/// `[--orientation=gravity|quaternion] [--action=position|torque|discrete]`
+/// `[--checkpoint-dir=<dir>] [--checkpoint-every=<steps>] [--resume=<dir>] [--eval=<dir>]`
+/// `[--runs] [--delete-run=<id>]`
+pub struct Args {
+    /// JSON, MJCF (`.xml`) or URDF (`.urdf`) skeleton.
+    pub skeleton_path: String,
+    pub joint_mode: JointMode,
+    pub self_collision: bool,
+    /// Simulation only, without window, renderer or frame pacing.
+    pub headless: bool,
+    /// JSON `RewardConfig`, the default reward when absent.
+    pub reward_path: Option<String>,
+    /// Humanoids simulated side by side.
+    pub num_envs: usize,
+    /// Torso orientation in the observation, agents are sized for `Orientation::Gravity`.
+    pub orientation: Orientation,
+    /// How actions drive the joints, the mode of the `--agent` when absent.
+    /// Discrete actions suit DQN, continuous ones every other agent.
+    pub action: Option<ActionMode>,
+    /// Learner driving the humanoids, they hold still without one.
+    pub agent: Option<String>,
+    pub checkpoint_dir: Option<String>,
+    /// Agent steps between checkpoints, 0 disables them.
+    pub checkpoint_every: Option<usize>,
+    /// Checkpoint to continue training from.
+    pub resume: Option<String>,
+    /// Checkpoint, or directory of checkpoints, to watch the `--agent` policy of.
+    /// Requires `agent`.
+    pub eval: Option<String>,
+    /// Print every recorded run with its episode statistics, then exit.
+    pub runs: bool,
+    /// Delete a recorded run with its episodes and transitions, then exit.
+    pub delete_run: Option<String>,
+}
+impl Args {
+    /// Panics on malformed values and on `--eval` without an `--agent` to watch.
+    pub fn parse() -> Self {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        let flag = |name: &str| args.iter().any(|a| a == name);
+        let value = |name: &str| {
+            args.iter()
+                .find_map(|a| a.strip_prefix(name)?.strip_prefix('='))
+                .map(String::from)
+        };
+        let parsed = Self {
+            skeleton_path: args
+                .iter()
+                .find(|a| !a.starts_with("--"))
+                .cloned()
+                .unwrap_or_else(|| HUMANOID_SKELETON_PATH.to_string()),
+            joint_mode: match flag("--multibody") {
+                true => JointMode::Multibody,
+                false => JointMode::Impulse,
+            },
+            self_collision: flag("--self-collision"),
+            headless: flag("--headless"),
+            reward_path: value("--reward"),
+            action: value("--action").map(|a| match a.as_str() {
+                "position" => ActionMode::Position,
+                "torque" => ActionMode::Torque,
+                "discrete" => ActionMode::Discrete {
+                    step: DISCRETE_STEP,
+                },
+                other => panic!("unknown action mode {}", other),
+            }),
+            agent: value("--agent"),
+            checkpoint_dir: value("--checkpoint-dir"),
+            checkpoint_every: value("--checkpoint-every")
+                .map(|n| n.parse().expect("--checkpoint-every takes a number")),
+            resume: value("--resume"),
+            eval: value("--eval"),
+            runs: flag("--runs"),
+            delete_run: value("--delete-run"),
+            num_envs: value("--envs").map_or(1, |n| n.parse().expect("--envs takes a number")),
+            orientation: match value("--orientation").as_deref() {
+                None | Some("gravity") => Orientation::Gravity,
+                Some("quaternion") => Orientation::Quaternion,
+                Some(other) => panic!("unknown orientation {}", other),
+            },
+        };
+        if parsed.eval.is_some() && parsed.agent.is_none() {
+            panic!("--eval needs the --agent whose checkpoints it watches");
+        }
+        parsed
+    }
+
+    /// Continuous agents drive the joints with torques.
+    pub fn torque(&self) -> bool {
+        self.action == Some(ActionMode::Torque)
+    }
+}
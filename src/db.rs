@@ -1,7 +1,7 @@
 // Code generated by Prisma Client Rust. DO NOT EDIT
 
 #![allow(warnings, unused)]
-static DATAMODEL_STR : & 'static str = "datasource db {\n  provider = \"sqlite\"\n  url      = \"file:../dev.db\"\n}\n\ngenerator client {\n  provider = \"cargo prisma\"\n  output   = \"../src/db.rs\"\n}\n\nmodel Rb {\n  id         String   @id @default(cuid())\n  created_at DateTime @default(now())\n  state      String\n  action     Int\n  reward     Float\n  next_state String\n  done       Boolean\n}\n" ;
+static DATAMODEL_STR : & 'static str = "datasource db {\n  provider = \"sqlite\"\n  url      = \"file:../dev.db\"\n}\n\ngenerator client {\n  provider = \"cargo prisma\"\n  output   = \"../src/db.rs\"\n}\n\nmodel Rb {\n  id         String   @id @default(cuid())\n  created_at DateTime @default(now())\n  /// Floats per observation, `state` and `next_state` are little-endian f32 blobs\n  obs_dim    Int\n  state      Bytes\n  /// Discrete action index, null for continuous actions\n  action     Int?\n  /// Continuous action as a little-endian f32 blob, null for discrete actions\n  action_vec Bytes?\n  /// Log-probability of the action under the behavior policy, when it has one\n  log_prob   Float?\n  reward     Float\n  next_state Bytes\n  done       Boolean\n  run        Run?     @relation(fields: [run_id], references: [id], onDelete: Cascade)\n  run_id     String?\n  episode    Episode? @relation(fields: [episode_id], references: [id], onDelete: Cascade)\n  episode_id String?\n}\n\n/// One training process, deleting it deletes its episodes and transitions\nmodel Run {\n  id          String    @id @default(cuid())\n  started_at  DateTime  @default(now())\n  /// Agent name, agent config and command line as JSON\n  config      String\n  git_hash    String\n  seed        BigInt\n  episodes    Episode[]\n  transitions Rb[]\n}\n\nmodel Episode {\n  id          String  @id @default(cuid())\n  run         Run     @relation(fields: [run_id], references: [id], onDelete: Cascade)\n  run_id      String\n  /// Order in which the episodes of the run started\n  index       Int\n  /// Undiscounted return, 0 until the episode ends\n  reward      Float\n  length      Int\n  /// `TerminationReason`, null while the episode runs\n  termination String?\n  transitions Rb[]\n}\n" ;
 static DATABASE_STR: &'static str = "sqlite";
 pub async fn new_client() -> Result<PrismaClient, ::prisma_client_rust::NewClientError> {
     let config = ::prisma_client_rust::datamodel::parse_configuration(DATAMODEL_STR)?.subject;
@@ -227,50 +227,104 @@ pub mod rb {
             }
         }
     }
-    pub mod state {
+    pub mod obs_dim {
         use super::super::*;
         use super::_prisma::*;
         use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
-        pub fn set<T: From<Set>>(value: String) -> T {
+        pub fn set<T: From<Set>>(value: i32) -> T {
             Set(value).into()
         }
-        pub fn equals(value: String) -> WhereParam {
-            WhereParam::StateEquals(value).into()
+        pub fn equals(value: i32) -> WhereParam {
+            WhereParam::ObsDimEquals(value).into()
         }
         pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
-            OrderByParam::State(direction)
+            OrderByParam::ObsDim(direction)
         }
-        pub fn in_vec(value: Vec<String>) -> WhereParam {
-            WhereParam::StateInVec(value)
+        pub fn in_vec(value: Vec<i32>) -> WhereParam {
+            WhereParam::ObsDimInVec(value)
         }
-        pub fn not_in_vec(value: Vec<String>) -> WhereParam {
-            WhereParam::StateNotInVec(value)
+        pub fn not_in_vec(value: Vec<i32>) -> WhereParam {
+            WhereParam::ObsDimNotInVec(value)
         }
-        pub fn lt(value: String) -> WhereParam {
-            WhereParam::StateLt(value)
+        pub fn lt(value: i32) -> WhereParam {
+            WhereParam::ObsDimLt(value)
         }
-        pub fn lte(value: String) -> WhereParam {
-            WhereParam::StateLte(value)
+        pub fn lte(value: i32) -> WhereParam {
+            WhereParam::ObsDimLte(value)
         }
-        pub fn gt(value: String) -> WhereParam {
-            WhereParam::StateGt(value)
+        pub fn gt(value: i32) -> WhereParam {
+            WhereParam::ObsDimGt(value)
         }
-        pub fn gte(value: String) -> WhereParam {
-            WhereParam::StateGte(value)
+        pub fn gte(value: i32) -> WhereParam {
+            WhereParam::ObsDimGte(value)
         }
-        pub fn contains(value: String) -> WhereParam {
-            WhereParam::StateContains(value)
+        pub fn not(value: i32) -> WhereParam {
+            WhereParam::ObsDimNot(value)
         }
-        pub fn starts_with(value: String) -> WhereParam {
-            WhereParam::StateStartsWith(value)
+        pub fn increment(value: i32) -> SetParam {
+            SetParam::IncrementObsDim(value)
         }
-        pub fn ends_with(value: String) -> WhereParam {
-            WhereParam::StateEndsWith(value)
+        pub fn decrement(value: i32) -> SetParam {
+            SetParam::DecrementObsDim(value)
         }
-        pub fn not(value: String) -> WhereParam {
+        pub fn multiply(value: i32) -> SetParam {
+            SetParam::MultiplyObsDim(value)
+        }
+        pub fn divide(value: i32) -> SetParam {
+            SetParam::DivideObsDim(value)
+        }
+        pub struct Set(pub i32);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetObsDim(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::ObsDim(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("obs_dim").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::ObsDim(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("obs_dim").build()
+            }
+        }
+    }
+    pub mod state {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: Vec<u8>) -> T {
+            Set(value).into()
+        }
+        pub fn equals(value: Vec<u8>) -> WhereParam {
+            WhereParam::StateEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::State(direction)
+        }
+        pub fn in_vec(value: Vec<Vec<u8>>) -> WhereParam {
+            WhereParam::StateInVec(value)
+        }
+        pub fn not_in_vec(value: Vec<Vec<u8>>) -> WhereParam {
+            WhereParam::StateNotInVec(value)
+        }
+        pub fn not(value: Vec<u8>) -> WhereParam {
             WhereParam::StateNot(value)
         }
-        pub struct Set(pub String);
+        pub struct Set(pub Vec<u8>);
         impl From<Set> for SetParam {
             fn from(value: Set) -> Self {
                 Self::SetState(value.0)
@@ -303,10 +357,10 @@ pub mod rb {
         use super::super::*;
         use super::_prisma::*;
         use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
-        pub fn set<T: From<Set>>(value: i32) -> T {
+        pub fn set<T: From<Set>>(value: Option<i32>) -> T {
             Set(value).into()
         }
-        pub fn equals(value: i32) -> WhereParam {
+        pub fn equals(value: Option<i32>) -> WhereParam {
             WhereParam::ActionEquals(value).into()
         }
         pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
@@ -330,7 +384,7 @@ pub mod rb {
         pub fn gte(value: i32) -> WhereParam {
             WhereParam::ActionGte(value)
         }
-        pub fn not(value: i32) -> WhereParam {
+        pub fn not(value: Option<i32>) -> WhereParam {
             WhereParam::ActionNot(value)
         }
         pub fn increment(value: i32) -> SetParam {
@@ -345,7 +399,7 @@ pub mod rb {
         pub fn divide(value: i32) -> SetParam {
             SetParam::DivideAction(value)
         }
-        pub struct Set(pub i32);
+        pub struct Set(pub Option<i32>);
         impl From<Set> for SetParam {
             fn from(value: Set) -> Self {
                 Self::SetAction(value.0)
@@ -359,284 +413,3701 @@ pub mod rb {
         }
         impl Include {
             pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-                ::prisma_client_rust::Selection::builder("action").build()
+                ::prisma_client_rust::Selection::builder("action").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Action(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("action").build()
+            }
+        }
+    }
+    pub mod action_vec {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: Option<Vec<u8>>) -> T {
+            Set(value).into()
+        }
+        pub fn equals(value: Option<Vec<u8>>) -> WhereParam {
+            WhereParam::ActionVecEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::ActionVec(direction)
+        }
+        pub fn in_vec(value: Vec<Vec<u8>>) -> WhereParam {
+            WhereParam::ActionVecInVec(value)
+        }
+        pub fn not_in_vec(value: Vec<Vec<u8>>) -> WhereParam {
+            WhereParam::ActionVecNotInVec(value)
+        }
+        pub fn not(value: Option<Vec<u8>>) -> WhereParam {
+            WhereParam::ActionVecNot(value)
+        }
+        pub struct Set(pub Option<Vec<u8>>);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetActionVec(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::ActionVec(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("action_vec").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::ActionVec(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("action_vec").build()
+            }
+        }
+    }
+    pub mod log_prob {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: Option<f64>) -> T {
+            Set(value).into()
+        }
+        pub fn equals(value: Option<f64>) -> WhereParam {
+            WhereParam::LogProbEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::LogProb(direction)
+        }
+        pub fn in_vec(value: Vec<f64>) -> WhereParam {
+            WhereParam::LogProbInVec(value)
+        }
+        pub fn not_in_vec(value: Vec<f64>) -> WhereParam {
+            WhereParam::LogProbNotInVec(value)
+        }
+        pub fn lt(value: f64) -> WhereParam {
+            WhereParam::LogProbLt(value)
+        }
+        pub fn lte(value: f64) -> WhereParam {
+            WhereParam::LogProbLte(value)
+        }
+        pub fn gt(value: f64) -> WhereParam {
+            WhereParam::LogProbGt(value)
+        }
+        pub fn gte(value: f64) -> WhereParam {
+            WhereParam::LogProbGte(value)
+        }
+        pub fn not(value: Option<f64>) -> WhereParam {
+            WhereParam::LogProbNot(value)
+        }
+        pub fn increment(value: f64) -> SetParam {
+            SetParam::IncrementLogProb(value)
+        }
+        pub fn decrement(value: f64) -> SetParam {
+            SetParam::DecrementLogProb(value)
+        }
+        pub fn multiply(value: f64) -> SetParam {
+            SetParam::MultiplyLogProb(value)
+        }
+        pub fn divide(value: f64) -> SetParam {
+            SetParam::DivideLogProb(value)
+        }
+        pub struct Set(pub Option<f64>);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetLogProb(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::LogProb(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("log_prob").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::LogProb(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("log_prob").build()
+            }
+        }
+    }
+    pub mod reward {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: f64) -> T {
+            Set(value).into()
+        }
+        pub fn equals(value: f64) -> WhereParam {
+            WhereParam::RewardEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::Reward(direction)
+        }
+        pub fn in_vec(value: Vec<f64>) -> WhereParam {
+            WhereParam::RewardInVec(value)
+        }
+        pub fn not_in_vec(value: Vec<f64>) -> WhereParam {
+            WhereParam::RewardNotInVec(value)
+        }
+        pub fn lt(value: f64) -> WhereParam {
+            WhereParam::RewardLt(value)
+        }
+        pub fn lte(value: f64) -> WhereParam {
+            WhereParam::RewardLte(value)
+        }
+        pub fn gt(value: f64) -> WhereParam {
+            WhereParam::RewardGt(value)
+        }
+        pub fn gte(value: f64) -> WhereParam {
+            WhereParam::RewardGte(value)
+        }
+        pub fn not(value: f64) -> WhereParam {
+            WhereParam::RewardNot(value)
+        }
+        pub fn increment(value: f64) -> SetParam {
+            SetParam::IncrementReward(value)
+        }
+        pub fn decrement(value: f64) -> SetParam {
+            SetParam::DecrementReward(value)
+        }
+        pub fn multiply(value: f64) -> SetParam {
+            SetParam::MultiplyReward(value)
+        }
+        pub fn divide(value: f64) -> SetParam {
+            SetParam::DivideReward(value)
+        }
+        pub struct Set(pub f64);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetReward(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Reward(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("reward").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Reward(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("reward").build()
+            }
+        }
+    }
+    pub mod next_state {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: Vec<u8>) -> T {
+            Set(value).into()
+        }
+        pub fn equals(value: Vec<u8>) -> WhereParam {
+            WhereParam::NextStateEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::NextState(direction)
+        }
+        pub fn in_vec(value: Vec<Vec<u8>>) -> WhereParam {
+            WhereParam::NextStateInVec(value)
+        }
+        pub fn not_in_vec(value: Vec<Vec<u8>>) -> WhereParam {
+            WhereParam::NextStateNotInVec(value)
+        }
+        pub fn not(value: Vec<u8>) -> WhereParam {
+            WhereParam::NextStateNot(value)
+        }
+        pub struct Set(pub Vec<u8>);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetNextState(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::NextState(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("next_state").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::NextState(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("next_state").build()
+            }
+        }
+    }
+    pub mod done {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: bool) -> T {
+            Set(value).into()
+        }
+        pub fn equals(value: bool) -> WhereParam {
+            WhereParam::DoneEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::Done(direction)
+        }
+        pub struct Set(pub bool);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetDone(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Done(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("done").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Done(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("done").build()
+            }
+        }
+    }
+    pub mod run {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn is(value: Vec<super::super::run::WhereParam>) -> WhereParam {
+            WhereParam::RunIs(value)
+        }
+        pub fn is_not(value: Vec<super::super::run::WhereParam>) -> WhereParam {
+            WhereParam::RunIsNot(value)
+        }
+        pub struct Fetch(pub super::super::run::UniqueArgs);
+        impl Fetch {
+            pub fn with(mut self, params: impl Into<super::super::run::WithParam>) -> Self {
+                self.0 = self.0.with(params.into());
+                self
+            }
+        }
+        impl From<Fetch> for WithParam {
+            fn from(fetch: Fetch) -> Self {
+                WithParam::Run(fetch.0)
+            }
+        }
+        pub fn fetch() -> Fetch {
+            Fetch(super::super::run::UniqueArgs::new())
+        }
+        pub fn connect<T: From<Connect>>(value: super::super::run::UniqueWhereParam) -> T {
+            Connect(value).into()
+        }
+        pub struct Connect(pub super::super::run::UniqueWhereParam);
+        impl From<Connect> for SetParam {
+            fn from(value: Connect) -> Self {
+                Self::ConnectRun(value.0)
+            }
+        }
+        pub fn disconnect() -> SetParam {
+            SetParam::DisconnectRun
+        }
+        pub enum Include {
+            Select(Vec<super::super::run::SelectParam>),
+            Include(Vec<super::super::run::IncludeParam>),
+            Fetch,
+        }
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Run(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                let mut builder = ::prisma_client_rust::Selection::builder("run");
+                let selections = match self {
+                    Self::Select(selections) => {
+                        selections.into_iter().map(|s| s.to_selection()).collect()
+                    }
+                    Self::Include(selections) => {
+                        let mut nested = super::super::run::_outputs();
+                        nested.extend(selections.into_iter().map(|s| s.to_selection()));
+                        nested
+                    }
+                    Self::Fetch => super::super::run::_outputs(),
+                };
+                builder.nested_selections(selections);
+                builder.build()
+            }
+        }
+        pub enum Select {
+            Select(Vec<super::super::run::SelectParam>),
+            Include(Vec<super::super::run::IncludeParam>),
+            Fetch,
+        }
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Run(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                let mut builder = ::prisma_client_rust::Selection::builder("run");
+                let selections = match self {
+                    Self::Select(selections) => {
+                        selections.into_iter().map(|s| s.to_selection()).collect()
+                    }
+                    Self::Include(selections) => {
+                        let mut nested = super::super::run::_outputs();
+                        nested.extend(selections.into_iter().map(|s| s.to_selection()));
+                        nested
+                    }
+                    Self::Fetch => super::super::run::_outputs(),
+                };
+                builder.nested_selections(selections);
+                builder.build()
+            }
+        }
+    }
+    pub mod run_id {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: Option<String>) -> T {
+            Set(value).into()
+        }
+        pub fn equals(value: Option<String>) -> WhereParam {
+            WhereParam::RunIdEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::RunId(direction)
+        }
+        pub fn in_vec(value: Vec<String>) -> WhereParam {
+            WhereParam::RunIdInVec(value)
+        }
+        pub fn not_in_vec(value: Vec<String>) -> WhereParam {
+            WhereParam::RunIdNotInVec(value)
+        }
+        pub fn lt(value: String) -> WhereParam {
+            WhereParam::RunIdLt(value)
+        }
+        pub fn lte(value: String) -> WhereParam {
+            WhereParam::RunIdLte(value)
+        }
+        pub fn gt(value: String) -> WhereParam {
+            WhereParam::RunIdGt(value)
+        }
+        pub fn gte(value: String) -> WhereParam {
+            WhereParam::RunIdGte(value)
+        }
+        pub fn contains(value: String) -> WhereParam {
+            WhereParam::RunIdContains(value)
+        }
+        pub fn starts_with(value: String) -> WhereParam {
+            WhereParam::RunIdStartsWith(value)
+        }
+        pub fn ends_with(value: String) -> WhereParam {
+            WhereParam::RunIdEndsWith(value)
+        }
+        pub fn not(value: Option<String>) -> WhereParam {
+            WhereParam::RunIdNot(value)
+        }
+        pub struct Set(pub Option<String>);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetRunId(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::RunId(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("run_id").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::RunId(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("run_id").build()
+            }
+        }
+    }
+    pub mod episode {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn is(value: Vec<super::super::episode::WhereParam>) -> WhereParam {
+            WhereParam::EpisodeIs(value)
+        }
+        pub fn is_not(value: Vec<super::super::episode::WhereParam>) -> WhereParam {
+            WhereParam::EpisodeIsNot(value)
+        }
+        pub struct Fetch(pub super::super::episode::UniqueArgs);
+        impl Fetch {
+            pub fn with(mut self, params: impl Into<super::super::episode::WithParam>) -> Self {
+                self.0 = self.0.with(params.into());
+                self
+            }
+        }
+        impl From<Fetch> for WithParam {
+            fn from(fetch: Fetch) -> Self {
+                WithParam::Episode(fetch.0)
+            }
+        }
+        pub fn fetch() -> Fetch {
+            Fetch(super::super::episode::UniqueArgs::new())
+        }
+        pub fn connect<T: From<Connect>>(value: super::super::episode::UniqueWhereParam) -> T {
+            Connect(value).into()
+        }
+        pub struct Connect(pub super::super::episode::UniqueWhereParam);
+        impl From<Connect> for SetParam {
+            fn from(value: Connect) -> Self {
+                Self::ConnectEpisode(value.0)
+            }
+        }
+        pub fn disconnect() -> SetParam {
+            SetParam::DisconnectEpisode
+        }
+        pub enum Include {
+            Select(Vec<super::super::episode::SelectParam>),
+            Include(Vec<super::super::episode::IncludeParam>),
+            Fetch,
+        }
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Episode(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                let mut builder = ::prisma_client_rust::Selection::builder("episode");
+                let selections = match self {
+                    Self::Select(selections) => {
+                        selections.into_iter().map(|s| s.to_selection()).collect()
+                    }
+                    Self::Include(selections) => {
+                        let mut nested = super::super::episode::_outputs();
+                        nested.extend(selections.into_iter().map(|s| s.to_selection()));
+                        nested
+                    }
+                    Self::Fetch => super::super::episode::_outputs(),
+                };
+                builder.nested_selections(selections);
+                builder.build()
+            }
+        }
+        pub enum Select {
+            Select(Vec<super::super::episode::SelectParam>),
+            Include(Vec<super::super::episode::IncludeParam>),
+            Fetch,
+        }
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Episode(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                let mut builder = ::prisma_client_rust::Selection::builder("episode");
+                let selections = match self {
+                    Self::Select(selections) => {
+                        selections.into_iter().map(|s| s.to_selection()).collect()
+                    }
+                    Self::Include(selections) => {
+                        let mut nested = super::super::episode::_outputs();
+                        nested.extend(selections.into_iter().map(|s| s.to_selection()));
+                        nested
+                    }
+                    Self::Fetch => super::super::episode::_outputs(),
+                };
+                builder.nested_selections(selections);
+                builder.build()
+            }
+        }
+    }
+    pub mod episode_id {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: Option<String>) -> T {
+            Set(value).into()
+        }
+        pub fn equals(value: Option<String>) -> WhereParam {
+            WhereParam::EpisodeIdEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::EpisodeId(direction)
+        }
+        pub fn in_vec(value: Vec<String>) -> WhereParam {
+            WhereParam::EpisodeIdInVec(value)
+        }
+        pub fn not_in_vec(value: Vec<String>) -> WhereParam {
+            WhereParam::EpisodeIdNotInVec(value)
+        }
+        pub fn lt(value: String) -> WhereParam {
+            WhereParam::EpisodeIdLt(value)
+        }
+        pub fn lte(value: String) -> WhereParam {
+            WhereParam::EpisodeIdLte(value)
+        }
+        pub fn gt(value: String) -> WhereParam {
+            WhereParam::EpisodeIdGt(value)
+        }
+        pub fn gte(value: String) -> WhereParam {
+            WhereParam::EpisodeIdGte(value)
+        }
+        pub fn contains(value: String) -> WhereParam {
+            WhereParam::EpisodeIdContains(value)
+        }
+        pub fn starts_with(value: String) -> WhereParam {
+            WhereParam::EpisodeIdStartsWith(value)
+        }
+        pub fn ends_with(value: String) -> WhereParam {
+            WhereParam::EpisodeIdEndsWith(value)
+        }
+        pub fn not(value: Option<String>) -> WhereParam {
+            WhereParam::EpisodeIdNot(value)
+        }
+        pub struct Set(pub Option<String>);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetEpisodeId(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::EpisodeId(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("episode_id").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::EpisodeId(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("episode_id").build()
+            }
+        }
+    }
+    pub fn _outputs() -> Vec<::prisma_client_rust::Selection> {
+        [
+            "id",
+            "created_at",
+            "obs_dim",
+            "state",
+            "action",
+            "action_vec",
+            "log_prob",
+            "reward",
+            "next_state",
+            "done",
+            "run_id",
+            "episode_id",
+        ]
+        .into_iter()
+        .map(|o| {
+            let builder = ::prisma_client_rust::Selection::builder(o);
+            builder.build()
+        })
+        .collect()
+    }
+    pub fn create(
+        obs_dim: i32,
+        state: Vec<u8>,
+        reward: f64,
+        next_state: Vec<u8>,
+        done: bool,
+        _params: Vec<SetParam>,
+    ) -> (i32, Vec<u8>, f64, Vec<u8>, bool, Vec<SetParam>) {
+        (obs_dim, state, reward, next_state, done, _params)
+    }
+    #[macro_export]
+    macro_rules ! _select_rb { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: rb :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: rb :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn select ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Select { Select ($ crate :: prisma :: rb :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: rb :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: rb :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Select ($ crate :: prisma :: rb :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { id , created_at , obs_dim , state , action , action_vec , log_prob , reward , next_state , done , run_id , episode_id } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { $ (pub $ field : $ crate :: prisma :: rb :: select ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (created_at) , stringify ! (obs_dim) , stringify ! (state) , stringify ! (action) , stringify ! (action_vec) , stringify ! (log_prob) , stringify ! (reward) , stringify ! (next_state) , stringify ! (done) , stringify ! (run_id) , stringify ! (episode_id)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: rb :: select ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: rb :: select ! (@ field_serde_name ; $ field) , ", ") , +)) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: rb :: select ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * while let Some (key) = map . next_key () ? { match key { $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: select ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: select ! (@ field_serde_name ; $ field))) ? ;) * Ok (Data { $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "created_at" , "obs_dim" , "state" , "action" , "action_vec" , "log_prob" , "reward" , "next_state" , "done" , "run_id" , "episode_id"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: rb :: select ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; id) => { String } ; (@ field_type ; created_at) => { :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > } ; (@ field_type ; obs_dim) => { i32 } ; (@ field_type ; state) => { Vec < u8 > } ; (@ field_type ; action) => { Option < i32 > } ; (@ field_type ; action_vec) => { Option < Vec < u8 > > } ; (@ field_type ; log_prob) => { Option < f64 > } ; (@ field_type ; reward) => { f64 } ; (@ field_type ; next_state) => { Vec < u8 > } ; (@ field_type ; done) => { bool } ; (@ field_type ; run_id) => { Option < String > } ; (@ field_type ; episode_id) => { Option < String > } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot select field nonexistent field $ field on model "Rb" , available fields are "id, created_at, obs_dim, state, action, action_vec, log_prob, reward, next_state, done, run_id, episode_id")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; id) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: id :: Select) } ; (@ selection_field_to_selection_param ; created_at) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: created_at :: Select) } ; (@ selection_field_to_selection_param ; obs_dim) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: obs_dim :: Select) } ; (@ selection_field_to_selection_param ; state) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: state :: Select) } ; (@ selection_field_to_selection_param ; action) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: action :: Select) } ; (@ selection_field_to_selection_param ; action_vec) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: action_vec :: Select) } ; (@ selection_field_to_selection_param ; log_prob) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: log_prob :: Select) } ; (@ selection_field_to_selection_param ; reward) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: reward :: Select) } ; (@ selection_field_to_selection_param ; next_state) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: next_state :: Select) } ; (@ selection_field_to_selection_param ; done) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: done :: Select) } ; (@ selection_field_to_selection_param ; run_id) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: run_id :: Select) } ; (@ selection_field_to_selection_param ; episode_id) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: episode_id :: Select) } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_select_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: rb :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; created_at) => { "created_at" } ; (@ field_serde_name ; obs_dim) => { "obs_dim" } ; (@ field_serde_name ; state) => { "state" } ; (@ field_serde_name ; action) => { "action" } ; (@ field_serde_name ; action_vec) => { "action_vec" } ; (@ field_serde_name ; log_prob) => { "log_prob" } ; (@ field_serde_name ; reward) => { "reward" } ; (@ field_serde_name ; next_state) => { "next_state" } ; (@ field_serde_name ; done) => { "done" } ; (@ field_serde_name ; run_id) => { "run_id" } ; (@ field_serde_name ; episode_id) => { "episode_id" } ; }
+    pub use _select_rb as select;
+    pub enum SelectParam {
+        Id(id::Select),
+        CreatedAt(created_at::Select),
+        ObsDim(obs_dim::Select),
+        State(state::Select),
+        Action(action::Select),
+        ActionVec(action_vec::Select),
+        LogProb(log_prob::Select),
+        Reward(reward::Select),
+        NextState(next_state::Select),
+        Done(done::Select),
+        Run(run::Select),
+        RunId(run_id::Select),
+        Episode(episode::Select),
+        EpisodeId(episode_id::Select),
+    }
+    impl SelectParam {
+        pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+            match self {
+                Self::Id(data) => data.to_selection(),
+                Self::CreatedAt(data) => data.to_selection(),
+                Self::ObsDim(data) => data.to_selection(),
+                Self::State(data) => data.to_selection(),
+                Self::Action(data) => data.to_selection(),
+                Self::ActionVec(data) => data.to_selection(),
+                Self::LogProb(data) => data.to_selection(),
+                Self::Reward(data) => data.to_selection(),
+                Self::NextState(data) => data.to_selection(),
+                Self::Done(data) => data.to_selection(),
+                Self::Run(data) => data.to_selection(),
+                Self::RunId(data) => data.to_selection(),
+                Self::Episode(data) => data.to_selection(),
+                Self::EpisodeId(data) => data.to_selection(),
+            }
+        }
+    }
+    #[macro_export]
+    macro_rules ! _include_rb { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: rb :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: rb :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn include ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Include { let mut selections = $ crate :: prisma :: rb :: _outputs () ; selections . extend ($ crate :: prisma :: rb :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; Include (selections) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: rb :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: rb :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Include ({ let mut selections = $ crate :: prisma :: rb :: _outputs () ; selections . extend ($ crate :: prisma :: rb :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; selections }) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { pub id : String , pub created_at : :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > , pub obs_dim : i32 , pub state : Vec < u8 > , pub action : Option < i32 > , pub action_vec : Option < Vec < u8 > > , pub log_prob : Option < f64 > , pub reward : f64 , pub next_state : Vec < u8 > , pub done : bool , pub run_id : Option < String > , pub episode_id : Option < String > , $ (pub $ field : $ crate :: prisma :: rb :: include ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (created_at) , stringify ! (obs_dim) , stringify ! (state) , stringify ! (action) , stringify ! (action_vec) , stringify ! (log_prob) , stringify ! (reward) , stringify ! (next_state) , stringify ! (done) , stringify ! (run_id) , stringify ! (episode_id)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; id) , & self . id) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; created_at) , & self . created_at) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; obs_dim) , & self . obs_dim) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; state) , & self . state) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; action) , & self . action) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; action_vec) , & self . action_vec) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; log_prob) , & self . log_prob) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; reward) , & self . reward) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; next_state) , & self . next_state) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; done) , & self . done) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; run_id) , & self . run_id) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; episode_id) , & self . episode_id) ? ; state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , id , created_at , obs_dim , state , action , action_vec , log_prob , reward , next_state , done , run_id , episode_id } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; $ field) , ", ") , + , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; id) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; created_at) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; obs_dim) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; state) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; action) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; action_vec) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; log_prob) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; reward) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; next_state) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; done) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; run_id) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; episode_id) , ", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; id) => Ok (Field :: id) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; created_at) => Ok (Field :: created_at) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; obs_dim) => Ok (Field :: obs_dim) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; state) => Ok (Field :: state) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; action) => Ok (Field :: action) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; action_vec) => Ok (Field :: action_vec) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; log_prob) => Ok (Field :: log_prob) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; reward) => Ok (Field :: reward) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; next_state) => Ok (Field :: next_state) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; done) => Ok (Field :: done) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; run_id) => Ok (Field :: run_id) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; episode_id) => Ok (Field :: episode_id) , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * let mut id = None ; let mut created_at = None ; let mut obs_dim = None ; let mut state = None ; let mut action = None ; let mut action_vec = None ; let mut log_prob = None ; let mut reward = None ; let mut next_state = None ; let mut done = None ; let mut run_id = None ; let mut episode_id = None ; while let Some (key) = map . next_key () ? { match key { Field :: id => { if id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; id))) ; } id = Some (map . next_value () ?) ; } Field :: created_at => { if created_at . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; created_at))) ; } created_at = Some (map . next_value () ?) ; } Field :: obs_dim => { if obs_dim . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; obs_dim))) ; } obs_dim = Some (map . next_value () ?) ; } Field :: state => { if state . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; state))) ; } state = Some (map . next_value () ?) ; } Field :: action => { if action . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; action))) ; } action = Some (map . next_value () ?) ; } Field :: action_vec => { if action_vec . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; action_vec))) ; } action_vec = Some (map . next_value () ?) ; } Field :: log_prob => { if log_prob . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; log_prob))) ; } log_prob = Some (map . next_value () ?) ; } Field :: reward => { if reward . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; reward))) ; } reward = Some (map . next_value () ?) ; } Field :: next_state => { if next_state . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; next_state))) ; } next_state = Some (map . next_value () ?) ; } Field :: done => { if done . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; done))) ; } done = Some (map . next_value () ?) ; } Field :: run_id => { if run_id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; run_id))) ; } run_id = Some (map . next_value () ?) ; } Field :: episode_id => { if episode_id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; episode_id))) ; } episode_id = Some (map . next_value () ?) ; } $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; $ field))) ? ;) * let id = id . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; id))) ? ; let created_at = created_at . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; created_at))) ? ; let obs_dim = obs_dim . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; obs_dim))) ? ; let state = state . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; state))) ? ; let action = action . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; action))) ? ; let action_vec = action_vec . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; action_vec))) ? ; let log_prob = log_prob . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; log_prob))) ? ; let reward = reward . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; reward))) ? ; let next_state = next_state . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; next_state))) ? ; let done = done . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; done))) ? ; let run_id = run_id . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; run_id))) ? ; let episode_id = episode_id . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; episode_id))) ? ; Ok (Data { id , created_at , obs_dim , state , action , action_vec , log_prob , reward , next_state , done , run_id , episode_id , $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "created_at" , "obs_dim" , "state" , "action" , "action_vec" , "log_prob" , "reward" , "next_state" , "done" , "run_id" , "episode_id"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: rb :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "Rb" , available relations are "run, episode")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_include_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: rb :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; created_at) => { "created_at" } ; (@ field_serde_name ; obs_dim) => { "obs_dim" } ; (@ field_serde_name ; state) => { "state" } ; (@ field_serde_name ; action) => { "action" } ; (@ field_serde_name ; action_vec) => { "action_vec" } ; (@ field_serde_name ; log_prob) => { "log_prob" } ; (@ field_serde_name ; reward) => { "reward" } ; (@ field_serde_name ; next_state) => { "next_state" } ; (@ field_serde_name ; done) => { "done" } ; (@ field_serde_name ; run_id) => { "run_id" } ; (@ field_serde_name ; episode_id) => { "episode_id" } ; }
+    pub use _include_rb as include;
+    pub enum IncludeParam {
+        Id(id::Include),
+        CreatedAt(created_at::Include),
+        ObsDim(obs_dim::Include),
+        State(state::Include),
+        Action(action::Include),
+        ActionVec(action_vec::Include),
+        LogProb(log_prob::Include),
+        Reward(reward::Include),
+        NextState(next_state::Include),
+        Done(done::Include),
+        Run(run::Include),
+        RunId(run_id::Include),
+        Episode(episode::Include),
+        EpisodeId(episode_id::Include),
+    }
+    impl IncludeParam {
+        pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+            match self {
+                Self::Id(data) => data.to_selection(),
+                Self::CreatedAt(data) => data.to_selection(),
+                Self::ObsDim(data) => data.to_selection(),
+                Self::State(data) => data.to_selection(),
+                Self::Action(data) => data.to_selection(),
+                Self::ActionVec(data) => data.to_selection(),
+                Self::LogProb(data) => data.to_selection(),
+                Self::Reward(data) => data.to_selection(),
+                Self::NextState(data) => data.to_selection(),
+                Self::Done(data) => data.to_selection(),
+                Self::Run(data) => data.to_selection(),
+                Self::RunId(data) => data.to_selection(),
+                Self::Episode(data) => data.to_selection(),
+                Self::EpisodeId(data) => data.to_selection(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, :: serde :: Serialize, :: serde :: Deserialize)]
+    pub struct Data {
+        #[serde(rename = "id")]
+        pub id: String,
+        #[serde(rename = "created_at")]
+        pub created_at:
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        #[serde(rename = "obs_dim")]
+        pub obs_dim: i32,
+        #[serde(rename = "state")]
+        pub state: Vec<u8>,
+        #[serde(rename = "action")]
+        pub action: Option<i32>,
+        #[serde(rename = "action_vec")]
+        pub action_vec: Option<Vec<u8>>,
+        #[serde(rename = "log_prob")]
+        pub log_prob: Option<f64>,
+        #[serde(rename = "reward")]
+        pub reward: f64,
+        #[serde(rename = "next_state")]
+        pub next_state: Vec<u8>,
+        #[serde(rename = "done")]
+        pub done: bool,
+        #[serde(
+            rename = "run",
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "prisma_client_rust::serde::double_option"
+        )]
+        pub run: Option<Option<Box<super::run::Data>>>,
+        #[serde(rename = "run_id")]
+        pub run_id: Option<String>,
+        #[serde(
+            rename = "episode",
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "prisma_client_rust::serde::double_option"
+        )]
+        pub episode: Option<Option<Box<super::episode::Data>>>,
+        #[serde(rename = "episode_id")]
+        pub episode_id: Option<String>,
+    }
+    impl Data {
+        pub fn run(
+            &self,
+        ) -> Result<Option<&super::run::Data>, ::prisma_client_rust::RelationNotFetchedError>
+        {
+            self.run
+                .as_ref()
+                .ok_or(::prisma_client_rust::RelationNotFetchedError::new(
+                    stringify!(run),
+                ))
+                .map(|v| v.as_ref().map(|v| v.as_ref()))
+        }
+        pub fn episode(
+            &self,
+        ) -> Result<Option<&super::episode::Data>, ::prisma_client_rust::RelationNotFetchedError>
+        {
+            self.episode
+                .as_ref()
+                .ok_or(::prisma_client_rust::RelationNotFetchedError::new(
+                    stringify!(episode),
+                ))
+                .map(|v| v.as_ref().map(|v| v.as_ref()))
+        }
+    }
+    #[derive(Clone)]
+    pub enum WithParam {
+        Run(super::run::UniqueArgs),
+        Episode(super::episode::UniqueArgs),
+    }
+    impl Into<::prisma_client_rust::Selection> for WithParam {
+        fn into(self) -> ::prisma_client_rust::Selection {
+            match self {
+                Self::Run(args) => {
+                    let mut selections = super::run::_outputs();
+                    selections.extend(
+                        args.with_params
+                            .into_iter()
+                            .map(Into::<::prisma_client_rust::Selection>::into),
+                    );
+                    let mut builder = ::prisma_client_rust::Selection::builder("run");
+                    builder.nested_selections(selections);
+                    builder.build()
+                }
+                Self::Episode(args) => {
+                    let mut selections = super::episode::_outputs();
+                    selections.extend(
+                        args.with_params
+                            .into_iter()
+                            .map(Into::<::prisma_client_rust::Selection>::into),
+                    );
+                    let mut builder = ::prisma_client_rust::Selection::builder("episode");
+                    builder.nested_selections(selections);
+                    builder.build()
+                }
+            }
+        }
+    }
+    #[derive(Clone)]
+    pub enum SetParam {
+        SetId(String),
+        SetCreatedAt(
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        ),
+        SetObsDim(i32),
+        IncrementObsDim(i32),
+        DecrementObsDim(i32),
+        MultiplyObsDim(i32),
+        DivideObsDim(i32),
+        SetState(Vec<u8>),
+        SetAction(Option<i32>),
+        IncrementAction(i32),
+        DecrementAction(i32),
+        MultiplyAction(i32),
+        DivideAction(i32),
+        SetActionVec(Option<Vec<u8>>),
+        SetLogProb(Option<f64>),
+        IncrementLogProb(f64),
+        DecrementLogProb(f64),
+        MultiplyLogProb(f64),
+        DivideLogProb(f64),
+        SetReward(f64),
+        IncrementReward(f64),
+        DecrementReward(f64),
+        MultiplyReward(f64),
+        DivideReward(f64),
+        SetNextState(Vec<u8>),
+        SetDone(bool),
+        ConnectRun(super::run::UniqueWhereParam),
+        DisconnectRun,
+        SetRunId(Option<String>),
+        ConnectEpisode(super::episode::UniqueWhereParam),
+        DisconnectEpisode,
+        SetEpisodeId(Option<String>),
+    }
+    impl Into<(String, ::prisma_client_rust::PrismaValue)> for SetParam {
+        fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+            match self { SetParam :: SetId (value) => ("id" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: SetCreatedAt (value) => ("created_at" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value)) , SetParam :: SetObsDim (value) => ("obs_dim" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64)) , SetParam :: IncrementObsDim (value) => ("obs_dim" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DecrementObsDim (value) => ("obs_dim" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: MultiplyObsDim (value) => ("obs_dim" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DivideObsDim (value) => ("obs_dim" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: SetState (value) => ("state" . to_string () , :: prisma_client_rust :: PrismaValue :: Bytes (value)) , SetParam :: SetAction (value) => ("action" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Int (value as i64)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: IncrementAction (value) => ("action" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DecrementAction (value) => ("action" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: MultiplyAction (value) => ("action" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DivideAction (value) => ("action" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: SetActionVec (value) => ("action_vec" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Bytes (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetLogProb (value) => ("log_prob" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: IncrementLogProb (value) => ("log_prob" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DecrementLogProb (value) => ("log_prob" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: MultiplyLogProb (value) => ("log_prob" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DivideLogProb (value) => ("log_prob" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: SetReward (value) => ("reward" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ())) , SetParam :: IncrementReward (value) => ("reward" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DecrementReward (value) => ("reward" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: MultiplyReward (value) => ("reward" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DivideReward (value) => ("reward" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: SetNextState (value) => ("next_state" . to_string () , :: prisma_client_rust :: PrismaValue :: Bytes (value)) , SetParam :: SetDone (value) => ("done" . to_string () , :: prisma_client_rust :: PrismaValue :: Boolean (value)) , SetParam :: ConnectRun (where_param) => ("run" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("connect" . to_string () , :: prisma_client_rust :: PrismaValue :: Object ([where_param] . into_iter () . map (Into :: < super :: run :: WhereParam > :: into) . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . collect ()))])) , SetParam :: DisconnectRun => ("run" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("disconnect" . to_string () , :: prisma_client_rust :: PrismaValue :: Boolean (true))])) , SetParam :: SetRunId (value) => ("run_id" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: String (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: ConnectEpisode (where_param) => ("episode" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("connect" . to_string () , :: prisma_client_rust :: PrismaValue :: Object ([where_param] . into_iter () . map (Into :: < super :: episode :: WhereParam > :: into) . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . collect ()))])) , SetParam :: DisconnectEpisode => ("episode" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("disconnect" . to_string () , :: prisma_client_rust :: PrismaValue :: Boolean (true))])) , SetParam :: SetEpisodeId (value) => ("episode_id" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: String (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) }
+        }
+    }
+    #[derive(Clone)]
+    pub enum OrderByParam {
+        Id(::prisma_client_rust::Direction),
+        CreatedAt(::prisma_client_rust::Direction),
+        ObsDim(::prisma_client_rust::Direction),
+        State(::prisma_client_rust::Direction),
+        Action(::prisma_client_rust::Direction),
+        ActionVec(::prisma_client_rust::Direction),
+        LogProb(::prisma_client_rust::Direction),
+        Reward(::prisma_client_rust::Direction),
+        NextState(::prisma_client_rust::Direction),
+        Done(::prisma_client_rust::Direction),
+        RunId(::prisma_client_rust::Direction),
+        EpisodeId(::prisma_client_rust::Direction),
+    }
+    impl Into<(String, ::prisma_client_rust::PrismaValue)> for OrderByParam {
+        fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+            match self {
+                Self::Id(direction) => (
+                    "id".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+                Self::CreatedAt(direction) => (
+                    "created_at".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+                Self::ObsDim(direction) => (
+                    "obs_dim".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+                Self::State(direction) => (
+                    "state".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+                Self::Action(direction) => (
+                    "action".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+                Self::ActionVec(direction) => (
+                    "action_vec".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+                Self::LogProb(direction) => (
+                    "log_prob".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+                Self::Reward(direction) => (
+                    "reward".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+                Self::NextState(direction) => (
+                    "next_state".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+                Self::Done(direction) => (
+                    "done".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+                Self::RunId(direction) => (
+                    "run_id".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+                Self::EpisodeId(direction) => (
+                    "episode_id".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+            }
+        }
+    }
+    #[derive(Clone)]
+    pub enum WhereParam {
+        Not(Vec<WhereParam>),
+        Or(Vec<WhereParam>),
+        And(Vec<WhereParam>),
+        IdEquals(String),
+        IdInVec(Vec<String>),
+        IdNotInVec(Vec<String>),
+        IdLt(String),
+        IdLte(String),
+        IdGt(String),
+        IdGte(String),
+        IdContains(String),
+        IdStartsWith(String),
+        IdEndsWith(String),
+        IdNot(String),
+        CreatedAtEquals(
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        ),
+        CreatedAtInVec(
+            Vec<::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>>,
+        ),
+        CreatedAtNotInVec(
+            Vec<::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>>,
+        ),
+        CreatedAtLt(
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        ),
+        CreatedAtLte(
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        ),
+        CreatedAtGt(
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        ),
+        CreatedAtGte(
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        ),
+        CreatedAtNot(
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        ),
+        ObsDimEquals(i32),
+        ObsDimInVec(Vec<i32>),
+        ObsDimNotInVec(Vec<i32>),
+        ObsDimLt(i32),
+        ObsDimLte(i32),
+        ObsDimGt(i32),
+        ObsDimGte(i32),
+        ObsDimNot(i32),
+        StateEquals(Vec<u8>),
+        StateInVec(Vec<Vec<u8>>),
+        StateNotInVec(Vec<Vec<u8>>),
+        StateNot(Vec<u8>),
+        ActionEquals(Option<i32>),
+        ActionInVec(Vec<i32>),
+        ActionNotInVec(Vec<i32>),
+        ActionLt(i32),
+        ActionLte(i32),
+        ActionGt(i32),
+        ActionGte(i32),
+        ActionNot(Option<i32>),
+        ActionVecEquals(Option<Vec<u8>>),
+        ActionVecInVec(Vec<Vec<u8>>),
+        ActionVecNotInVec(Vec<Vec<u8>>),
+        ActionVecNot(Option<Vec<u8>>),
+        LogProbEquals(Option<f64>),
+        LogProbInVec(Vec<f64>),
+        LogProbNotInVec(Vec<f64>),
+        LogProbLt(f64),
+        LogProbLte(f64),
+        LogProbGt(f64),
+        LogProbGte(f64),
+        LogProbNot(Option<f64>),
+        RewardEquals(f64),
+        RewardInVec(Vec<f64>),
+        RewardNotInVec(Vec<f64>),
+        RewardLt(f64),
+        RewardLte(f64),
+        RewardGt(f64),
+        RewardGte(f64),
+        RewardNot(f64),
+        NextStateEquals(Vec<u8>),
+        NextStateInVec(Vec<Vec<u8>>),
+        NextStateNotInVec(Vec<Vec<u8>>),
+        NextStateNot(Vec<u8>),
+        DoneEquals(bool),
+        RunIs(Vec<super::run::WhereParam>),
+        RunIsNot(Vec<super::run::WhereParam>),
+        RunIdEquals(Option<String>),
+        RunIdInVec(Vec<String>),
+        RunIdNotInVec(Vec<String>),
+        RunIdLt(String),
+        RunIdLte(String),
+        RunIdGt(String),
+        RunIdGte(String),
+        RunIdContains(String),
+        RunIdStartsWith(String),
+        RunIdEndsWith(String),
+        RunIdNot(Option<String>),
+        EpisodeIs(Vec<super::episode::WhereParam>),
+        EpisodeIsNot(Vec<super::episode::WhereParam>),
+        EpisodeIdEquals(Option<String>),
+        EpisodeIdInVec(Vec<String>),
+        EpisodeIdNotInVec(Vec<String>),
+        EpisodeIdLt(String),
+        EpisodeIdLte(String),
+        EpisodeIdGt(String),
+        EpisodeIdGte(String),
+        EpisodeIdContains(String),
+        EpisodeIdStartsWith(String),
+        EpisodeIdEndsWith(String),
+        EpisodeIdNot(Option<String>),
+    }
+    impl Into<::prisma_client_rust::SerializedWhere> for WhereParam {
+        fn into(self) -> ::prisma_client_rust::SerializedWhere {
+            match self { Self :: Not (value) => :: prisma_client_rust :: SerializedWhere :: new ("NOT" , :: prisma_client_rust :: SerializedWhereValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()) ,) , Self :: Or (value) => :: prisma_client_rust :: SerializedWhere :: new ("OR" , :: prisma_client_rust :: SerializedWhereValue :: List (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . map (| v | vec ! [v]) . map (:: prisma_client_rust :: PrismaValue :: Object) . collect ()) ,) , Self :: And (value) => :: prisma_client_rust :: SerializedWhere :: new ("AND" , :: prisma_client_rust :: SerializedWhereValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()) ,) , Self :: IdEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: IdNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: IdLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdContains (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("contains" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdStartsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("startsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdEndsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("endsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: CreatedAtEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: CreatedAtInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: DateTime (v)) . collect ()))])) , Self :: CreatedAtNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: DateTime (v)) . collect ()))])) , Self :: CreatedAtLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: CreatedAtLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: CreatedAtGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: CreatedAtGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: CreatedAtNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: ObsDimEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("obs_dim" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ObsDimInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("obs_dim" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: ObsDimNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("obs_dim" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: ObsDimLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("obs_dim" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ObsDimLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("obs_dim" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ObsDimGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("obs_dim" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ObsDimGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("obs_dim" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ObsDimNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("obs_dim" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: StateEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Bytes (value))])) , Self :: StateInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Bytes (v)) . collect ()))])) , Self :: StateNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Bytes (v)) . collect ()))])) , Self :: StateNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Bytes (value))])) , Self :: ActionEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Int (value as i64)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) , Self :: ActionInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: ActionNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: ActionLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ActionLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ActionGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ActionGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ActionNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Int (value as i64)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) , Self :: ActionVecEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("action_vec" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Bytes (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) , Self :: ActionVecInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("action_vec" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Bytes (v)) . collect ()))])) , Self :: ActionVecNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("action_vec" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Bytes (v)) . collect ()))])) , Self :: ActionVecNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("action_vec" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Bytes (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) , Self :: LogProbEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("log_prob" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) , Self :: LogProbInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("log_prob" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: LogProbNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("log_prob" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: LogProbLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("log_prob" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: LogProbLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("log_prob" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: LogProbGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("log_prob" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: LogProbGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("log_prob" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: LogProbNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("log_prob" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) , Self :: RewardEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: RewardInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: RewardNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: RewardLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: RewardLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: RewardGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: RewardGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: RewardNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: NextStateEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("next_state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Bytes (value))])) , Self :: NextStateInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("next_state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Bytes (v)) . collect ()))])) , Self :: NextStateNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("next_state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Bytes (v)) . collect ()))])) , Self :: NextStateNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("next_state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Bytes (value))])) , Self :: DoneEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("done" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Boolean (value))])) , Self :: RunIs (where_params) => :: prisma_client_rust :: SerializedWhere :: new ("run" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("is" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (where_params . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . collect ()))])) , Self :: RunIsNot (where_params) => :: prisma_client_rust :: SerializedWhere :: new ("run" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("isNot" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (where_params . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . collect ()))])) , Self :: RunIdEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: String (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) , Self :: RunIdInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: RunIdNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: RunIdLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIdLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIdGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIdGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIdContains (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("contains" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIdStartsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("startsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIdEndsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("endsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIdNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: String (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) , Self :: EpisodeIs (where_params) => :: prisma_client_rust :: SerializedWhere :: new ("episode" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("is" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (where_params . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . collect ()))])) , Self :: EpisodeIsNot (where_params) => :: prisma_client_rust :: SerializedWhere :: new ("episode" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("isNot" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (where_params . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . collect ()))])) , Self :: EpisodeIdEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("episode_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: String (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) , Self :: EpisodeIdInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("episode_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: EpisodeIdNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("episode_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: EpisodeIdLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("episode_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: EpisodeIdLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("episode_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: EpisodeIdGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("episode_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: EpisodeIdGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("episode_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: EpisodeIdContains (value) => :: prisma_client_rust :: SerializedWhere :: new ("episode_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("contains" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: EpisodeIdStartsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("episode_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("startsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: EpisodeIdEndsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("episode_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("endsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: EpisodeIdNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("episode_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: String (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) }
+        }
+    }
+    #[derive(Clone)]
+    pub enum UniqueWhereParam {
+        IdEquals(String),
+    }
+    impl From<UniqueWhereParam> for WhereParam {
+        fn from(value: UniqueWhereParam) -> Self {
+            match value {
+                UniqueWhereParam::IdEquals(value) => Self::IdEquals(value),
+            }
+        }
+    }
+    impl From<::prisma_client_rust::Operator<Self>> for WhereParam {
+        fn from(op: ::prisma_client_rust::Operator<Self>) -> Self {
+            match op {
+                ::prisma_client_rust::Operator::Not(value) => Self::Not(value),
+                ::prisma_client_rust::Operator::And(value) => Self::And(value),
+                ::prisma_client_rust::Operator::Or(value) => Self::Or(value),
+            }
+        }
+    }
+    pub type UniqueArgs = ::prisma_client_rust::UniqueArgs<WithParam>;
+    pub type ManyArgs =
+        ::prisma_client_rust::ManyArgs<WhereParam, WithParam, OrderByParam, UniqueWhereParam>;
+    pub type Count<'a> =
+        ::prisma_client_rust::Count<'a, WhereParam, OrderByParam, UniqueWhereParam>;
+    pub type Create<'a> = ::prisma_client_rust::Create<'a, SetParam, WithParam, Data>;
+    pub type CreateMany<'a> = ::prisma_client_rust::CreateMany<'a, SetParam>;
+    pub type FindUnique<'a> =
+        ::prisma_client_rust::FindUnique<'a, WhereParam, WithParam, SetParam, Data>;
+    pub type FindMany<'a> = ::prisma_client_rust::FindMany<
+        'a,
+        WhereParam,
+        WithParam,
+        OrderByParam,
+        UniqueWhereParam,
+        SetParam,
+        Data,
+    >;
+    pub type FindFirst<'a> = ::prisma_client_rust::FindFirst<
+        'a,
+        WhereParam,
+        WithParam,
+        OrderByParam,
+        UniqueWhereParam,
+        Data,
+    >;
+    pub type Update<'a> = ::prisma_client_rust::Update<'a, WhereParam, WithParam, SetParam, Data>;
+    pub type UpdateMany<'a> = ::prisma_client_rust::UpdateMany<'a, WhereParam, SetParam>;
+    pub type Upsert<'a> = ::prisma_client_rust::Upsert<'a, WhereParam, SetParam, WithParam, Data>;
+    pub type Delete<'a> = ::prisma_client_rust::Delete<'a, WhereParam, WithParam, Data>;
+    pub type DeleteMany<'a> = ::prisma_client_rust::DeleteMany<'a, WhereParam>;
+    pub struct Actions<'a> {
+        pub client: &'a PrismaClient,
+    }
+    impl<'a> Actions<'a> {
+        pub fn find_unique(self, _where: UniqueWhereParam) -> FindUnique<'a> {
+            FindUnique::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                _where.into(),
+            )
+        }
+        pub fn find_first(self, _where: Vec<WhereParam>) -> FindFirst<'a> {
+            FindFirst::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                _where,
+            )
+        }
+        pub fn find_many(self, _where: Vec<WhereParam>) -> FindMany<'a> {
+            FindMany::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                _where,
+            )
+        }
+        pub fn create(
+            self,
+            obs_dim: i32,
+            state: Vec<u8>,
+            reward: f64,
+            next_state: Vec<u8>,
+            done: bool,
+            mut _params: Vec<SetParam>,
+        ) -> Create<'a> {
+            _params.push(obs_dim::set(obs_dim));
+            _params.push(state::set(state));
+            _params.push(reward::set(reward));
+            _params.push(next_state::set(next_state));
+            _params.push(done::set(done));
+            Create::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                _params,
+            )
+        }
+        pub fn create_many(
+            self,
+            data: Vec<(i32, Vec<u8>, f64, Vec<u8>, bool, Vec<SetParam>)>,
+        ) -> CreateMany<'a> {
+            let data = data
+                .into_iter()
+                .map(|(obs_dim, state, reward, next_state, done, mut _params)| {
+                    _params.push(obs_dim::set(obs_dim));
+                    _params.push(state::set(state));
+                    _params.push(reward::set(reward));
+                    _params.push(next_state::set(next_state));
+                    _params.push(done::set(done));
+                    _params
+                })
+                .collect();
+            CreateMany::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                data,
+            )
+        }
+        pub fn update(self, _where: UniqueWhereParam, _params: Vec<SetParam>) -> Update<'a> {
+            Update::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                _where.into(),
+                _params,
+                vec![],
+            )
+        }
+        pub fn update_many(
+            self,
+            _where: Vec<WhereParam>,
+            _params: Vec<SetParam>,
+        ) -> UpdateMany<'a> {
+            UpdateMany::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                _where,
+                _params,
+            )
+        }
+        pub fn upsert(
+            self,
+            _where: UniqueWhereParam,
+            (obs_dim, state, reward, next_state, done, mut _params): (
+                i32,
+                Vec<u8>,
+                f64,
+                Vec<u8>,
+                bool,
+                Vec<SetParam>,
+            ),
+            _update: Vec<SetParam>,
+        ) -> Upsert<'a> {
+            _params.push(obs_dim::set(obs_dim));
+            _params.push(state::set(state));
+            _params.push(reward::set(reward));
+            _params.push(next_state::set(next_state));
+            _params.push(done::set(done));
+            Upsert::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                _where.into(),
+                _params,
+                _update,
+            )
+        }
+        pub fn delete(self, _where: UniqueWhereParam) -> Delete<'a> {
+            Delete::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                _where.into(),
+                vec![],
+            )
+        }
+        pub fn delete_many(self, _where: Vec<WhereParam>) -> DeleteMany<'a> {
+            DeleteMany::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                _where.into(),
+            )
+        }
+        pub fn count(self, _where: Vec<WhereParam>) -> Count<'a> {
+            Count::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                vec![],
+            )
+        }
+    }
+}
+pub mod run {
+    use super::_prisma::*;
+    use super::*;
+    pub mod id {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: String) -> T {
+            Set(value).into()
+        }
+        pub fn equals<T: From<UniqueWhereParam>>(value: String) -> T {
+            UniqueWhereParam::IdEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::Id(direction)
+        }
+        pub fn in_vec(value: Vec<String>) -> WhereParam {
+            WhereParam::IdInVec(value)
+        }
+        pub fn not_in_vec(value: Vec<String>) -> WhereParam {
+            WhereParam::IdNotInVec(value)
+        }
+        pub fn lt(value: String) -> WhereParam {
+            WhereParam::IdLt(value)
+        }
+        pub fn lte(value: String) -> WhereParam {
+            WhereParam::IdLte(value)
+        }
+        pub fn gt(value: String) -> WhereParam {
+            WhereParam::IdGt(value)
+        }
+        pub fn gte(value: String) -> WhereParam {
+            WhereParam::IdGte(value)
+        }
+        pub fn contains(value: String) -> WhereParam {
+            WhereParam::IdContains(value)
+        }
+        pub fn starts_with(value: String) -> WhereParam {
+            WhereParam::IdStartsWith(value)
+        }
+        pub fn ends_with(value: String) -> WhereParam {
+            WhereParam::IdEndsWith(value)
+        }
+        pub fn not(value: String) -> WhereParam {
+            WhereParam::IdNot(value)
+        }
+        pub struct Set(pub String);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetId(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Id(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("id").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Id(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("id").build()
+            }
+        }
+    }
+    pub mod started_at {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(
+            value: ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        ) -> WhereParam {
+            WhereParam::StartedAtEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::StartedAt(direction)
+        }
+        pub fn in_vec(
+            value: Vec<
+                ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+            >,
+        ) -> WhereParam {
+            WhereParam::StartedAtInVec(value)
+        }
+        pub fn not_in_vec(
+            value: Vec<
+                ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+            >,
+        ) -> WhereParam {
+            WhereParam::StartedAtNotInVec(value)
+        }
+        pub fn lt(
+            value: ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        ) -> WhereParam {
+            WhereParam::StartedAtLt(value)
+        }
+        pub fn lte(
+            value: ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        ) -> WhereParam {
+            WhereParam::StartedAtLte(value)
+        }
+        pub fn gt(
+            value: ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        ) -> WhereParam {
+            WhereParam::StartedAtGt(value)
+        }
+        pub fn gte(
+            value: ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        ) -> WhereParam {
+            WhereParam::StartedAtGte(value)
+        }
+        pub fn not(
+            value: ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        ) -> WhereParam {
+            WhereParam::StartedAtNot(value)
+        }
+        pub struct Set(
+            pub ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        );
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetStartedAt(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::StartedAt(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("started_at").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::StartedAt(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("started_at").build()
+            }
+        }
+    }
+    pub mod config {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: String) -> T {
+            Set(value).into()
+        }
+        pub fn equals(value: String) -> WhereParam {
+            WhereParam::ConfigEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::Config(direction)
+        }
+        pub fn in_vec(value: Vec<String>) -> WhereParam {
+            WhereParam::ConfigInVec(value)
+        }
+        pub fn not_in_vec(value: Vec<String>) -> WhereParam {
+            WhereParam::ConfigNotInVec(value)
+        }
+        pub fn lt(value: String) -> WhereParam {
+            WhereParam::ConfigLt(value)
+        }
+        pub fn lte(value: String) -> WhereParam {
+            WhereParam::ConfigLte(value)
+        }
+        pub fn gt(value: String) -> WhereParam {
+            WhereParam::ConfigGt(value)
+        }
+        pub fn gte(value: String) -> WhereParam {
+            WhereParam::ConfigGte(value)
+        }
+        pub fn contains(value: String) -> WhereParam {
+            WhereParam::ConfigContains(value)
+        }
+        pub fn starts_with(value: String) -> WhereParam {
+            WhereParam::ConfigStartsWith(value)
+        }
+        pub fn ends_with(value: String) -> WhereParam {
+            WhereParam::ConfigEndsWith(value)
+        }
+        pub fn not(value: String) -> WhereParam {
+            WhereParam::ConfigNot(value)
+        }
+        pub struct Set(pub String);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetConfig(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Config(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("config").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Config(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("config").build()
+            }
+        }
+    }
+    pub mod git_hash {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: String) -> T {
+            Set(value).into()
+        }
+        pub fn equals(value: String) -> WhereParam {
+            WhereParam::GitHashEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::GitHash(direction)
+        }
+        pub fn in_vec(value: Vec<String>) -> WhereParam {
+            WhereParam::GitHashInVec(value)
+        }
+        pub fn not_in_vec(value: Vec<String>) -> WhereParam {
+            WhereParam::GitHashNotInVec(value)
+        }
+        pub fn lt(value: String) -> WhereParam {
+            WhereParam::GitHashLt(value)
+        }
+        pub fn lte(value: String) -> WhereParam {
+            WhereParam::GitHashLte(value)
+        }
+        pub fn gt(value: String) -> WhereParam {
+            WhereParam::GitHashGt(value)
+        }
+        pub fn gte(value: String) -> WhereParam {
+            WhereParam::GitHashGte(value)
+        }
+        pub fn contains(value: String) -> WhereParam {
+            WhereParam::GitHashContains(value)
+        }
+        pub fn starts_with(value: String) -> WhereParam {
+            WhereParam::GitHashStartsWith(value)
+        }
+        pub fn ends_with(value: String) -> WhereParam {
+            WhereParam::GitHashEndsWith(value)
+        }
+        pub fn not(value: String) -> WhereParam {
+            WhereParam::GitHashNot(value)
+        }
+        pub struct Set(pub String);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetGitHash(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::GitHash(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("git_hash").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::GitHash(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("git_hash").build()
+            }
+        }
+    }
+    pub mod seed {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: i64) -> T {
+            Set(value).into()
+        }
+        pub fn equals(value: i64) -> WhereParam {
+            WhereParam::SeedEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::Seed(direction)
+        }
+        pub fn in_vec(value: Vec<i64>) -> WhereParam {
+            WhereParam::SeedInVec(value)
+        }
+        pub fn not_in_vec(value: Vec<i64>) -> WhereParam {
+            WhereParam::SeedNotInVec(value)
+        }
+        pub fn lt(value: i64) -> WhereParam {
+            WhereParam::SeedLt(value)
+        }
+        pub fn lte(value: i64) -> WhereParam {
+            WhereParam::SeedLte(value)
+        }
+        pub fn gt(value: i64) -> WhereParam {
+            WhereParam::SeedGt(value)
+        }
+        pub fn gte(value: i64) -> WhereParam {
+            WhereParam::SeedGte(value)
+        }
+        pub fn not(value: i64) -> WhereParam {
+            WhereParam::SeedNot(value)
+        }
+        pub fn increment(value: i64) -> SetParam {
+            SetParam::IncrementSeed(value)
+        }
+        pub fn decrement(value: i64) -> SetParam {
+            SetParam::DecrementSeed(value)
+        }
+        pub fn multiply(value: i64) -> SetParam {
+            SetParam::MultiplySeed(value)
+        }
+        pub fn divide(value: i64) -> SetParam {
+            SetParam::DivideSeed(value)
+        }
+        pub struct Set(pub i64);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetSeed(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Seed(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("seed").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Seed(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("seed").build()
+            }
+        }
+    }
+    pub mod episodes {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn some(value: Vec<super::super::episode::WhereParam>) -> WhereParam {
+            WhereParam::EpisodesSome(value)
+        }
+        pub fn every(value: Vec<super::super::episode::WhereParam>) -> WhereParam {
+            WhereParam::EpisodesEvery(value)
+        }
+        pub fn none(value: Vec<super::super::episode::WhereParam>) -> WhereParam {
+            WhereParam::EpisodesNone(value)
+        }
+        pub struct Fetch(pub super::super::episode::ManyArgs);
+        impl Fetch {
+            pub fn with(mut self, params: impl Into<super::super::episode::WithParam>) -> Self {
+                self.0 = self.0.with(params.into());
+                self
+            }
+            pub fn order_by(mut self, param: super::super::episode::OrderByParam) -> Self {
+                self.0 = self.0.order_by(param);
+                self
+            }
+            pub fn skip(mut self, value: i64) -> Self {
+                self.0 = self.0.skip(value);
+                self
+            }
+            pub fn take(mut self, value: i64) -> Self {
+                self.0 = self.0.take(value);
+                self
+            }
+        }
+        impl From<Fetch> for WithParam {
+            fn from(fetch: Fetch) -> Self {
+                WithParam::Episodes(fetch.0)
+            }
+        }
+        pub fn fetch(params: Vec<super::super::episode::WhereParam>) -> Fetch {
+            Fetch(super::super::episode::ManyArgs::new(params))
+        }
+        pub fn connect<T: From<Connect>>(
+            params: Vec<super::super::episode::UniqueWhereParam>,
+        ) -> T {
+            Connect(params).into()
+        }
+        pub fn disconnect(params: Vec<super::super::episode::UniqueWhereParam>) -> SetParam {
+            SetParam::DisconnectEpisodes(params)
+        }
+        pub fn set(params: Vec<super::super::episode::UniqueWhereParam>) -> SetParam {
+            SetParam::SetEpisodes(params)
+        }
+        pub struct Connect(pub Vec<super::super::episode::UniqueWhereParam>);
+        impl From<Connect> for SetParam {
+            fn from(value: Connect) -> Self {
+                Self::ConnectEpisodes(value.0)
+            }
+        }
+        pub enum Include {
+            Select(Vec<super::super::episode::SelectParam>),
+            Include(Vec<super::super::episode::IncludeParam>),
+            Fetch,
+        }
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Episodes(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                let mut builder = ::prisma_client_rust::Selection::builder("episodes");
+                let selections = match self {
+                    Self::Select(selections) => {
+                        selections.into_iter().map(|s| s.to_selection()).collect()
+                    }
+                    Self::Include(selections) => {
+                        let mut nested = super::super::episode::_outputs();
+                        nested.extend(selections.into_iter().map(|s| s.to_selection()));
+                        nested
+                    }
+                    Self::Fetch => super::super::episode::_outputs(),
+                };
+                builder.nested_selections(selections);
+                builder.build()
+            }
+        }
+        pub enum Select {
+            Select(Vec<super::super::episode::SelectParam>),
+            Include(Vec<super::super::episode::IncludeParam>),
+            Fetch,
+        }
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Episodes(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                let mut builder = ::prisma_client_rust::Selection::builder("episodes");
+                let selections = match self {
+                    Self::Select(selections) => {
+                        selections.into_iter().map(|s| s.to_selection()).collect()
+                    }
+                    Self::Include(selections) => {
+                        let mut nested = super::super::episode::_outputs();
+                        nested.extend(selections.into_iter().map(|s| s.to_selection()));
+                        nested
+                    }
+                    Self::Fetch => super::super::episode::_outputs(),
+                };
+                builder.nested_selections(selections);
+                builder.build()
+            }
+        }
+    }
+    pub mod transitions {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn some(value: Vec<super::super::rb::WhereParam>) -> WhereParam {
+            WhereParam::TransitionsSome(value)
+        }
+        pub fn every(value: Vec<super::super::rb::WhereParam>) -> WhereParam {
+            WhereParam::TransitionsEvery(value)
+        }
+        pub fn none(value: Vec<super::super::rb::WhereParam>) -> WhereParam {
+            WhereParam::TransitionsNone(value)
+        }
+        pub struct Fetch(pub super::super::rb::ManyArgs);
+        impl Fetch {
+            pub fn with(mut self, params: impl Into<super::super::rb::WithParam>) -> Self {
+                self.0 = self.0.with(params.into());
+                self
+            }
+            pub fn order_by(mut self, param: super::super::rb::OrderByParam) -> Self {
+                self.0 = self.0.order_by(param);
+                self
+            }
+            pub fn skip(mut self, value: i64) -> Self {
+                self.0 = self.0.skip(value);
+                self
+            }
+            pub fn take(mut self, value: i64) -> Self {
+                self.0 = self.0.take(value);
+                self
+            }
+        }
+        impl From<Fetch> for WithParam {
+            fn from(fetch: Fetch) -> Self {
+                WithParam::Transitions(fetch.0)
+            }
+        }
+        pub fn fetch(params: Vec<super::super::rb::WhereParam>) -> Fetch {
+            Fetch(super::super::rb::ManyArgs::new(params))
+        }
+        pub fn connect<T: From<Connect>>(params: Vec<super::super::rb::UniqueWhereParam>) -> T {
+            Connect(params).into()
+        }
+        pub fn disconnect(params: Vec<super::super::rb::UniqueWhereParam>) -> SetParam {
+            SetParam::DisconnectTransitions(params)
+        }
+        pub fn set(params: Vec<super::super::rb::UniqueWhereParam>) -> SetParam {
+            SetParam::SetTransitions(params)
+        }
+        pub struct Connect(pub Vec<super::super::rb::UniqueWhereParam>);
+        impl From<Connect> for SetParam {
+            fn from(value: Connect) -> Self {
+                Self::ConnectTransitions(value.0)
+            }
+        }
+        pub enum Include {
+            Select(Vec<super::super::rb::SelectParam>),
+            Include(Vec<super::super::rb::IncludeParam>),
+            Fetch,
+        }
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Transitions(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                let mut builder = ::prisma_client_rust::Selection::builder("transitions");
+                let selections = match self {
+                    Self::Select(selections) => {
+                        selections.into_iter().map(|s| s.to_selection()).collect()
+                    }
+                    Self::Include(selections) => {
+                        let mut nested = super::super::rb::_outputs();
+                        nested.extend(selections.into_iter().map(|s| s.to_selection()));
+                        nested
+                    }
+                    Self::Fetch => super::super::rb::_outputs(),
+                };
+                builder.nested_selections(selections);
+                builder.build()
+            }
+        }
+        pub enum Select {
+            Select(Vec<super::super::rb::SelectParam>),
+            Include(Vec<super::super::rb::IncludeParam>),
+            Fetch,
+        }
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Transitions(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                let mut builder = ::prisma_client_rust::Selection::builder("transitions");
+                let selections = match self {
+                    Self::Select(selections) => {
+                        selections.into_iter().map(|s| s.to_selection()).collect()
+                    }
+                    Self::Include(selections) => {
+                        let mut nested = super::super::rb::_outputs();
+                        nested.extend(selections.into_iter().map(|s| s.to_selection()));
+                        nested
+                    }
+                    Self::Fetch => super::super::rb::_outputs(),
+                };
+                builder.nested_selections(selections);
+                builder.build()
+            }
+        }
+    }
+    pub fn _outputs() -> Vec<::prisma_client_rust::Selection> {
+        ["id", "started_at", "config", "git_hash", "seed"]
+            .into_iter()
+            .map(|o| {
+                let builder = ::prisma_client_rust::Selection::builder(o);
+                builder.build()
+            })
+            .collect()
+    }
+    pub fn create(
+        config: String,
+        git_hash: String,
+        seed: i64,
+        _params: Vec<SetParam>,
+    ) -> (String, String, i64, Vec<SetParam>) {
+        (config, git_hash, seed, _params)
+    }
+    #[macro_export]
+    macro_rules ! _select_run { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: run :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: run :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn select ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Select { Select ($ crate :: prisma :: run :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: run :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: run :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Select ($ crate :: prisma :: run :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { id , started_at , config , git_hash , seed } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { $ (pub $ field : $ crate :: prisma :: run :: select ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (started_at) , stringify ! (config) , stringify ! (git_hash) , stringify ! (seed)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: run :: select ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: run :: select ! (@ field_serde_name ; $ field) , ", ") , +)) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: run :: select ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * while let Some (key) = map . next_key () ? { match key { $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: run :: select ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: run :: select ! (@ field_serde_name ; $ field))) ? ;) * Ok (Data { $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "started_at" , "config" , "git_hash" , "seed"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: run :: select ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; id) => { String } ; (@ field_type ; started_at) => { :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > } ; (@ field_type ; config) => { String } ; (@ field_type ; git_hash) => { String } ; (@ field_type ; seed) => { i64 } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot select field nonexistent field $ field on model "Run" , available fields are "id, started_at, config, git_hash, seed")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; id) => { Into :: < $ crate :: prisma :: run :: SelectParam > :: into ($ crate :: prisma :: run :: id :: Select) } ; (@ selection_field_to_selection_param ; started_at) => { Into :: < $ crate :: prisma :: run :: SelectParam > :: into ($ crate :: prisma :: run :: started_at :: Select) } ; (@ selection_field_to_selection_param ; config) => { Into :: < $ crate :: prisma :: run :: SelectParam > :: into ($ crate :: prisma :: run :: config :: Select) } ; (@ selection_field_to_selection_param ; git_hash) => { Into :: < $ crate :: prisma :: run :: SelectParam > :: into ($ crate :: prisma :: run :: git_hash :: Select) } ; (@ selection_field_to_selection_param ; seed) => { Into :: < $ crate :: prisma :: run :: SelectParam > :: into ($ crate :: prisma :: run :: seed :: Select) } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_select_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: run :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; started_at) => { "started_at" } ; (@ field_serde_name ; config) => { "config" } ; (@ field_serde_name ; git_hash) => { "git_hash" } ; (@ field_serde_name ; seed) => { "seed" } ; }
+    pub use _select_run as select;
+    pub enum SelectParam {
+        Id(id::Select),
+        StartedAt(started_at::Select),
+        Config(config::Select),
+        GitHash(git_hash::Select),
+        Seed(seed::Select),
+        Episodes(episodes::Select),
+        Transitions(transitions::Select),
+    }
+    impl SelectParam {
+        pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+            match self {
+                Self::Id(data) => data.to_selection(),
+                Self::StartedAt(data) => data.to_selection(),
+                Self::Config(data) => data.to_selection(),
+                Self::GitHash(data) => data.to_selection(),
+                Self::Seed(data) => data.to_selection(),
+                Self::Episodes(data) => data.to_selection(),
+                Self::Transitions(data) => data.to_selection(),
+            }
+        }
+    }
+    #[macro_export]
+    macro_rules ! _include_run { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: run :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: run :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn include ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Include { let mut selections = $ crate :: prisma :: run :: _outputs () ; selections . extend ($ crate :: prisma :: run :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; Include (selections) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: run :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: run :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Include ({ let mut selections = $ crate :: prisma :: run :: _outputs () ; selections . extend ($ crate :: prisma :: run :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; selections }) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { pub id : String , pub started_at : :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > , pub config : String , pub git_hash : String , pub seed : i64 , $ (pub $ field : $ crate :: prisma :: run :: include ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (started_at) , stringify ! (config) , stringify ! (git_hash) , stringify ! (seed)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . serialize_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; id) , & self . id) ? ; state . serialize_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; started_at) , & self . started_at) ? ; state . serialize_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; config) , & self . config) ? ; state . serialize_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; git_hash) , & self . git_hash) ? ; state . serialize_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; seed) , & self . seed) ? ; state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , id , started_at , config , git_hash , seed } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: run :: include ! (@ field_serde_name ; $ field) , ", ") , + , $ crate :: prisma :: run :: include ! (@ field_serde_name ; id) , ", " , $ crate :: prisma :: run :: include ! (@ field_serde_name ; started_at) , ", " , $ crate :: prisma :: run :: include ! (@ field_serde_name ; config) , ", " , $ crate :: prisma :: run :: include ! (@ field_serde_name ; git_hash) , ", " , $ crate :: prisma :: run :: include ! (@ field_serde_name ; seed) , ", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: run :: include ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , $ crate :: prisma :: run :: include ! (@ field_serde_name ; id) => Ok (Field :: id) , $ crate :: prisma :: run :: include ! (@ field_serde_name ; started_at) => Ok (Field :: started_at) , $ crate :: prisma :: run :: include ! (@ field_serde_name ; config) => Ok (Field :: config) , $ crate :: prisma :: run :: include ! (@ field_serde_name ; git_hash) => Ok (Field :: git_hash) , $ crate :: prisma :: run :: include ! (@ field_serde_name ; seed) => Ok (Field :: seed) , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * let mut id = None ; let mut started_at = None ; let mut config = None ; let mut git_hash = None ; let mut seed = None ; while let Some (key) = map . next_key () ? { match key { Field :: id => { if id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; id))) ; } id = Some (map . next_value () ?) ; } Field :: started_at => { if started_at . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; started_at))) ; } started_at = Some (map . next_value () ?) ; } Field :: config => { if config . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; config))) ; } config = Some (map . next_value () ?) ; } Field :: git_hash => { if git_hash . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; git_hash))) ; } git_hash = Some (map . next_value () ?) ; } Field :: seed => { if seed . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; seed))) ; } seed = Some (map . next_value () ?) ; } $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; $ field))) ? ;) * let id = id . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; id))) ? ; let started_at = started_at . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; started_at))) ? ; let config = config . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; config))) ? ; let git_hash = git_hash . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; git_hash))) ? ; let seed = seed . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: run :: include ! (@ field_serde_name ; seed))) ? ; Ok (Data { id , started_at , config , git_hash , seed , $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "started_at" , "config" , "git_hash" , "seed"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: run :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "Run" , available relations are "episodes, transitions")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_include_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: run :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; started_at) => { "started_at" } ; (@ field_serde_name ; config) => { "config" } ; (@ field_serde_name ; git_hash) => { "git_hash" } ; (@ field_serde_name ; seed) => { "seed" } ; }
+    pub use _include_run as include;
+    pub enum IncludeParam {
+        Id(id::Include),
+        StartedAt(started_at::Include),
+        Config(config::Include),
+        GitHash(git_hash::Include),
+        Seed(seed::Include),
+        Episodes(episodes::Include),
+        Transitions(transitions::Include),
+    }
+    impl IncludeParam {
+        pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+            match self {
+                Self::Id(data) => data.to_selection(),
+                Self::StartedAt(data) => data.to_selection(),
+                Self::Config(data) => data.to_selection(),
+                Self::GitHash(data) => data.to_selection(),
+                Self::Seed(data) => data.to_selection(),
+                Self::Episodes(data) => data.to_selection(),
+                Self::Transitions(data) => data.to_selection(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, :: serde :: Serialize, :: serde :: Deserialize)]
+    pub struct Data {
+        #[serde(rename = "id")]
+        pub id: String,
+        #[serde(rename = "started_at")]
+        pub started_at:
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        #[serde(rename = "config")]
+        pub config: String,
+        #[serde(rename = "git_hash")]
+        pub git_hash: String,
+        #[serde(rename = "seed")]
+        pub seed: i64,
+        #[serde(rename = "episodes", skip_serializing_if = "Option::is_none")]
+        pub episodes: Option<Vec<super::episode::Data>>,
+        #[serde(rename = "transitions", skip_serializing_if = "Option::is_none")]
+        pub transitions: Option<Vec<super::rb::Data>>,
+    }
+    impl Data {
+        pub fn episodes(
+            &self,
+        ) -> Result<&Vec<super::episode::Data>, ::prisma_client_rust::RelationNotFetchedError>
+        {
+            self.episodes
+                .as_ref()
+                .ok_or(::prisma_client_rust::RelationNotFetchedError::new(
+                    stringify!(episodes),
+                ))
+        }
+        pub fn transitions(
+            &self,
+        ) -> Result<&Vec<super::rb::Data>, ::prisma_client_rust::RelationNotFetchedError> {
+            self.transitions
+                .as_ref()
+                .ok_or(::prisma_client_rust::RelationNotFetchedError::new(
+                    stringify!(transitions),
+                ))
+        }
+    }
+    #[derive(Clone)]
+    pub enum WithParam {
+        Episodes(super::episode::ManyArgs),
+        Transitions(super::rb::ManyArgs),
+    }
+    impl Into<::prisma_client_rust::Selection> for WithParam {
+        fn into(self) -> ::prisma_client_rust::Selection {
+            match self {
+                Self::Episodes(args) => {
+                    let (arguments, nested_selections) = args.to_graphql();
+                    let mut selections = super::episode::_outputs();
+                    selections.extend(nested_selections);
+                    let mut builder = ::prisma_client_rust::Selection::builder("episodes");
+                    builder.set_arguments(arguments);
+                    builder.nested_selections(selections);
+                    builder.build()
+                }
+                Self::Transitions(args) => {
+                    let (arguments, nested_selections) = args.to_graphql();
+                    let mut selections = super::rb::_outputs();
+                    selections.extend(nested_selections);
+                    let mut builder = ::prisma_client_rust::Selection::builder("transitions");
+                    builder.set_arguments(arguments);
+                    builder.nested_selections(selections);
+                    builder.build()
+                }
+            }
+        }
+    }
+    #[derive(Clone)]
+    pub enum SetParam {
+        SetId(String),
+        SetStartedAt(
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        ),
+        SetConfig(String),
+        SetGitHash(String),
+        SetSeed(i64),
+        IncrementSeed(i64),
+        DecrementSeed(i64),
+        MultiplySeed(i64),
+        DivideSeed(i64),
+        ConnectEpisodes(Vec<super::episode::UniqueWhereParam>),
+        DisconnectEpisodes(Vec<super::episode::UniqueWhereParam>),
+        SetEpisodes(Vec<super::episode::UniqueWhereParam>),
+        ConnectTransitions(Vec<super::rb::UniqueWhereParam>),
+        DisconnectTransitions(Vec<super::rb::UniqueWhereParam>),
+        SetTransitions(Vec<super::rb::UniqueWhereParam>),
+    }
+    impl Into<(String, ::prisma_client_rust::PrismaValue)> for SetParam {
+        fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+            match self {
+                SetParam::SetId(value) => (
+                    "id".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(value),
+                ),
+                SetParam::SetStartedAt(value) => (
+                    "started_at".to_string(),
+                    ::prisma_client_rust::PrismaValue::DateTime(value),
+                ),
+                SetParam::SetConfig(value) => (
+                    "config".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(value),
+                ),
+                SetParam::SetGitHash(value) => (
+                    "git_hash".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(value),
+                ),
+                SetParam::SetSeed(value) => (
+                    "seed".to_string(),
+                    ::prisma_client_rust::PrismaValue::BigInt(value),
+                ),
+                SetParam::IncrementSeed(value) => (
+                    "seed".to_string(),
+                    ::prisma_client_rust::PrismaValue::Object(vec![(
+                        "increment".to_string(),
+                        ::prisma_client_rust::PrismaValue::BigInt(value),
+                    )]),
+                ),
+                SetParam::DecrementSeed(value) => (
+                    "seed".to_string(),
+                    ::prisma_client_rust::PrismaValue::Object(vec![(
+                        "decrement".to_string(),
+                        ::prisma_client_rust::PrismaValue::BigInt(value),
+                    )]),
+                ),
+                SetParam::MultiplySeed(value) => (
+                    "seed".to_string(),
+                    ::prisma_client_rust::PrismaValue::Object(vec![(
+                        "multiply".to_string(),
+                        ::prisma_client_rust::PrismaValue::BigInt(value),
+                    )]),
+                ),
+                SetParam::DivideSeed(value) => (
+                    "seed".to_string(),
+                    ::prisma_client_rust::PrismaValue::Object(vec![(
+                        "divide".to_string(),
+                        ::prisma_client_rust::PrismaValue::BigInt(value),
+                    )]),
+                ),
+                SetParam::ConnectEpisodes(where_params) => (
+                    "episodes".to_string(),
+                    ::prisma_client_rust::PrismaValue::Object(vec![(
+                        "connect".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            where_params
+                                .into_iter()
+                                .map(Into::<super::episode::WhereParam>::into)
+                                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                SetParam::DisconnectEpisodes(where_params) => (
+                    "episodes".to_string(),
+                    ::prisma_client_rust::PrismaValue::Object(vec![(
+                        "disconnect".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            where_params
+                                .into_iter()
+                                .map(Into::<super::episode::WhereParam>::into)
+                                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                SetParam::SetEpisodes(where_params) => (
+                    "episodes".to_string(),
+                    ::prisma_client_rust::PrismaValue::Object(vec![(
+                        "set".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            where_params
+                                .into_iter()
+                                .map(Into::<super::episode::WhereParam>::into)
+                                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                SetParam::ConnectTransitions(where_params) => (
+                    "transitions".to_string(),
+                    ::prisma_client_rust::PrismaValue::Object(vec![(
+                        "connect".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            where_params
+                                .into_iter()
+                                .map(Into::<super::rb::WhereParam>::into)
+                                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                SetParam::DisconnectTransitions(where_params) => (
+                    "transitions".to_string(),
+                    ::prisma_client_rust::PrismaValue::Object(vec![(
+                        "disconnect".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            where_params
+                                .into_iter()
+                                .map(Into::<super::rb::WhereParam>::into)
+                                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                SetParam::SetTransitions(where_params) => (
+                    "transitions".to_string(),
+                    ::prisma_client_rust::PrismaValue::Object(vec![(
+                        "set".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            where_params
+                                .into_iter()
+                                .map(Into::<super::rb::WhereParam>::into)
+                                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+            }
+        }
+    }
+    #[derive(Clone)]
+    pub enum OrderByParam {
+        Id(::prisma_client_rust::Direction),
+        StartedAt(::prisma_client_rust::Direction),
+        Config(::prisma_client_rust::Direction),
+        GitHash(::prisma_client_rust::Direction),
+        Seed(::prisma_client_rust::Direction),
+    }
+    impl Into<(String, ::prisma_client_rust::PrismaValue)> for OrderByParam {
+        fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+            match self {
+                Self::Id(direction) => (
+                    "id".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+                Self::StartedAt(direction) => (
+                    "started_at".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+                Self::Config(direction) => (
+                    "config".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+                Self::GitHash(direction) => (
+                    "git_hash".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+                Self::Seed(direction) => (
+                    "seed".to_string(),
+                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
+                ),
+            }
+        }
+    }
+    #[derive(Clone)]
+    pub enum WhereParam {
+        Not(Vec<WhereParam>),
+        Or(Vec<WhereParam>),
+        And(Vec<WhereParam>),
+        IdEquals(String),
+        IdInVec(Vec<String>),
+        IdNotInVec(Vec<String>),
+        IdLt(String),
+        IdLte(String),
+        IdGt(String),
+        IdGte(String),
+        IdContains(String),
+        IdStartsWith(String),
+        IdEndsWith(String),
+        IdNot(String),
+        StartedAtEquals(
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        ),
+        StartedAtInVec(
+            Vec<::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>>,
+        ),
+        StartedAtNotInVec(
+            Vec<::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>>,
+        ),
+        StartedAtLt(
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        ),
+        StartedAtLte(
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        ),
+        StartedAtGt(
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        ),
+        StartedAtGte(
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        ),
+        StartedAtNot(
+            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
+        ),
+        ConfigEquals(String),
+        ConfigInVec(Vec<String>),
+        ConfigNotInVec(Vec<String>),
+        ConfigLt(String),
+        ConfigLte(String),
+        ConfigGt(String),
+        ConfigGte(String),
+        ConfigContains(String),
+        ConfigStartsWith(String),
+        ConfigEndsWith(String),
+        ConfigNot(String),
+        GitHashEquals(String),
+        GitHashInVec(Vec<String>),
+        GitHashNotInVec(Vec<String>),
+        GitHashLt(String),
+        GitHashLte(String),
+        GitHashGt(String),
+        GitHashGte(String),
+        GitHashContains(String),
+        GitHashStartsWith(String),
+        GitHashEndsWith(String),
+        GitHashNot(String),
+        SeedEquals(i64),
+        SeedInVec(Vec<i64>),
+        SeedNotInVec(Vec<i64>),
+        SeedLt(i64),
+        SeedLte(i64),
+        SeedGt(i64),
+        SeedGte(i64),
+        SeedNot(i64),
+        EpisodesSome(Vec<super::episode::WhereParam>),
+        EpisodesEvery(Vec<super::episode::WhereParam>),
+        EpisodesNone(Vec<super::episode::WhereParam>),
+        TransitionsSome(Vec<super::rb::WhereParam>),
+        TransitionsEvery(Vec<super::rb::WhereParam>),
+        TransitionsNone(Vec<super::rb::WhereParam>),
+    }
+    impl Into<::prisma_client_rust::SerializedWhere> for WhereParam {
+        fn into(self) -> ::prisma_client_rust::SerializedWhere {
+            match self {
+                Self::Not(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "NOT",
+                    ::prisma_client_rust::SerializedWhereValue::Object(
+                        value
+                            .into_iter()
+                            .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                            .map(Into::into)
+                            .collect(),
+                    ),
+                ),
+                Self::Or(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "OR",
+                    ::prisma_client_rust::SerializedWhereValue::List(
+                        value
+                            .into_iter()
+                            .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                            .map(Into::into)
+                            .map(|v| vec![v])
+                            .map(::prisma_client_rust::PrismaValue::Object)
+                            .collect(),
+                    ),
+                ),
+                Self::And(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "AND",
+                    ::prisma_client_rust::SerializedWhereValue::Object(
+                        value
+                            .into_iter()
+                            .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                            .map(Into::into)
+                            .collect(),
+                    ),
+                ),
+                Self::IdEquals(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "id",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "equals".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::IdInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "id",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "in".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            value
+                                .into_iter()
+                                .map(|v| ::prisma_client_rust::PrismaValue::String(v))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                Self::IdNotInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "id",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "notIn".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            value
+                                .into_iter()
+                                .map(|v| ::prisma_client_rust::PrismaValue::String(v))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                Self::IdLt(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "id",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "lt".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::IdLte(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "id",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "lte".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::IdGt(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "id",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "gt".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::IdGte(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "id",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "gte".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::IdContains(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "id",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "contains".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::IdStartsWith(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "id",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "startsWith".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::IdEndsWith(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "id",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "endsWith".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::IdNot(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "id",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "not".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::StartedAtEquals(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "started_at",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "equals".to_string(),
+                        ::prisma_client_rust::PrismaValue::DateTime(value),
+                    )]),
+                ),
+                Self::StartedAtInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "started_at",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "in".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            value
+                                .into_iter()
+                                .map(|v| ::prisma_client_rust::PrismaValue::DateTime(v))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                Self::StartedAtNotInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "started_at",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "notIn".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            value
+                                .into_iter()
+                                .map(|v| ::prisma_client_rust::PrismaValue::DateTime(v))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                Self::StartedAtLt(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "started_at",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "lt".to_string(),
+                        ::prisma_client_rust::PrismaValue::DateTime(value),
+                    )]),
+                ),
+                Self::StartedAtLte(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "started_at",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "lte".to_string(),
+                        ::prisma_client_rust::PrismaValue::DateTime(value),
+                    )]),
+                ),
+                Self::StartedAtGt(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "started_at",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "gt".to_string(),
+                        ::prisma_client_rust::PrismaValue::DateTime(value),
+                    )]),
+                ),
+                Self::StartedAtGte(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "started_at",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "gte".to_string(),
+                        ::prisma_client_rust::PrismaValue::DateTime(value),
+                    )]),
+                ),
+                Self::StartedAtNot(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "started_at",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "not".to_string(),
+                        ::prisma_client_rust::PrismaValue::DateTime(value),
+                    )]),
+                ),
+                Self::ConfigEquals(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "config",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "equals".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::ConfigInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "config",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "in".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            value
+                                .into_iter()
+                                .map(|v| ::prisma_client_rust::PrismaValue::String(v))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                Self::ConfigNotInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "config",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "notIn".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            value
+                                .into_iter()
+                                .map(|v| ::prisma_client_rust::PrismaValue::String(v))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                Self::ConfigLt(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "config",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "lt".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::ConfigLte(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "config",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "lte".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::ConfigGt(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "config",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "gt".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::ConfigGte(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "config",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "gte".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::ConfigContains(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "config",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "contains".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::ConfigStartsWith(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "config",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "startsWith".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::ConfigEndsWith(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "config",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "endsWith".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::ConfigNot(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "config",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "not".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::GitHashEquals(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "git_hash",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "equals".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::GitHashInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "git_hash",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "in".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            value
+                                .into_iter()
+                                .map(|v| ::prisma_client_rust::PrismaValue::String(v))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                Self::GitHashNotInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "git_hash",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "notIn".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            value
+                                .into_iter()
+                                .map(|v| ::prisma_client_rust::PrismaValue::String(v))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                Self::GitHashLt(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "git_hash",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "lt".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::GitHashLte(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "git_hash",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "lte".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::GitHashGt(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "git_hash",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "gt".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::GitHashGte(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "git_hash",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "gte".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::GitHashContains(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "git_hash",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "contains".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::GitHashStartsWith(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "git_hash",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "startsWith".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::GitHashEndsWith(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "git_hash",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "endsWith".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::GitHashNot(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "git_hash",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "not".to_string(),
+                        ::prisma_client_rust::PrismaValue::String(value),
+                    )]),
+                ),
+                Self::SeedEquals(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "seed",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "equals".to_string(),
+                        ::prisma_client_rust::PrismaValue::BigInt(value),
+                    )]),
+                ),
+                Self::SeedInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "seed",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "in".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            value
+                                .into_iter()
+                                .map(|v| ::prisma_client_rust::PrismaValue::BigInt(v))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                Self::SeedNotInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "seed",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "notIn".to_string(),
+                        ::prisma_client_rust::PrismaValue::List(
+                            value
+                                .into_iter()
+                                .map(|v| ::prisma_client_rust::PrismaValue::BigInt(v))
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                Self::SeedLt(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "seed",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "lt".to_string(),
+                        ::prisma_client_rust::PrismaValue::BigInt(value),
+                    )]),
+                ),
+                Self::SeedLte(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "seed",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "lte".to_string(),
+                        ::prisma_client_rust::PrismaValue::BigInt(value),
+                    )]),
+                ),
+                Self::SeedGt(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "seed",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "gt".to_string(),
+                        ::prisma_client_rust::PrismaValue::BigInt(value),
+                    )]),
+                ),
+                Self::SeedGte(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "seed",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "gte".to_string(),
+                        ::prisma_client_rust::PrismaValue::BigInt(value),
+                    )]),
+                ),
+                Self::SeedNot(value) => ::prisma_client_rust::SerializedWhere::new(
+                    "seed",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "not".to_string(),
+                        ::prisma_client_rust::PrismaValue::BigInt(value),
+                    )]),
+                ),
+                Self::EpisodesSome(where_params) => ::prisma_client_rust::SerializedWhere::new(
+                    "episodes",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "some".to_string(),
+                        ::prisma_client_rust::PrismaValue::Object(
+                            where_params
+                                .into_iter()
+                                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                Self::EpisodesEvery(where_params) => ::prisma_client_rust::SerializedWhere::new(
+                    "episodes",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "every".to_string(),
+                        ::prisma_client_rust::PrismaValue::Object(
+                            where_params
+                                .into_iter()
+                                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                Self::EpisodesNone(where_params) => ::prisma_client_rust::SerializedWhere::new(
+                    "episodes",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "none".to_string(),
+                        ::prisma_client_rust::PrismaValue::Object(
+                            where_params
+                                .into_iter()
+                                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                Self::TransitionsSome(where_params) => ::prisma_client_rust::SerializedWhere::new(
+                    "transitions",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "some".to_string(),
+                        ::prisma_client_rust::PrismaValue::Object(
+                            where_params
+                                .into_iter()
+                                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                Self::TransitionsEvery(where_params) => ::prisma_client_rust::SerializedWhere::new(
+                    "transitions",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "every".to_string(),
+                        ::prisma_client_rust::PrismaValue::Object(
+                            where_params
+                                .into_iter()
+                                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                                .collect(),
+                        ),
+                    )]),
+                ),
+                Self::TransitionsNone(where_params) => ::prisma_client_rust::SerializedWhere::new(
+                    "transitions",
+                    ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+                        "none".to_string(),
+                        ::prisma_client_rust::PrismaValue::Object(
+                            where_params
+                                .into_iter()
+                                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                                .collect(),
+                        ),
+                    )]),
+                ),
+            }
+        }
+    }
+    #[derive(Clone)]
+    pub enum UniqueWhereParam {
+        IdEquals(String),
+    }
+    impl From<UniqueWhereParam> for WhereParam {
+        fn from(value: UniqueWhereParam) -> Self {
+            match value {
+                UniqueWhereParam::IdEquals(value) => Self::IdEquals(value),
+            }
+        }
+    }
+    impl From<::prisma_client_rust::Operator<Self>> for WhereParam {
+        fn from(op: ::prisma_client_rust::Operator<Self>) -> Self {
+            match op {
+                ::prisma_client_rust::Operator::Not(value) => Self::Not(value),
+                ::prisma_client_rust::Operator::And(value) => Self::And(value),
+                ::prisma_client_rust::Operator::Or(value) => Self::Or(value),
+            }
+        }
+    }
+    pub type UniqueArgs = ::prisma_client_rust::UniqueArgs<WithParam>;
+    pub type ManyArgs =
+        ::prisma_client_rust::ManyArgs<WhereParam, WithParam, OrderByParam, UniqueWhereParam>;
+    pub type Count<'a> =
+        ::prisma_client_rust::Count<'a, WhereParam, OrderByParam, UniqueWhereParam>;
+    pub type Create<'a> = ::prisma_client_rust::Create<'a, SetParam, WithParam, Data>;
+    pub type CreateMany<'a> = ::prisma_client_rust::CreateMany<'a, SetParam>;
+    pub type FindUnique<'a> =
+        ::prisma_client_rust::FindUnique<'a, WhereParam, WithParam, SetParam, Data>;
+    pub type FindMany<'a> = ::prisma_client_rust::FindMany<
+        'a,
+        WhereParam,
+        WithParam,
+        OrderByParam,
+        UniqueWhereParam,
+        SetParam,
+        Data,
+    >;
+    pub type FindFirst<'a> = ::prisma_client_rust::FindFirst<
+        'a,
+        WhereParam,
+        WithParam,
+        OrderByParam,
+        UniqueWhereParam,
+        Data,
+    >;
+    pub type Update<'a> = ::prisma_client_rust::Update<'a, WhereParam, WithParam, SetParam, Data>;
+    pub type UpdateMany<'a> = ::prisma_client_rust::UpdateMany<'a, WhereParam, SetParam>;
+    pub type Upsert<'a> = ::prisma_client_rust::Upsert<'a, WhereParam, SetParam, WithParam, Data>;
+    pub type Delete<'a> = ::prisma_client_rust::Delete<'a, WhereParam, WithParam, Data>;
+    pub type DeleteMany<'a> = ::prisma_client_rust::DeleteMany<'a, WhereParam>;
+    pub struct Actions<'a> {
+        pub client: &'a PrismaClient,
+    }
+    impl<'a> Actions<'a> {
+        pub fn find_unique(self, _where: UniqueWhereParam) -> FindUnique<'a> {
+            FindUnique::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Run", _outputs()),
+                _where.into(),
+            )
+        }
+        pub fn find_first(self, _where: Vec<WhereParam>) -> FindFirst<'a> {
+            FindFirst::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Run", _outputs()),
+                _where,
+            )
+        }
+        pub fn find_many(self, _where: Vec<WhereParam>) -> FindMany<'a> {
+            FindMany::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Run", _outputs()),
+                _where,
+            )
+        }
+        pub fn create(
+            self,
+            config: String,
+            git_hash: String,
+            seed: i64,
+            mut _params: Vec<SetParam>,
+        ) -> Create<'a> {
+            _params.push(config::set(config));
+            _params.push(git_hash::set(git_hash));
+            _params.push(seed::set(seed));
+            Create::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Run", _outputs()),
+                _params,
+            )
+        }
+        pub fn create_many(
+            self,
+            data: Vec<(String, String, i64, Vec<SetParam>)>,
+        ) -> CreateMany<'a> {
+            let data = data
+                .into_iter()
+                .map(|(config, git_hash, seed, mut _params)| {
+                    _params.push(config::set(config));
+                    _params.push(git_hash::set(git_hash));
+                    _params.push(seed::set(seed));
+                    _params
+                })
+                .collect();
+            CreateMany::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Run", _outputs()),
+                data,
+            )
+        }
+        pub fn update(self, _where: UniqueWhereParam, _params: Vec<SetParam>) -> Update<'a> {
+            Update::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Run", _outputs()),
+                _where.into(),
+                _params,
+                vec![],
+            )
+        }
+        pub fn update_many(
+            self,
+            _where: Vec<WhereParam>,
+            _params: Vec<SetParam>,
+        ) -> UpdateMany<'a> {
+            UpdateMany::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Run", _outputs()),
+                _where,
+                _params,
+            )
+        }
+        pub fn upsert(
+            self,
+            _where: UniqueWhereParam,
+            (config, git_hash, seed, mut _params): (String, String, i64, Vec<SetParam>),
+            _update: Vec<SetParam>,
+        ) -> Upsert<'a> {
+            _params.push(config::set(config));
+            _params.push(git_hash::set(git_hash));
+            _params.push(seed::set(seed));
+            Upsert::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Run", _outputs()),
+                _where.into(),
+                _params,
+                _update,
+            )
+        }
+        pub fn delete(self, _where: UniqueWhereParam) -> Delete<'a> {
+            Delete::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Run", _outputs()),
+                _where.into(),
+                vec![],
+            )
+        }
+        pub fn delete_many(self, _where: Vec<WhereParam>) -> DeleteMany<'a> {
+            DeleteMany::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Run", _outputs()),
+                _where.into(),
+            )
+        }
+        pub fn count(self, _where: Vec<WhereParam>) -> Count<'a> {
+            Count::new(
+                self.client._new_query_context(),
+                ::prisma_client_rust::QueryInfo::new("Run", _outputs()),
+                vec![],
+            )
+        }
+    }
+}
+pub mod episode {
+    use super::_prisma::*;
+    use super::*;
+    pub mod id {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: String) -> T {
+            Set(value).into()
+        }
+        pub fn equals<T: From<UniqueWhereParam>>(value: String) -> T {
+            UniqueWhereParam::IdEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::Id(direction)
+        }
+        pub fn in_vec(value: Vec<String>) -> WhereParam {
+            WhereParam::IdInVec(value)
+        }
+        pub fn not_in_vec(value: Vec<String>) -> WhereParam {
+            WhereParam::IdNotInVec(value)
+        }
+        pub fn lt(value: String) -> WhereParam {
+            WhereParam::IdLt(value)
+        }
+        pub fn lte(value: String) -> WhereParam {
+            WhereParam::IdLte(value)
+        }
+        pub fn gt(value: String) -> WhereParam {
+            WhereParam::IdGt(value)
+        }
+        pub fn gte(value: String) -> WhereParam {
+            WhereParam::IdGte(value)
+        }
+        pub fn contains(value: String) -> WhereParam {
+            WhereParam::IdContains(value)
+        }
+        pub fn starts_with(value: String) -> WhereParam {
+            WhereParam::IdStartsWith(value)
+        }
+        pub fn ends_with(value: String) -> WhereParam {
+            WhereParam::IdEndsWith(value)
+        }
+        pub fn not(value: String) -> WhereParam {
+            WhereParam::IdNot(value)
+        }
+        pub struct Set(pub String);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetId(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Id(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("id").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Id(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("id").build()
+            }
+        }
+    }
+    pub mod run {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn is(value: Vec<super::super::run::WhereParam>) -> WhereParam {
+            WhereParam::RunIs(value)
+        }
+        pub fn is_not(value: Vec<super::super::run::WhereParam>) -> WhereParam {
+            WhereParam::RunIsNot(value)
+        }
+        pub struct Fetch(pub super::super::run::UniqueArgs);
+        impl Fetch {
+            pub fn with(mut self, params: impl Into<super::super::run::WithParam>) -> Self {
+                self.0 = self.0.with(params.into());
+                self
+            }
+        }
+        impl From<Fetch> for WithParam {
+            fn from(fetch: Fetch) -> Self {
+                WithParam::Run(fetch.0)
+            }
+        }
+        pub fn fetch() -> Fetch {
+            Fetch(super::super::run::UniqueArgs::new())
+        }
+        pub fn connect<T: From<Connect>>(value: super::super::run::UniqueWhereParam) -> T {
+            Connect(value).into()
+        }
+        pub struct Connect(pub super::super::run::UniqueWhereParam);
+        impl From<Connect> for SetParam {
+            fn from(value: Connect) -> Self {
+                Self::ConnectRun(value.0)
+            }
+        }
+        pub enum Include {
+            Select(Vec<super::super::run::SelectParam>),
+            Include(Vec<super::super::run::IncludeParam>),
+            Fetch,
+        }
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Run(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                let mut builder = ::prisma_client_rust::Selection::builder("run");
+                let selections = match self {
+                    Self::Select(selections) => {
+                        selections.into_iter().map(|s| s.to_selection()).collect()
+                    }
+                    Self::Include(selections) => {
+                        let mut nested = super::super::run::_outputs();
+                        nested.extend(selections.into_iter().map(|s| s.to_selection()));
+                        nested
+                    }
+                    Self::Fetch => super::super::run::_outputs(),
+                };
+                builder.nested_selections(selections);
+                builder.build()
+            }
+        }
+        pub enum Select {
+            Select(Vec<super::super::run::SelectParam>),
+            Include(Vec<super::super::run::IncludeParam>),
+            Fetch,
+        }
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Run(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                let mut builder = ::prisma_client_rust::Selection::builder("run");
+                let selections = match self {
+                    Self::Select(selections) => {
+                        selections.into_iter().map(|s| s.to_selection()).collect()
+                    }
+                    Self::Include(selections) => {
+                        let mut nested = super::super::run::_outputs();
+                        nested.extend(selections.into_iter().map(|s| s.to_selection()));
+                        nested
+                    }
+                    Self::Fetch => super::super::run::_outputs(),
+                };
+                builder.nested_selections(selections);
+                builder.build()
+            }
+        }
+    }
+    pub mod run_id {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: String) -> T {
+            Set(value).into()
+        }
+        pub fn equals(value: String) -> WhereParam {
+            WhereParam::RunIdEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::RunId(direction)
+        }
+        pub fn in_vec(value: Vec<String>) -> WhereParam {
+            WhereParam::RunIdInVec(value)
+        }
+        pub fn not_in_vec(value: Vec<String>) -> WhereParam {
+            WhereParam::RunIdNotInVec(value)
+        }
+        pub fn lt(value: String) -> WhereParam {
+            WhereParam::RunIdLt(value)
+        }
+        pub fn lte(value: String) -> WhereParam {
+            WhereParam::RunIdLte(value)
+        }
+        pub fn gt(value: String) -> WhereParam {
+            WhereParam::RunIdGt(value)
+        }
+        pub fn gte(value: String) -> WhereParam {
+            WhereParam::RunIdGte(value)
+        }
+        pub fn contains(value: String) -> WhereParam {
+            WhereParam::RunIdContains(value)
+        }
+        pub fn starts_with(value: String) -> WhereParam {
+            WhereParam::RunIdStartsWith(value)
+        }
+        pub fn ends_with(value: String) -> WhereParam {
+            WhereParam::RunIdEndsWith(value)
+        }
+        pub fn not(value: String) -> WhereParam {
+            WhereParam::RunIdNot(value)
+        }
+        pub struct Set(pub String);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetRunId(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::RunId(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("run_id").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::RunId(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("run_id").build()
+            }
+        }
+    }
+    pub mod index {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: i32) -> T {
+            Set(value).into()
+        }
+        pub fn equals(value: i32) -> WhereParam {
+            WhereParam::IndexEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::Index(direction)
+        }
+        pub fn in_vec(value: Vec<i32>) -> WhereParam {
+            WhereParam::IndexInVec(value)
+        }
+        pub fn not_in_vec(value: Vec<i32>) -> WhereParam {
+            WhereParam::IndexNotInVec(value)
+        }
+        pub fn lt(value: i32) -> WhereParam {
+            WhereParam::IndexLt(value)
+        }
+        pub fn lte(value: i32) -> WhereParam {
+            WhereParam::IndexLte(value)
+        }
+        pub fn gt(value: i32) -> WhereParam {
+            WhereParam::IndexGt(value)
+        }
+        pub fn gte(value: i32) -> WhereParam {
+            WhereParam::IndexGte(value)
+        }
+        pub fn not(value: i32) -> WhereParam {
+            WhereParam::IndexNot(value)
+        }
+        pub fn increment(value: i32) -> SetParam {
+            SetParam::IncrementIndex(value)
+        }
+        pub fn decrement(value: i32) -> SetParam {
+            SetParam::DecrementIndex(value)
+        }
+        pub fn multiply(value: i32) -> SetParam {
+            SetParam::MultiplyIndex(value)
+        }
+        pub fn divide(value: i32) -> SetParam {
+            SetParam::DivideIndex(value)
+        }
+        pub struct Set(pub i32);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetIndex(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Index(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("index").build()
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Index(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("index").build()
+            }
+        }
+    }
+    pub mod reward {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
+        pub fn set<T: From<Set>>(value: f64) -> T {
+            Set(value).into()
+        }
+        pub fn equals(value: f64) -> WhereParam {
+            WhereParam::RewardEquals(value).into()
+        }
+        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+            OrderByParam::Reward(direction)
+        }
+        pub fn in_vec(value: Vec<f64>) -> WhereParam {
+            WhereParam::RewardInVec(value)
+        }
+        pub fn not_in_vec(value: Vec<f64>) -> WhereParam {
+            WhereParam::RewardNotInVec(value)
+        }
+        pub fn lt(value: f64) -> WhereParam {
+            WhereParam::RewardLt(value)
+        }
+        pub fn lte(value: f64) -> WhereParam {
+            WhereParam::RewardLte(value)
+        }
+        pub fn gt(value: f64) -> WhereParam {
+            WhereParam::RewardGt(value)
+        }
+        pub fn gte(value: f64) -> WhereParam {
+            WhereParam::RewardGte(value)
+        }
+        pub fn not(value: f64) -> WhereParam {
+            WhereParam::RewardNot(value)
+        }
+        pub fn increment(value: f64) -> SetParam {
+            SetParam::IncrementReward(value)
+        }
+        pub fn decrement(value: f64) -> SetParam {
+            SetParam::DecrementReward(value)
+        }
+        pub fn multiply(value: f64) -> SetParam {
+            SetParam::MultiplyReward(value)
+        }
+        pub fn divide(value: f64) -> SetParam {
+            SetParam::DivideReward(value)
+        }
+        pub struct Set(pub f64);
+        impl From<Set> for SetParam {
+            fn from(value: Set) -> Self {
+                Self::SetReward(value.0)
+            }
+        }
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Reward(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::Selection::builder("reward").build()
             }
         }
         pub struct Select;
         impl Into<super::SelectParam> for Select {
             fn into(self) -> super::SelectParam {
-                super::SelectParam::Action(self)
+                super::SelectParam::Reward(self)
             }
         }
         impl Select {
             pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-                ::prisma_client_rust::Selection::builder("action").build()
+                ::prisma_client_rust::Selection::builder("reward").build()
             }
         }
     }
-    pub mod reward {
+    pub mod length {
         use super::super::*;
         use super::_prisma::*;
         use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
-        pub fn set<T: From<Set>>(value: f64) -> T {
+        pub fn set<T: From<Set>>(value: i32) -> T {
             Set(value).into()
         }
-        pub fn equals(value: f64) -> WhereParam {
-            WhereParam::RewardEquals(value).into()
+        pub fn equals(value: i32) -> WhereParam {
+            WhereParam::LengthEquals(value).into()
         }
         pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
-            OrderByParam::Reward(direction)
+            OrderByParam::Length(direction)
         }
-        pub fn in_vec(value: Vec<f64>) -> WhereParam {
-            WhereParam::RewardInVec(value)
+        pub fn in_vec(value: Vec<i32>) -> WhereParam {
+            WhereParam::LengthInVec(value)
         }
-        pub fn not_in_vec(value: Vec<f64>) -> WhereParam {
-            WhereParam::RewardNotInVec(value)
+        pub fn not_in_vec(value: Vec<i32>) -> WhereParam {
+            WhereParam::LengthNotInVec(value)
         }
-        pub fn lt(value: f64) -> WhereParam {
-            WhereParam::RewardLt(value)
+        pub fn lt(value: i32) -> WhereParam {
+            WhereParam::LengthLt(value)
         }
-        pub fn lte(value: f64) -> WhereParam {
-            WhereParam::RewardLte(value)
+        pub fn lte(value: i32) -> WhereParam {
+            WhereParam::LengthLte(value)
         }
-        pub fn gt(value: f64) -> WhereParam {
-            WhereParam::RewardGt(value)
+        pub fn gt(value: i32) -> WhereParam {
+            WhereParam::LengthGt(value)
         }
-        pub fn gte(value: f64) -> WhereParam {
-            WhereParam::RewardGte(value)
+        pub fn gte(value: i32) -> WhereParam {
+            WhereParam::LengthGte(value)
         }
-        pub fn not(value: f64) -> WhereParam {
-            WhereParam::RewardNot(value)
+        pub fn not(value: i32) -> WhereParam {
+            WhereParam::LengthNot(value)
         }
-        pub fn increment(value: f64) -> SetParam {
-            SetParam::IncrementReward(value)
+        pub fn increment(value: i32) -> SetParam {
+            SetParam::IncrementLength(value)
         }
-        pub fn decrement(value: f64) -> SetParam {
-            SetParam::DecrementReward(value)
+        pub fn decrement(value: i32) -> SetParam {
+            SetParam::DecrementLength(value)
         }
-        pub fn multiply(value: f64) -> SetParam {
-            SetParam::MultiplyReward(value)
+        pub fn multiply(value: i32) -> SetParam {
+            SetParam::MultiplyLength(value)
         }
-        pub fn divide(value: f64) -> SetParam {
-            SetParam::DivideReward(value)
+        pub fn divide(value: i32) -> SetParam {
+            SetParam::DivideLength(value)
         }
-        pub struct Set(pub f64);
+        pub struct Set(pub i32);
         impl From<Set> for SetParam {
             fn from(value: Set) -> Self {
-                Self::SetReward(value.0)
+                Self::SetLength(value.0)
             }
         }
         pub struct Include;
         impl Into<super::IncludeParam> for Include {
             fn into(self) -> super::IncludeParam {
-                super::IncludeParam::Reward(self)
+                super::IncludeParam::Length(self)
             }
         }
         impl Include {
             pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-                ::prisma_client_rust::Selection::builder("reward").build()
+                ::prisma_client_rust::Selection::builder("length").build()
             }
         }
         pub struct Select;
         impl Into<super::SelectParam> for Select {
             fn into(self) -> super::SelectParam {
-                super::SelectParam::Reward(self)
+                super::SelectParam::Length(self)
             }
         }
         impl Select {
             pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-                ::prisma_client_rust::Selection::builder("reward").build()
+                ::prisma_client_rust::Selection::builder("length").build()
             }
         }
     }
-    pub mod next_state {
+    pub mod termination {
         use super::super::*;
         use super::_prisma::*;
         use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
-        pub fn set<T: From<Set>>(value: String) -> T {
+        pub fn set<T: From<Set>>(value: Option<String>) -> T {
             Set(value).into()
         }
-        pub fn equals(value: String) -> WhereParam {
-            WhereParam::NextStateEquals(value).into()
+        pub fn equals(value: Option<String>) -> WhereParam {
+            WhereParam::TerminationEquals(value).into()
         }
         pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
-            OrderByParam::NextState(direction)
+            OrderByParam::Termination(direction)
         }
         pub fn in_vec(value: Vec<String>) -> WhereParam {
-            WhereParam::NextStateInVec(value)
+            WhereParam::TerminationInVec(value)
         }
         pub fn not_in_vec(value: Vec<String>) -> WhereParam {
-            WhereParam::NextStateNotInVec(value)
+            WhereParam::TerminationNotInVec(value)
         }
         pub fn lt(value: String) -> WhereParam {
-            WhereParam::NextStateLt(value)
+            WhereParam::TerminationLt(value)
         }
         pub fn lte(value: String) -> WhereParam {
-            WhereParam::NextStateLte(value)
+            WhereParam::TerminationLte(value)
         }
         pub fn gt(value: String) -> WhereParam {
-            WhereParam::NextStateGt(value)
+            WhereParam::TerminationGt(value)
         }
         pub fn gte(value: String) -> WhereParam {
-            WhereParam::NextStateGte(value)
+            WhereParam::TerminationGte(value)
         }
         pub fn contains(value: String) -> WhereParam {
-            WhereParam::NextStateContains(value)
+            WhereParam::TerminationContains(value)
         }
         pub fn starts_with(value: String) -> WhereParam {
-            WhereParam::NextStateStartsWith(value)
+            WhereParam::TerminationStartsWith(value)
         }
         pub fn ends_with(value: String) -> WhereParam {
-            WhereParam::NextStateEndsWith(value)
+            WhereParam::TerminationEndsWith(value)
         }
-        pub fn not(value: String) -> WhereParam {
-            WhereParam::NextStateNot(value)
+        pub fn not(value: Option<String>) -> WhereParam {
+            WhereParam::TerminationNot(value)
         }
-        pub struct Set(pub String);
+        pub struct Set(pub Option<String>);
         impl From<Set> for SetParam {
             fn from(value: Set) -> Self {
-                Self::SetNextState(value.0)
+                Self::SetTermination(value.0)
             }
         }
         pub struct Include;
         impl Into<super::IncludeParam> for Include {
             fn into(self) -> super::IncludeParam {
-                super::IncludeParam::NextState(self)
+                super::IncludeParam::Termination(self)
             }
         }
         impl Include {
             pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-                ::prisma_client_rust::Selection::builder("next_state").build()
+                ::prisma_client_rust::Selection::builder("termination").build()
             }
         }
         pub struct Select;
         impl Into<super::SelectParam> for Select {
             fn into(self) -> super::SelectParam {
-                super::SelectParam::NextState(self)
+                super::SelectParam::Termination(self)
             }
         }
         impl Select {
             pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-                ::prisma_client_rust::Selection::builder("next_state").build()
+                ::prisma_client_rust::Selection::builder("termination").build()
             }
         }
     }
-    pub mod done {
+    pub mod transitions {
         use super::super::*;
         use super::_prisma::*;
         use super::{OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam};
-        pub fn set<T: From<Set>>(value: bool) -> T {
-            Set(value).into()
+        pub fn some(value: Vec<super::super::rb::WhereParam>) -> WhereParam {
+            WhereParam::TransitionsSome(value)
         }
-        pub fn equals(value: bool) -> WhereParam {
-            WhereParam::DoneEquals(value).into()
+        pub fn every(value: Vec<super::super::rb::WhereParam>) -> WhereParam {
+            WhereParam::TransitionsEvery(value)
         }
-        pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
-            OrderByParam::Done(direction)
+        pub fn none(value: Vec<super::super::rb::WhereParam>) -> WhereParam {
+            WhereParam::TransitionsNone(value)
         }
-        pub struct Set(pub bool);
-        impl From<Set> for SetParam {
-            fn from(value: Set) -> Self {
-                Self::SetDone(value.0)
+        pub struct Fetch(pub super::super::rb::ManyArgs);
+        impl Fetch {
+            pub fn with(mut self, params: impl Into<super::super::rb::WithParam>) -> Self {
+                self.0 = self.0.with(params.into());
+                self
+            }
+            pub fn order_by(mut self, param: super::super::rb::OrderByParam) -> Self {
+                self.0 = self.0.order_by(param);
+                self
+            }
+            pub fn skip(mut self, value: i64) -> Self {
+                self.0 = self.0.skip(value);
+                self
+            }
+            pub fn take(mut self, value: i64) -> Self {
+                self.0 = self.0.take(value);
+                self
             }
         }
-        pub struct Include;
+        impl From<Fetch> for WithParam {
+            fn from(fetch: Fetch) -> Self {
+                WithParam::Transitions(fetch.0)
+            }
+        }
+        pub fn fetch(params: Vec<super::super::rb::WhereParam>) -> Fetch {
+            Fetch(super::super::rb::ManyArgs::new(params))
+        }
+        pub fn connect<T: From<Connect>>(params: Vec<super::super::rb::UniqueWhereParam>) -> T {
+            Connect(params).into()
+        }
+        pub fn disconnect(params: Vec<super::super::rb::UniqueWhereParam>) -> SetParam {
+            SetParam::DisconnectTransitions(params)
+        }
+        pub fn set(params: Vec<super::super::rb::UniqueWhereParam>) -> SetParam {
+            SetParam::SetTransitions(params)
+        }
+        pub struct Connect(pub Vec<super::super::rb::UniqueWhereParam>);
+        impl From<Connect> for SetParam {
+            fn from(value: Connect) -> Self {
+                Self::ConnectTransitions(value.0)
+            }
+        }
+        pub enum Include {
+            Select(Vec<super::super::rb::SelectParam>),
+            Include(Vec<super::super::rb::IncludeParam>),
+            Fetch,
+        }
         impl Into<super::IncludeParam> for Include {
             fn into(self) -> super::IncludeParam {
-                super::IncludeParam::Done(self)
+                super::IncludeParam::Transitions(self)
             }
         }
         impl Include {
             pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-                ::prisma_client_rust::Selection::builder("done").build()
+                let mut builder = ::prisma_client_rust::Selection::builder("transitions");
+                let selections = match self {
+                    Self::Select(selections) => {
+                        selections.into_iter().map(|s| s.to_selection()).collect()
+                    }
+                    Self::Include(selections) => {
+                        let mut nested = super::super::rb::_outputs();
+                        nested.extend(selections.into_iter().map(|s| s.to_selection()));
+                        nested
+                    }
+                    Self::Fetch => super::super::rb::_outputs(),
+                };
+                builder.nested_selections(selections);
+                builder.build()
             }
         }
-        pub struct Select;
+        pub enum Select {
+            Select(Vec<super::super::rb::SelectParam>),
+            Include(Vec<super::super::rb::IncludeParam>),
+            Fetch,
+        }
         impl Into<super::SelectParam> for Select {
             fn into(self) -> super::SelectParam {
-                super::SelectParam::Done(self)
+                super::SelectParam::Transitions(self)
             }
         }
         impl Select {
             pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-                ::prisma_client_rust::Selection::builder("done").build()
+                let mut builder = ::prisma_client_rust::Selection::builder("transitions");
+                let selections = match self {
+                    Self::Select(selections) => {
+                        selections.into_iter().map(|s| s.to_selection()).collect()
+                    }
+                    Self::Include(selections) => {
+                        let mut nested = super::super::rb::_outputs();
+                        nested.extend(selections.into_iter().map(|s| s.to_selection()));
+                        nested
+                    }
+                    Self::Fetch => super::super::rb::_outputs(),
+                };
+                builder.nested_selections(selections);
+                builder.build()
             }
         }
     }
     pub fn _outputs() -> Vec<::prisma_client_rust::Selection> {
-        [
-            "id",
-            "created_at",
-            "state",
-            "action",
-            "reward",
-            "next_state",
-            "done",
-        ]
-        .into_iter()
-        .map(|o| {
-            let builder = ::prisma_client_rust::Selection::builder(o);
-            builder.build()
-        })
-        .collect()
+        ["id", "run_id", "index", "reward", "length", "termination"]
+            .into_iter()
+            .map(|o| {
+                let builder = ::prisma_client_rust::Selection::builder(o);
+                builder.build()
+            })
+            .collect()
     }
     pub fn create(
-        state: String,
-        action: i32,
+        run: super::run::UniqueWhereParam,
+        index: i32,
         reward: f64,
-        next_state: String,
-        done: bool,
+        length: i32,
         _params: Vec<SetParam>,
-    ) -> (String, i32, f64, String, bool, Vec<SetParam>) {
-        (state, action, reward, next_state, done, _params)
+    ) -> (super::run::UniqueWhereParam, i32, f64, i32, Vec<SetParam>) {
+        (run, index, reward, length, _params)
     }
     #[macro_export]
-    macro_rules ! _select_rb { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: rb :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: rb :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn select ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Select { Select ($ crate :: prisma :: rb :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: rb :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: rb :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Select ($ crate :: prisma :: rb :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { id , created_at , state , action , reward , next_state , done } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { $ (pub $ field : $ crate :: prisma :: rb :: select ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (created_at) , stringify ! (state) , stringify ! (action) , stringify ! (reward) , stringify ! (next_state) , stringify ! (done)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: rb :: select ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: rb :: select ! (@ field_serde_name ; $ field) , ", ") , +)) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: rb :: select ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * while let Some (key) = map . next_key () ? { match key { $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: select ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: select ! (@ field_serde_name ; $ field))) ? ;) * Ok (Data { $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "created_at" , "state" , "action" , "reward" , "next_state" , "done"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: rb :: select ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; id) => { String } ; (@ field_type ; created_at) => { :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > } ; (@ field_type ; state) => { String } ; (@ field_type ; action) => { i32 } ; (@ field_type ; reward) => { f64 } ; (@ field_type ; next_state) => { String } ; (@ field_type ; done) => { bool } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot select field nonexistent field $ field on model "Rb" , available fields are "id, created_at, state, action, reward, next_state, done")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; id) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: id :: Select) } ; (@ selection_field_to_selection_param ; created_at) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: created_at :: Select) } ; (@ selection_field_to_selection_param ; state) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: state :: Select) } ; (@ selection_field_to_selection_param ; action) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: action :: Select) } ; (@ selection_field_to_selection_param ; reward) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: reward :: Select) } ; (@ selection_field_to_selection_param ; next_state) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: next_state :: Select) } ; (@ selection_field_to_selection_param ; done) => { Into :: < $ crate :: prisma :: rb :: SelectParam > :: into ($ crate :: prisma :: rb :: done :: Select) } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_select_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: rb :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; created_at) => { "created_at" } ; (@ field_serde_name ; state) => { "state" } ; (@ field_serde_name ; action) => { "action" } ; (@ field_serde_name ; reward) => { "reward" } ; (@ field_serde_name ; next_state) => { "next_state" } ; (@ field_serde_name ; done) => { "done" } ; }
-    pub use _select_rb as select;
+    macro_rules ! _select_episode { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: episode :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: episode :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn select ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Select { Select ($ crate :: prisma :: episode :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: episode :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: episode :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Select ($ crate :: prisma :: episode :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { id , run_id , index , reward , length , termination } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { $ (pub $ field : $ crate :: prisma :: episode :: select ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (run_id) , stringify ! (index) , stringify ! (reward) , stringify ! (length) , stringify ! (termination)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: episode :: select ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: episode :: select ! (@ field_serde_name ; $ field) , ", ") , +)) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: episode :: select ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * while let Some (key) = map . next_key () ? { match key { $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: episode :: select ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: episode :: select ! (@ field_serde_name ; $ field))) ? ;) * Ok (Data { $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "run_id" , "index" , "reward" , "length" , "termination"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: episode :: select ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; id) => { String } ; (@ field_type ; run_id) => { String } ; (@ field_type ; index) => { i32 } ; (@ field_type ; reward) => { f64 } ; (@ field_type ; length) => { i32 } ; (@ field_type ; termination) => { Option < String > } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot select field nonexistent field $ field on model "Episode" , available fields are "id, run_id, index, reward, length, termination")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; id) => { Into :: < $ crate :: prisma :: episode :: SelectParam > :: into ($ crate :: prisma :: episode :: id :: Select) } ; (@ selection_field_to_selection_param ; run_id) => { Into :: < $ crate :: prisma :: episode :: SelectParam > :: into ($ crate :: prisma :: episode :: run_id :: Select) } ; (@ selection_field_to_selection_param ; index) => { Into :: < $ crate :: prisma :: episode :: SelectParam > :: into ($ crate :: prisma :: episode :: index :: Select) } ; (@ selection_field_to_selection_param ; reward) => { Into :: < $ crate :: prisma :: episode :: SelectParam > :: into ($ crate :: prisma :: episode :: reward :: Select) } ; (@ selection_field_to_selection_param ; length) => { Into :: < $ crate :: prisma :: episode :: SelectParam > :: into ($ crate :: prisma :: episode :: length :: Select) } ; (@ selection_field_to_selection_param ; termination) => { Into :: < $ crate :: prisma :: episode :: SelectParam > :: into ($ crate :: prisma :: episode :: termination :: Select) } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_select_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: episode :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; run_id) => { "run_id" } ; (@ field_serde_name ; index) => { "index" } ; (@ field_serde_name ; reward) => { "reward" } ; (@ field_serde_name ; length) => { "length" } ; (@ field_serde_name ; termination) => { "termination" } ; }
+    pub use _select_episode as select;
     pub enum SelectParam {
         Id(id::Select),
-        CreatedAt(created_at::Select),
-        State(state::Select),
-        Action(action::Select),
+        Run(run::Select),
+        RunId(run_id::Select),
+        Index(index::Select),
         Reward(reward::Select),
-        NextState(next_state::Select),
-        Done(done::Select),
+        Length(length::Select),
+        Termination(termination::Select),
+        Transitions(transitions::Select),
     }
     impl SelectParam {
         pub fn to_selection(self) -> ::prisma_client_rust::Selection {
             match self {
                 Self::Id(data) => data.to_selection(),
-                Self::CreatedAt(data) => data.to_selection(),
-                Self::State(data) => data.to_selection(),
-                Self::Action(data) => data.to_selection(),
+                Self::Run(data) => data.to_selection(),
+                Self::RunId(data) => data.to_selection(),
+                Self::Index(data) => data.to_selection(),
                 Self::Reward(data) => data.to_selection(),
-                Self::NextState(data) => data.to_selection(),
-                Self::Done(data) => data.to_selection(),
+                Self::Length(data) => data.to_selection(),
+                Self::Termination(data) => data.to_selection(),
+                Self::Transitions(data) => data.to_selection(),
             }
         }
     }
     #[macro_export]
-    macro_rules ! _include_rb { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: rb :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: rb :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn include ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Include { let mut selections = $ crate :: prisma :: rb :: _outputs () ; selections . extend ($ crate :: prisma :: rb :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; Include (selections) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: rb :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: rb :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Include ({ let mut selections = $ crate :: prisma :: rb :: _outputs () ; selections . extend ($ crate :: prisma :: rb :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; selections }) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { pub id : String , pub created_at : :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > , pub state : String , pub action : i32 , pub reward : f64 , pub next_state : String , pub done : bool , $ (pub $ field : $ crate :: prisma :: rb :: include ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (created_at) , stringify ! (state) , stringify ! (action) , stringify ! (reward) , stringify ! (next_state) , stringify ! (done)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; id) , & self . id) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; created_at) , & self . created_at) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; state) , & self . state) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; action) , & self . action) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; reward) , & self . reward) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; next_state) , & self . next_state) ? ; state . serialize_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; done) , & self . done) ? ; state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , id , created_at , state , action , reward , next_state , done } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; $ field) , ", ") , + , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; id) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; created_at) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; state) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; action) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; reward) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; next_state) , ", " , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; done) , ", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; id) => Ok (Field :: id) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; created_at) => Ok (Field :: created_at) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; state) => Ok (Field :: state) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; action) => Ok (Field :: action) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; reward) => Ok (Field :: reward) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; next_state) => Ok (Field :: next_state) , $ crate :: prisma :: rb :: include ! (@ field_serde_name ; done) => Ok (Field :: done) , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * let mut id = None ; let mut created_at = None ; let mut state = None ; let mut action = None ; let mut reward = None ; let mut next_state = None ; let mut done = None ; while let Some (key) = map . next_key () ? { match key { Field :: id => { if id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; id))) ; } id = Some (map . next_value () ?) ; } Field :: created_at => { if created_at . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; created_at))) ; } created_at = Some (map . next_value () ?) ; } Field :: state => { if state . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; state))) ; } state = Some (map . next_value () ?) ; } Field :: action => { if action . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; action))) ; } action = Some (map . next_value () ?) ; } Field :: reward => { if reward . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; reward))) ; } reward = Some (map . next_value () ?) ; } Field :: next_state => { if next_state . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; next_state))) ; } next_state = Some (map . next_value () ?) ; } Field :: done => { if done . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; done))) ; } done = Some (map . next_value () ?) ; } $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; $ field))) ? ;) * let id = id . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; id))) ? ; let created_at = created_at . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; created_at))) ? ; let state = state . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; state))) ? ; let action = action . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; action))) ? ; let reward = reward . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; reward))) ? ; let next_state = next_state . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; next_state))) ? ; let done = done . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: rb :: include ! (@ field_serde_name ; done))) ? ; Ok (Data { id , created_at , state , action , reward , next_state , done , $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "created_at" , "state" , "action" , "reward" , "next_state" , "done"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: rb :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "Rb" , available relations are "")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_include_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: rb :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; created_at) => { "created_at" } ; (@ field_serde_name ; state) => { "state" } ; (@ field_serde_name ; action) => { "action" } ; (@ field_serde_name ; reward) => { "reward" } ; (@ field_serde_name ; next_state) => { "next_state" } ; (@ field_serde_name ; done) => { "done" } ; }
-    pub use _include_rb as include;
+    macro_rules ! _include_episode { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: episode :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: episode :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn include ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Include { let mut selections = $ crate :: prisma :: episode :: _outputs () ; selections . extend ($ crate :: prisma :: episode :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; Include (selections) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: episode :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: episode :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Include ({ let mut selections = $ crate :: prisma :: episode :: _outputs () ; selections . extend ($ crate :: prisma :: episode :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; selections }) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { pub id : String , pub run_id : String , pub index : i32 , pub reward : f64 , pub length : i32 , pub termination : Option < String > , $ (pub $ field : $ crate :: prisma :: episode :: include ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (run_id) , stringify ! (index) , stringify ! (reward) , stringify ! (length) , stringify ! (termination)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . serialize_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; id) , & self . id) ? ; state . serialize_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; run_id) , & self . run_id) ? ; state . serialize_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; index) , & self . index) ? ; state . serialize_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; reward) , & self . reward) ? ; state . serialize_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; length) , & self . length) ? ; state . serialize_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; termination) , & self . termination) ? ; state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , id , run_id , index , reward , length , termination } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; $ field) , ", ") , + , $ crate :: prisma :: episode :: include ! (@ field_serde_name ; id) , ", " , $ crate :: prisma :: episode :: include ! (@ field_serde_name ; run_id) , ", " , $ crate :: prisma :: episode :: include ! (@ field_serde_name ; index) , ", " , $ crate :: prisma :: episode :: include ! (@ field_serde_name ; reward) , ", " , $ crate :: prisma :: episode :: include ! (@ field_serde_name ; length) , ", " , $ crate :: prisma :: episode :: include ! (@ field_serde_name ; termination) , ", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , $ crate :: prisma :: episode :: include ! (@ field_serde_name ; id) => Ok (Field :: id) , $ crate :: prisma :: episode :: include ! (@ field_serde_name ; run_id) => Ok (Field :: run_id) , $ crate :: prisma :: episode :: include ! (@ field_serde_name ; index) => Ok (Field :: index) , $ crate :: prisma :: episode :: include ! (@ field_serde_name ; reward) => Ok (Field :: reward) , $ crate :: prisma :: episode :: include ! (@ field_serde_name ; length) => Ok (Field :: length) , $ crate :: prisma :: episode :: include ! (@ field_serde_name ; termination) => Ok (Field :: termination) , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * let mut id = None ; let mut run_id = None ; let mut index = None ; let mut reward = None ; let mut length = None ; let mut termination = None ; while let Some (key) = map . next_key () ? { match key { Field :: id => { if id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; id))) ; } id = Some (map . next_value () ?) ; } Field :: run_id => { if run_id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; run_id))) ; } run_id = Some (map . next_value () ?) ; } Field :: index => { if index . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; index))) ; } index = Some (map . next_value () ?) ; } Field :: reward => { if reward . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; reward))) ; } reward = Some (map . next_value () ?) ; } Field :: length => { if length . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; length))) ; } length = Some (map . next_value () ?) ; } Field :: termination => { if termination . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; termination))) ; } termination = Some (map . next_value () ?) ; } $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; $ field))) ? ;) * let id = id . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; id))) ? ; let run_id = run_id . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; run_id))) ? ; let index = index . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; index))) ? ; let reward = reward . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; reward))) ? ; let length = length . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; length))) ? ; let termination = termination . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: episode :: include ! (@ field_serde_name ; termination))) ? ; Ok (Data { id , run_id , index , reward , length , termination , $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "run_id" , "index" , "reward" , "length" , "termination"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: episode :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "Episode" , available relations are "run, transitions")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_include_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: episode :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; run_id) => { "run_id" } ; (@ field_serde_name ; index) => { "index" } ; (@ field_serde_name ; reward) => { "reward" } ; (@ field_serde_name ; length) => { "length" } ; (@ field_serde_name ; termination) => { "termination" } ; }
+    pub use _include_episode as include;
     pub enum IncludeParam {
         Id(id::Include),
-        CreatedAt(created_at::Include),
-        State(state::Include),
-        Action(action::Include),
+        Run(run::Include),
+        RunId(run_id::Include),
+        Index(index::Include),
         Reward(reward::Include),
-        NextState(next_state::Include),
-        Done(done::Include),
+        Length(length::Include),
+        Termination(termination::Include),
+        Transitions(transitions::Include),
     }
     impl IncludeParam {
         pub fn to_selection(self) -> ::prisma_client_rust::Selection {
             match self {
                 Self::Id(data) => data.to_selection(),
-                Self::CreatedAt(data) => data.to_selection(),
-                Self::State(data) => data.to_selection(),
-                Self::Action(data) => data.to_selection(),
+                Self::Run(data) => data.to_selection(),
+                Self::RunId(data) => data.to_selection(),
+                Self::Index(data) => data.to_selection(),
                 Self::Reward(data) => data.to_selection(),
-                Self::NextState(data) => data.to_selection(),
-                Self::Done(data) => data.to_selection(),
+                Self::Length(data) => data.to_selection(),
+                Self::Termination(data) => data.to_selection(),
+                Self::Transitions(data) => data.to_selection(),
             }
         }
     }
@@ -644,62 +4115,111 @@ pub mod rb {
     pub struct Data {
         #[serde(rename = "id")]
         pub id: String,
-        #[serde(rename = "created_at")]
-        pub created_at:
-            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
-        #[serde(rename = "state")]
-        pub state: String,
-        #[serde(rename = "action")]
-        pub action: i32,
+        #[serde(rename = "run", skip_serializing_if = "Option::is_none")]
+        pub run: Option<Box<super::run::Data>>,
+        #[serde(rename = "run_id")]
+        pub run_id: String,
+        #[serde(rename = "index")]
+        pub index: i32,
         #[serde(rename = "reward")]
         pub reward: f64,
-        #[serde(rename = "next_state")]
-        pub next_state: String,
-        #[serde(rename = "done")]
-        pub done: bool,
+        #[serde(rename = "length")]
+        pub length: i32,
+        #[serde(rename = "termination")]
+        pub termination: Option<String>,
+        #[serde(rename = "transitions", skip_serializing_if = "Option::is_none")]
+        pub transitions: Option<Vec<super::rb::Data>>,
+    }
+    impl Data {
+        pub fn run(
+            &self,
+        ) -> Result<&super::run::Data, ::prisma_client_rust::RelationNotFetchedError> {
+            self.run
+                .as_ref()
+                .ok_or(::prisma_client_rust::RelationNotFetchedError::new(
+                    stringify!(run),
+                ))
+                .map(|v| v.as_ref())
+        }
+        pub fn transitions(
+            &self,
+        ) -> Result<&Vec<super::rb::Data>, ::prisma_client_rust::RelationNotFetchedError> {
+            self.transitions
+                .as_ref()
+                .ok_or(::prisma_client_rust::RelationNotFetchedError::new(
+                    stringify!(transitions),
+                ))
+        }
     }
-    impl Data {}
     #[derive(Clone)]
-    pub enum WithParam {}
+    pub enum WithParam {
+        Run(super::run::UniqueArgs),
+        Transitions(super::rb::ManyArgs),
+    }
     impl Into<::prisma_client_rust::Selection> for WithParam {
         fn into(self) -> ::prisma_client_rust::Selection {
-            match self {}
+            match self {
+                Self::Run(args) => {
+                    let mut selections = super::run::_outputs();
+                    selections.extend(
+                        args.with_params
+                            .into_iter()
+                            .map(Into::<::prisma_client_rust::Selection>::into),
+                    );
+                    let mut builder = ::prisma_client_rust::Selection::builder("run");
+                    builder.nested_selections(selections);
+                    builder.build()
+                }
+                Self::Transitions(args) => {
+                    let (arguments, nested_selections) = args.to_graphql();
+                    let mut selections = super::rb::_outputs();
+                    selections.extend(nested_selections);
+                    let mut builder = ::prisma_client_rust::Selection::builder("transitions");
+                    builder.set_arguments(arguments);
+                    builder.nested_selections(selections);
+                    builder.build()
+                }
+            }
         }
     }
     #[derive(Clone)]
     pub enum SetParam {
         SetId(String),
-        SetCreatedAt(
-            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
-        ),
-        SetState(String),
-        SetAction(i32),
-        IncrementAction(i32),
-        DecrementAction(i32),
-        MultiplyAction(i32),
-        DivideAction(i32),
+        ConnectRun(super::run::UniqueWhereParam),
+        SetRunId(String),
+        SetIndex(i32),
+        IncrementIndex(i32),
+        DecrementIndex(i32),
+        MultiplyIndex(i32),
+        DivideIndex(i32),
         SetReward(f64),
         IncrementReward(f64),
         DecrementReward(f64),
         MultiplyReward(f64),
         DivideReward(f64),
-        SetNextState(String),
-        SetDone(bool),
+        SetLength(i32),
+        IncrementLength(i32),
+        DecrementLength(i32),
+        MultiplyLength(i32),
+        DivideLength(i32),
+        SetTermination(Option<String>),
+        ConnectTransitions(Vec<super::rb::UniqueWhereParam>),
+        DisconnectTransitions(Vec<super::rb::UniqueWhereParam>),
+        SetTransitions(Vec<super::rb::UniqueWhereParam>),
     }
     impl Into<(String, ::prisma_client_rust::PrismaValue)> for SetParam {
         fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
-            match self { SetParam :: SetId (value) => ("id" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: SetCreatedAt (value) => ("created_at" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value)) , SetParam :: SetState (value) => ("state" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: SetAction (value) => ("action" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64)) , SetParam :: IncrementAction (value) => ("action" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DecrementAction (value) => ("action" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: MultiplyAction (value) => ("action" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DivideAction (value) => ("action" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: SetReward (value) => ("reward" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ())) , SetParam :: IncrementReward (value) => ("reward" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DecrementReward (value) => ("reward" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: MultiplyReward (value) => ("reward" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DivideReward (value) => ("reward" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: SetNextState (value) => ("next_state" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: SetDone (value) => ("done" . to_string () , :: prisma_client_rust :: PrismaValue :: Boolean (value)) }
+            match self { SetParam :: SetId (value) => ("id" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: ConnectRun (where_param) => ("run" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("connect" . to_string () , :: prisma_client_rust :: PrismaValue :: Object ([where_param] . into_iter () . map (Into :: < super :: run :: WhereParam > :: into) . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . collect ()))])) , SetParam :: SetRunId (value) => ("run_id" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: SetIndex (value) => ("index" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64)) , SetParam :: IncrementIndex (value) => ("index" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DecrementIndex (value) => ("index" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: MultiplyIndex (value) => ("index" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DivideIndex (value) => ("index" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: SetReward (value) => ("reward" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ())) , SetParam :: IncrementReward (value) => ("reward" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DecrementReward (value) => ("reward" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: MultiplyReward (value) => ("reward" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DivideReward (value) => ("reward" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: SetLength (value) => ("length" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64)) , SetParam :: IncrementLength (value) => ("length" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DecrementLength (value) => ("length" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: MultiplyLength (value) => ("length" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DivideLength (value) => ("length" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: SetTermination (value) => ("termination" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: String (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: ConnectTransitions (where_params) => ("transitions" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("connect" . to_string () , :: prisma_client_rust :: PrismaValue :: List (where_params . into_iter () . map (Into :: < super :: rb :: WhereParam > :: into) . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . map (| v | :: prisma_client_rust :: PrismaValue :: Object (vec ! [v])) . collect ()))])) , SetParam :: DisconnectTransitions (where_params) => ("transitions" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("disconnect" . to_string () , :: prisma_client_rust :: PrismaValue :: List (where_params . into_iter () . map (Into :: < super :: rb :: WhereParam > :: into) . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . map (| v | :: prisma_client_rust :: PrismaValue :: Object (vec ! [v])) . collect ()))])) , SetParam :: SetTransitions (where_params) => ("transitions" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("set" . to_string () , :: prisma_client_rust :: PrismaValue :: List (where_params . into_iter () . map (Into :: < super :: rb :: WhereParam > :: into) . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . map (| v | :: prisma_client_rust :: PrismaValue :: Object (vec ! [v])) . collect ()))])) }
         }
     }
     #[derive(Clone)]
     pub enum OrderByParam {
         Id(::prisma_client_rust::Direction),
-        CreatedAt(::prisma_client_rust::Direction),
-        State(::prisma_client_rust::Direction),
-        Action(::prisma_client_rust::Direction),
+        RunId(::prisma_client_rust::Direction),
+        Index(::prisma_client_rust::Direction),
         Reward(::prisma_client_rust::Direction),
-        NextState(::prisma_client_rust::Direction),
-        Done(::prisma_client_rust::Direction),
+        Length(::prisma_client_rust::Direction),
+        Termination(::prisma_client_rust::Direction),
     }
     impl Into<(String, ::prisma_client_rust::PrismaValue)> for OrderByParam {
         fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
@@ -708,28 +4228,24 @@ pub mod rb {
                     "id".to_string(),
                     ::prisma_client_rust::PrismaValue::String(direction.to_string()),
                 ),
-                Self::CreatedAt(direction) => (
-                    "created_at".to_string(),
-                    ::prisma_client_rust::PrismaValue::String(direction.to_string()),
-                ),
-                Self::State(direction) => (
-                    "state".to_string(),
+                Self::RunId(direction) => (
+                    "run_id".to_string(),
                     ::prisma_client_rust::PrismaValue::String(direction.to_string()),
                 ),
-                Self::Action(direction) => (
-                    "action".to_string(),
+                Self::Index(direction) => (
+                    "index".to_string(),
                     ::prisma_client_rust::PrismaValue::String(direction.to_string()),
                 ),
                 Self::Reward(direction) => (
                     "reward".to_string(),
                     ::prisma_client_rust::PrismaValue::String(direction.to_string()),
                 ),
-                Self::NextState(direction) => (
-                    "next_state".to_string(),
+                Self::Length(direction) => (
+                    "length".to_string(),
                     ::prisma_client_rust::PrismaValue::String(direction.to_string()),
                 ),
-                Self::Done(direction) => (
-                    "done".to_string(),
+                Self::Termination(direction) => (
+                    "termination".to_string(),
                     ::prisma_client_rust::PrismaValue::String(direction.to_string()),
                 ),
             }
@@ -751,49 +4267,27 @@ pub mod rb {
         IdStartsWith(String),
         IdEndsWith(String),
         IdNot(String),
-        CreatedAtEquals(
-            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
-        ),
-        CreatedAtInVec(
-            Vec<::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>>,
-        ),
-        CreatedAtNotInVec(
-            Vec<::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>>,
-        ),
-        CreatedAtLt(
-            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
-        ),
-        CreatedAtLte(
-            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
-        ),
-        CreatedAtGt(
-            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
-        ),
-        CreatedAtGte(
-            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
-        ),
-        CreatedAtNot(
-            ::prisma_client_rust::chrono::DateTime<::prisma_client_rust::chrono::FixedOffset>,
-        ),
-        StateEquals(String),
-        StateInVec(Vec<String>),
-        StateNotInVec(Vec<String>),
-        StateLt(String),
-        StateLte(String),
-        StateGt(String),
-        StateGte(String),
-        StateContains(String),
-        StateStartsWith(String),
-        StateEndsWith(String),
-        StateNot(String),
-        ActionEquals(i32),
-        ActionInVec(Vec<i32>),
-        ActionNotInVec(Vec<i32>),
-        ActionLt(i32),
-        ActionLte(i32),
-        ActionGt(i32),
-        ActionGte(i32),
-        ActionNot(i32),
+        RunIs(Vec<super::run::WhereParam>),
+        RunIsNot(Vec<super::run::WhereParam>),
+        RunIdEquals(String),
+        RunIdInVec(Vec<String>),
+        RunIdNotInVec(Vec<String>),
+        RunIdLt(String),
+        RunIdLte(String),
+        RunIdGt(String),
+        RunIdGte(String),
+        RunIdContains(String),
+        RunIdStartsWith(String),
+        RunIdEndsWith(String),
+        RunIdNot(String),
+        IndexEquals(i32),
+        IndexInVec(Vec<i32>),
+        IndexNotInVec(Vec<i32>),
+        IndexLt(i32),
+        IndexLte(i32),
+        IndexGt(i32),
+        IndexGte(i32),
+        IndexNot(i32),
         RewardEquals(f64),
         RewardInVec(Vec<f64>),
         RewardNotInVec(Vec<f64>),
@@ -802,22 +4296,32 @@ pub mod rb {
         RewardGt(f64),
         RewardGte(f64),
         RewardNot(f64),
-        NextStateEquals(String),
-        NextStateInVec(Vec<String>),
-        NextStateNotInVec(Vec<String>),
-        NextStateLt(String),
-        NextStateLte(String),
-        NextStateGt(String),
-        NextStateGte(String),
-        NextStateContains(String),
-        NextStateStartsWith(String),
-        NextStateEndsWith(String),
-        NextStateNot(String),
-        DoneEquals(bool),
+        LengthEquals(i32),
+        LengthInVec(Vec<i32>),
+        LengthNotInVec(Vec<i32>),
+        LengthLt(i32),
+        LengthLte(i32),
+        LengthGt(i32),
+        LengthGte(i32),
+        LengthNot(i32),
+        TerminationEquals(Option<String>),
+        TerminationInVec(Vec<String>),
+        TerminationNotInVec(Vec<String>),
+        TerminationLt(String),
+        TerminationLte(String),
+        TerminationGt(String),
+        TerminationGte(String),
+        TerminationContains(String),
+        TerminationStartsWith(String),
+        TerminationEndsWith(String),
+        TerminationNot(Option<String>),
+        TransitionsSome(Vec<super::rb::WhereParam>),
+        TransitionsEvery(Vec<super::rb::WhereParam>),
+        TransitionsNone(Vec<super::rb::WhereParam>),
     }
     impl Into<::prisma_client_rust::SerializedWhere> for WhereParam {
         fn into(self) -> ::prisma_client_rust::SerializedWhere {
-            match self { Self :: Not (value) => :: prisma_client_rust :: SerializedWhere :: new ("NOT" , :: prisma_client_rust :: SerializedWhereValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()) ,) , Self :: Or (value) => :: prisma_client_rust :: SerializedWhere :: new ("OR" , :: prisma_client_rust :: SerializedWhereValue :: List (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . map (| v | vec ! [v]) . map (:: prisma_client_rust :: PrismaValue :: Object) . collect ()) ,) , Self :: And (value) => :: prisma_client_rust :: SerializedWhere :: new ("AND" , :: prisma_client_rust :: SerializedWhereValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()) ,) , Self :: IdEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: IdNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: IdLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdContains (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("contains" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdStartsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("startsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdEndsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("endsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: CreatedAtEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: CreatedAtInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: DateTime (v)) . collect ()))])) , Self :: CreatedAtNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: DateTime (v)) . collect ()))])) , Self :: CreatedAtLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: CreatedAtLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: CreatedAtGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: CreatedAtGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: CreatedAtNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("created_at" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: StateEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: StateInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: StateNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: StateLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: StateLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: StateGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: StateGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: StateContains (value) => :: prisma_client_rust :: SerializedWhere :: new ("state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("contains" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: StateStartsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("startsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: StateEndsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("endsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: StateNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: ActionEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ActionInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: ActionNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: ActionLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ActionLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ActionGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ActionGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ActionNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("action" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: RewardEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: RewardInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: RewardNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: RewardLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: RewardLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: RewardGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: RewardGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: RewardNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: NextStateEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("next_state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NextStateInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("next_state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: NextStateNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("next_state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: NextStateLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("next_state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NextStateLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("next_state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NextStateGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("next_state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NextStateGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("next_state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NextStateContains (value) => :: prisma_client_rust :: SerializedWhere :: new ("next_state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("contains" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NextStateStartsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("next_state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("startsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NextStateEndsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("next_state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("endsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NextStateNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("next_state" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: DoneEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("done" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Boolean (value))])) }
+            match self { Self :: Not (value) => :: prisma_client_rust :: SerializedWhere :: new ("NOT" , :: prisma_client_rust :: SerializedWhereValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()) ,) , Self :: Or (value) => :: prisma_client_rust :: SerializedWhere :: new ("OR" , :: prisma_client_rust :: SerializedWhereValue :: List (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . map (| v | vec ! [v]) . map (:: prisma_client_rust :: PrismaValue :: Object) . collect ()) ,) , Self :: And (value) => :: prisma_client_rust :: SerializedWhere :: new ("AND" , :: prisma_client_rust :: SerializedWhereValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()) ,) , Self :: IdEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: IdNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: IdLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdContains (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("contains" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdStartsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("startsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdEndsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("endsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IdNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIs (where_params) => :: prisma_client_rust :: SerializedWhere :: new ("run" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("is" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (where_params . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . collect ()))])) , Self :: RunIsNot (where_params) => :: prisma_client_rust :: SerializedWhere :: new ("run" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("isNot" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (where_params . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . collect ()))])) , Self :: RunIdEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIdInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: RunIdNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: RunIdLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIdLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIdGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIdGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIdContains (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("contains" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIdStartsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("startsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIdEndsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("endsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: RunIdNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("run_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: IndexEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("index" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: IndexInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("index" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: IndexNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("index" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: IndexLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("index" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: IndexLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("index" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: IndexGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("index" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: IndexGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("index" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: IndexNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("index" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: RewardEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: RewardInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: RewardNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: RewardLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: RewardLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: RewardGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: RewardGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: RewardNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("reward" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: LengthEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("length" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: LengthInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("length" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: LengthNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("length" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: LengthLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("length" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: LengthLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("length" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: LengthGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("length" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: LengthGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("length" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: LengthNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("length" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: TerminationEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("termination" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: String (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) , Self :: TerminationInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("termination" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: TerminationNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("termination" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: TerminationLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("termination" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: TerminationLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("termination" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: TerminationGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("termination" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: TerminationGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("termination" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: TerminationContains (value) => :: prisma_client_rust :: SerializedWhere :: new ("termination" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("contains" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: TerminationStartsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("termination" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("startsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: TerminationEndsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("termination" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("endsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: TerminationNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("termination" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: String (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) , Self :: TransitionsSome (where_params) => :: prisma_client_rust :: SerializedWhere :: new ("transitions" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("some" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (where_params . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . collect ()))])) , Self :: TransitionsEvery (where_params) => :: prisma_client_rust :: SerializedWhere :: new ("transitions" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("every" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (where_params . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . collect ()))])) , Self :: TransitionsNone (where_params) => :: prisma_client_rust :: SerializedWhere :: new ("transitions" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("none" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (where_params . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . collect ()))])) }
         }
     }
     #[derive(Clone)]
@@ -878,69 +4382,66 @@ pub mod rb {
         pub fn find_unique(self, _where: UniqueWhereParam) -> FindUnique<'a> {
             FindUnique::new(
                 self.client._new_query_context(),
-                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                ::prisma_client_rust::QueryInfo::new("Episode", _outputs()),
                 _where.into(),
             )
         }
         pub fn find_first(self, _where: Vec<WhereParam>) -> FindFirst<'a> {
             FindFirst::new(
                 self.client._new_query_context(),
-                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                ::prisma_client_rust::QueryInfo::new("Episode", _outputs()),
                 _where,
             )
         }
         pub fn find_many(self, _where: Vec<WhereParam>) -> FindMany<'a> {
             FindMany::new(
                 self.client._new_query_context(),
-                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                ::prisma_client_rust::QueryInfo::new("Episode", _outputs()),
                 _where,
             )
         }
         pub fn create(
             self,
-            state: String,
-            action: i32,
+            run: super::run::UniqueWhereParam,
+            index: i32,
             reward: f64,
-            next_state: String,
-            done: bool,
+            length: i32,
             mut _params: Vec<SetParam>,
         ) -> Create<'a> {
-            _params.push(state::set(state));
-            _params.push(action::set(action));
+            _params.push(run::connect(run));
+            _params.push(index::set(index));
             _params.push(reward::set(reward));
-            _params.push(next_state::set(next_state));
-            _params.push(done::set(done));
+            _params.push(length::set(length));
             Create::new(
                 self.client._new_query_context(),
-                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                ::prisma_client_rust::QueryInfo::new("Episode", _outputs()),
                 _params,
             )
         }
         pub fn create_many(
             self,
-            data: Vec<(String, i32, f64, String, bool, Vec<SetParam>)>,
+            data: Vec<(super::run::UniqueWhereParam, i32, f64, i32, Vec<SetParam>)>,
         ) -> CreateMany<'a> {
             let data = data
                 .into_iter()
-                .map(|(state, action, reward, next_state, done, mut _params)| {
-                    _params.push(state::set(state));
-                    _params.push(action::set(action));
+                .map(|(run, index, reward, length, mut _params)| {
+                    _params.push(run::connect(run));
+                    _params.push(index::set(index));
                     _params.push(reward::set(reward));
-                    _params.push(next_state::set(next_state));
-                    _params.push(done::set(done));
+                    _params.push(length::set(length));
                     _params
                 })
                 .collect();
             CreateMany::new(
                 self.client._new_query_context(),
-                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                ::prisma_client_rust::QueryInfo::new("Episode", _outputs()),
                 data,
             )
         }
         pub fn update(self, _where: UniqueWhereParam, _params: Vec<SetParam>) -> Update<'a> {
             Update::new(
                 self.client._new_query_context(),
-                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                ::prisma_client_rust::QueryInfo::new("Episode", _outputs()),
                 _where.into(),
                 _params,
                 vec![],
@@ -953,7 +4454,7 @@ pub mod rb {
         ) -> UpdateMany<'a> {
             UpdateMany::new(
                 self.client._new_query_context(),
-                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                ::prisma_client_rust::QueryInfo::new("Episode", _outputs()),
                 _where,
                 _params,
             )
@@ -961,24 +4462,22 @@ pub mod rb {
         pub fn upsert(
             self,
             _where: UniqueWhereParam,
-            (state, action, reward, next_state, done, mut _params): (
-                String,
+            (run, index, reward, length, mut _params): (
+                super::run::UniqueWhereParam,
                 i32,
                 f64,
-                String,
-                bool,
+                i32,
                 Vec<SetParam>,
             ),
             _update: Vec<SetParam>,
         ) -> Upsert<'a> {
-            _params.push(state::set(state));
-            _params.push(action::set(action));
+            _params.push(run::connect(run));
+            _params.push(index::set(index));
             _params.push(reward::set(reward));
-            _params.push(next_state::set(next_state));
-            _params.push(done::set(done));
+            _params.push(length::set(length));
             Upsert::new(
                 self.client._new_query_context(),
-                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                ::prisma_client_rust::QueryInfo::new("Episode", _outputs()),
                 _where.into(),
                 _params,
                 _update,
@@ -987,7 +4486,7 @@ pub mod rb {
         pub fn delete(self, _where: UniqueWhereParam) -> Delete<'a> {
             Delete::new(
                 self.client._new_query_context(),
-                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                ::prisma_client_rust::QueryInfo::new("Episode", _outputs()),
                 _where.into(),
                 vec![],
             )
@@ -995,14 +4494,14 @@ pub mod rb {
         pub fn delete_many(self, _where: Vec<WhereParam>) -> DeleteMany<'a> {
             DeleteMany::new(
                 self.client._new_query_context(),
-                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                ::prisma_client_rust::QueryInfo::new("Episode", _outputs()),
                 _where.into(),
             )
         }
         pub fn count(self, _where: Vec<WhereParam>) -> Count<'a> {
             Count::new(
                 self.client._new_query_context(),
-                ::prisma_client_rust::QueryInfo::new("Rb", _outputs()),
+                ::prisma_client_rust::QueryInfo::new("Episode", _outputs()),
                 vec![],
             )
         }
@@ -1066,6 +4565,12 @@ pub mod _prisma {
         pub fn rb(&self) -> super::rb::Actions {
             super::rb::Actions { client: &self }
         }
+        pub fn run(&self) -> super::run::Actions {
+            super::run::Actions { client: &self }
+        }
+        pub fn episode(&self) -> super::episode::Actions {
+            super::episode::Actions { client: &self }
+        }
     }
     #[derive(Debug, Clone, Copy, :: serde :: Serialize, :: serde :: Deserialize)]
     pub enum RbScalarFieldEnum {
@@ -1073,27 +4578,93 @@ pub mod _prisma {
         Id,
         #[serde(rename = "created_at")]
         CreatedAt,
+        #[serde(rename = "obs_dim")]
+        ObsDim,
         #[serde(rename = "state")]
         State,
         #[serde(rename = "action")]
         Action,
+        #[serde(rename = "action_vec")]
+        ActionVec,
+        #[serde(rename = "log_prob")]
+        LogProb,
         #[serde(rename = "reward")]
         Reward,
         #[serde(rename = "next_state")]
         NextState,
         #[serde(rename = "done")]
         Done,
+        #[serde(rename = "run_id")]
+        RunId,
+        #[serde(rename = "episode_id")]
+        EpisodeId,
     }
     impl ToString for RbScalarFieldEnum {
         fn to_string(&self) -> String {
             match self {
                 Self::Id => "id".to_string(),
                 Self::CreatedAt => "created_at".to_string(),
+                Self::ObsDim => "obs_dim".to_string(),
                 Self::State => "state".to_string(),
                 Self::Action => "action".to_string(),
+                Self::ActionVec => "action_vec".to_string(),
+                Self::LogProb => "log_prob".to_string(),
                 Self::Reward => "reward".to_string(),
                 Self::NextState => "next_state".to_string(),
                 Self::Done => "done".to_string(),
+                Self::RunId => "run_id".to_string(),
+                Self::EpisodeId => "episode_id".to_string(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, :: serde :: Serialize, :: serde :: Deserialize)]
+    pub enum RunScalarFieldEnum {
+        #[serde(rename = "id")]
+        Id,
+        #[serde(rename = "started_at")]
+        StartedAt,
+        #[serde(rename = "config")]
+        Config,
+        #[serde(rename = "git_hash")]
+        GitHash,
+        #[serde(rename = "seed")]
+        Seed,
+    }
+    impl ToString for RunScalarFieldEnum {
+        fn to_string(&self) -> String {
+            match self {
+                Self::Id => "id".to_string(),
+                Self::StartedAt => "started_at".to_string(),
+                Self::Config => "config".to_string(),
+                Self::GitHash => "git_hash".to_string(),
+                Self::Seed => "seed".to_string(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, :: serde :: Serialize, :: serde :: Deserialize)]
+    pub enum EpisodeScalarFieldEnum {
+        #[serde(rename = "id")]
+        Id,
+        #[serde(rename = "run_id")]
+        RunId,
+        #[serde(rename = "index")]
+        Index,
+        #[serde(rename = "reward")]
+        Reward,
+        #[serde(rename = "length")]
+        Length,
+        #[serde(rename = "termination")]
+        Termination,
+    }
+    impl ToString for EpisodeScalarFieldEnum {
+        fn to_string(&self) -> String {
+            match self {
+                Self::Id => "id".to_string(),
+                Self::RunId => "run_id".to_string(),
+                Self::Index => "index".to_string(),
+                Self::Reward => "reward".to_string(),
+                Self::Length => "length".to_string(),
+                Self::Termination => "termination".to_string(),
             }
         }
     }
@@ -1,14 +1,331 @@
 use crate::db::{self, PrismaClient};
-use prisma_client_rust::NewClientError;
+use prisma_client_rust::{Direction, NewClientError};
+use tokio::runtime::Runtime;
 
+#[cfg(feature = "train")]
+use crate::{
+    action::Action,
+    env::EnvStep,
+    observation::{from_bytes, to_bytes},
+    replay::Transition,
+};
+
+/// Prisma client with the runtime its queries are blocked on, so systems can use it synchronously.
 pub struct DbClientResource {
     pub client: PrismaClient,
+    pub runtime: Runtime,
 }
 impl Default for DbClientResource {
-    #[tokio::main]
-    async fn default() -> Self {
-        let client: Result<PrismaClient, NewClientError> = db::new_client().await;
+    fn default() -> Self {
+        let runtime = Runtime::new().unwrap();
+        let client: Result<PrismaClient, NewClientError> = runtime.block_on(db::new_client());
         let client = client.unwrap();
-        return DbClientResource { client };
+        return DbClientResource { client, runtime };
+    }
+}
+
+/// Finished episodes of a run, see `DbClientResource::run_stats`.
+#[derive(Debug, Default)]
+pub struct RunStats {
+    pub episodes: usize,
+    /// Env steps over all finished episodes.
+    pub steps: usize,
+    pub mean_reward: f64,
+    pub best_reward: f64,
+    pub mean_length: f64,
+    /// Episodes per `TerminationReason`, most frequent first.
+    pub terminations: Vec<(String, usize)>,
+}
+
+impl DbClientResource {
+    /// Every run, oldest first.
+    pub fn runs(&self) -> Vec<db::run::Data> {
+        self.runtime
+            .block_on(
+                self.client
+                    .run()
+                    .find_many(vec![])
+                    .order_by(db::run::started_at::order(Direction::Asc))
+                    .exec(),
+            )
+            .unwrap_or_else(|e| {
+                eprintln!("failed to load runs: {}", e);
+                vec![]
+            })
+    }
+
+    /// Return, length and termination statistics of the finished episodes of `run`.
+    pub fn run_stats(&self, run: &str) -> RunStats {
+        let episodes = self
+            .runtime
+            .block_on(
+                self.client
+                    .episode()
+                    .find_many(vec![
+                        db::episode::run_id::equals(run.to_string()),
+                        db::episode::termination::not(None),
+                    ])
+                    .exec(),
+            )
+            .unwrap_or_else(|e| {
+                eprintln!("failed to load episodes of run {}: {}", run, e);
+                vec![]
+            });
+        if episodes.is_empty() {
+            return RunStats::default();
+        }
+        let n = episodes.len() as f64;
+        let mut terminations: Vec<(String, usize)> = vec![];
+        for reason in episodes.iter().filter_map(|e| e.termination.as_ref()) {
+            match terminations.iter_mut().find(|(r, _)| r == reason) {
+                Some((_, count)) => *count += 1,
+                None => terminations.push((reason.clone(), 1)),
+            }
+        }
+        terminations.sort_by(|a, b| b.1.cmp(&a.1));
+        let steps: usize = episodes.iter().map(|e| e.length as usize).sum();
+        RunStats {
+            episodes: episodes.len(),
+            steps,
+            mean_reward: episodes.iter().map(|e| e.reward).sum::<f64>() / n,
+            best_reward: episodes
+                .iter()
+                .map(|e| e.reward)
+                .fold(f64::NEG_INFINITY, f64::max),
+            mean_length: steps as f64 / n,
+            terminations,
+        }
+    }
+
+    /// Deletes a run, its episodes and transitions go with it.
+    pub fn delete_run(&self, run: &str) {
+        match self.runtime.block_on(
+            self.client
+                .run()
+                .delete(db::run::id::equals(run.to_string()))
+                .exec(),
+        ) {
+            Ok(_) => println!("deleted run {}", run),
+            Err(e) => eprintln!("failed to delete run {}: {}", run, e),
+        }
     }
+
+    /// One line per run for `--runs`.
+    pub fn print_runs(&self) {
+        for run in self.runs() {
+            let stats = self.run_stats(&run.id);
+            let terminations: Vec<String> = stats
+                .terminations
+                .iter()
+                .map(|(reason, count)| format!("{} {}", reason, count))
+                .collect();
+            println!(
+                "{} {} git {:.8} seed {}: {} episodes, {} steps, return mean {:.2} best {:.2}, length {:.1}, {}",
+                run.id,
+                run.started_at.format("%Y-%m-%d %H:%M"),
+                run.git_hash,
+                run.seed as u64,
+                stats.episodes,
+                stats.steps,
+                stats.mean_reward,
+                stats.best_reward,
+                stats.mean_length,
+                terminations.join(", ")
+            );
+            println!("  {}", run.config);
+        }
+    }
+}
+
+/// `Run` row of this training process and the running `Episode` row of every env.
+#[cfg(feature = "train")]
+pub struct RunRecord {
+    pub id: String,
+    episodes: Vec<Option<String>>,
+    next_index: i32,
+}
+
+#[cfg(feature = "train")]
+impl DbClientResource {
+    /// Records a training run with the agent config, command line and git commit.
+    pub fn start_run(&self, agent: &str, config: serde_json::Value) -> Option<RunRecord> {
+        let seed = config["seed"].as_u64().unwrap_or(0);
+        let config = serde_json::json!({
+            "agent": agent,
+            "config": config,
+            "args": std::env::args().skip(1).collect::<Vec<_>>(),
+        });
+        let run = self.runtime.block_on(
+            self.client
+                .run()
+                .create(config.to_string(), git_hash(), seed as i64, vec![])
+                .exec(),
+        );
+        match run {
+            Ok(run) => Some(RunRecord {
+                id: run.id,
+                episodes: vec![],
+                next_index: 0,
+            }),
+            Err(e) => {
+                eprintln!("failed to record run: {}", e);
+                None
+            }
+        }
+    }
+
+    /// The running episode of `env`, started on its first transition.
+    fn episode(&self, run: &mut RunRecord, env: usize) -> Option<String> {
+        if run.episodes.len() <= env {
+            run.episodes.resize(env + 1, None);
+        }
+        if run.episodes[env].is_none() {
+            let episode = self.runtime.block_on(
+                self.client
+                    .episode()
+                    .create(
+                        db::run::id::equals(run.id.clone()),
+                        run.next_index,
+                        0.,
+                        0,
+                        vec![],
+                    )
+                    .exec(),
+            );
+            match episode {
+                Ok(episode) => {
+                    run.episodes[env] = Some(episode.id);
+                    run.next_index += 1;
+                }
+                Err(e) => eprintln!("failed to record episode: {}", e),
+            }
+        }
+        run.episodes[env].clone()
+    }
+
+    /// Stores the return, length and termination of the episode `env` just ended.
+    pub fn end_episode(&self, run: &mut RunRecord, env: usize, step: &EnvStep) {
+        let episode = match self.episode(run, env) {
+            Some(episode) => episode,
+            None => return,
+        };
+        run.episodes[env] = None;
+        if let Err(e) = self.runtime.block_on(
+            self.client
+                .episode()
+                .update(
+                    db::episode::id::equals(episode),
+                    vec![
+                        db::episode::reward::set(step.episode_reward as f64),
+                        db::episode::length::set(step.steps as i32),
+                        db::episode::termination::set(
+                            step.termination.map(|reason| format!("{:?}", reason)),
+                        ),
+                    ],
+                )
+                .exec(),
+        ) {
+            eprintln!("failed to record episode end: {}", e);
+        }
+    }
+
+    /// Appends the transitions of every env to the `Rb` table, linked to the run and the
+    /// running episode of their env. States and continuous actions are little-endian f32
+    /// blobs, discrete actions go in the `action` column.
+    pub fn insert_transitions(&self, run: &mut RunRecord, transitions: &[(usize, Transition)]) {
+        let mut data = vec![];
+        for (env, t) in transitions {
+            let (action, action_vec) = match &t.action {
+                Action::Discrete(action) => (Some(*action), None),
+                Action::Continuous(action) => (None, Some(to_bytes(action))),
+            };
+            // create_many takes the foreign keys rather than relation connects
+            data.push(db::rb::create(
+                t.state.len() as i32,
+                to_bytes(&t.state),
+                t.reward as f64,
+                to_bytes(&t.next_state),
+                t.done,
+                vec![
+                    db::rb::action::set(action),
+                    db::rb::action_vec::set(action_vec),
+                    db::rb::log_prob::set(t.log_prob.map(f64::from)),
+                    db::rb::run_id::set(Some(run.id.clone())),
+                    db::rb::episode_id::set(self.episode(run, *env)),
+                ],
+            ));
+        }
+        if data.is_empty() {
+            return;
+        }
+        if let Err(e) = self
+            .runtime
+            .block_on(self.client.rb().create_many(data).exec())
+        {
+            eprintln!("failed to store transitions: {}", e);
+        }
+    }
+
+    /// The latest `limit` transitions of the `Rb` table with `obs_dim` sized observations and
+    /// discrete or continuous actions, oldest first.
+    pub fn load_transitions(
+        &self,
+        limit: usize,
+        obs_dim: usize,
+        discrete: bool,
+    ) -> Vec<Transition> {
+        let action = match discrete {
+            true => db::rb::action::not(None),
+            false => db::rb::action_vec::not(None),
+        };
+        let rows = self
+            .runtime
+            .block_on(
+                self.client
+                    .rb()
+                    .find_many(vec![db::rb::obs_dim::equals(obs_dim as i32), action])
+                    .order_by(db::rb::created_at::order(Direction::Desc))
+                    .take(limit as i64)
+                    .exec(),
+            )
+            .unwrap_or_else(|e| {
+                eprintln!("failed to load transitions: {}", e);
+                vec![]
+            });
+        rows.into_iter()
+            .rev()
+            .filter_map(|row| {
+                let state = from_bytes(&row.state)?;
+                let next_state = from_bytes(&row.next_state)?;
+                if state.len() != obs_dim || next_state.len() != obs_dim {
+                    return None;
+                }
+                let action = match (row.action, row.action_vec) {
+                    (Some(action), _) => Action::Discrete(action),
+                    (None, Some(action)) => Action::Continuous(from_bytes(&action)?),
+                    (None, None) => return None,
+                };
+                Some(Transition {
+                    state,
+                    action,
+                    reward: row.reward as f32,
+                    next_state,
+                    log_prob: row.log_prob.map(|p| p as f32),
+                    done: row.done,
+                    truncated: false,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "train")]
+fn git_hash() -> String {
+    std::process::Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map_or("unknown".to_string(), |hash| hash.trim().to_string())
 }
@@ -0,0 +1,247 @@
+use std::{error::Error, path::Path};
+
+use dfdx::prelude::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    action::{get_discrete_actions, Action, ActionMode, DISCRETE_STEP},
+    agent::Agent,
+    checkpoint::{load_net, reseed, save_net},
+    env::Observation,
+    nets::{argmax, Mlp},
+    observation::{to_batch, to_tensor, HUMANOID_DOF, HUMANOID_OBS},
+    optim::CheckpointedAdam,
+    replay::{ReplayBuffer, Transition},
+};
+
+/// Discrete actions of the humanoid in `assets/humanoid.json`, see `Action::Discrete`.
+pub const DQN_ACTIONS: usize = 2 * HUMANOID_DOF + 1;
+const BATCH: usize = 64;
+
+pub type QNetwork = Mlp<HUMANOID_OBS, DQN_ACTIONS>;
+
+#[derive(Serialize)]
+pub struct DqnConfig {
+    pub gamma: f32,
+    pub lr: f32,
+    pub epsilon_start: f32,
+    pub epsilon_end: f32,
+    /// Env steps over which epsilon decays linearly from start to end.
+    pub epsilon_decay_steps: usize,
+    /// Train steps between copies of the Q-network into the target network.
+    pub target_sync_steps: usize,
+    pub replay_capacity: usize,
+    /// Transitions in the replay buffer before training starts.
+    pub learning_starts: usize,
+    /// Position target change of one discrete action, in radians.
+    pub action_step: f32,
+    pub seed: u64,
+}
+impl Default for DqnConfig {
+    fn default() -> Self {
+        Self {
+            gamma: 0.99,
+            lr: 1e-4,
+            epsilon_start: 1.,
+            epsilon_end: 0.05,
+            epsilon_decay_steps: 100_000,
+            target_sync_steps: 1000,
+            replay_capacity: 100_000,
+            learning_starts: 1000,
+            action_step: DISCRETE_STEP,
+            seed: 0,
+        }
+    }
+}
+
+/// Deep Q-learning over `Action::Discrete`: epsilon-greedy exploration, a target network
+/// synced every `target_sync_steps`, Huber loss on the one step TD error and Adam.
+pub struct DqnAgent {
+    pub config: DqnConfig,
+    pub q_net: QNetwork,
+    pub target_q_net: QNetwork,
+    pub optimizer: CheckpointedAdam<QNetwork>,
+    pub replay: ReplayBuffer,
+    pub rng: StdRng,
+    pub env_steps: usize,
+    pub train_steps: usize,
+}
+
+/// Checkpointed training state, epsilon follows from `env_steps`.
+#[derive(Serialize, Deserialize)]
+struct DqnState {
+    seed: u64,
+    env_steps: usize,
+    train_steps: usize,
+    adam_t: i32,
+}
+
+impl DqnAgent {
+    pub fn new(config: DqnConfig) -> Self {
+        assert_eq!(DQN_ACTIONS, get_discrete_actions(HUMANOID_DOF));
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let mut q_net = QNetwork::default();
+        q_net.reset_params(&mut rng);
+        let target_q_net = q_net.clone();
+        let optimizer = CheckpointedAdam::new(AdamConfig {
+            lr: config.lr,
+            ..Default::default()
+        });
+        Self {
+            replay: ReplayBuffer::new(config.replay_capacity),
+            config,
+            q_net,
+            target_q_net,
+            optimizer,
+            rng,
+            env_steps: 0,
+            train_steps: 0,
+        }
+    }
+
+    pub fn epsilon(&self) -> f32 {
+        let t = (self.env_steps as f32 / self.config.epsilon_decay_steps as f32).min(1.);
+        self.config.epsilon_start + t * (self.config.epsilon_end - self.config.epsilon_start)
+    }
+
+    pub fn greedy(&self, observation: &[f32]) -> usize {
+        assert_eq!(
+            observation.len(),
+            HUMANOID_OBS,
+            "DQN is sized for the default humanoid and observation config"
+        );
+        let q_values: Tensor1D<DQN_ACTIONS> =
+            self.q_net.forward(to_tensor::<HUMANOID_OBS>(observation));
+        argmax(q_values.data())
+    }
+
+    fn train_step(&mut self) -> f32 {
+        let batch: Vec<Transition> = self
+            .replay
+            .sample(&mut self.rng, BATCH)
+            .into_iter()
+            .cloned()
+            .collect();
+        let states: Vec<Observation> = batch.iter().map(|t| t.state.clone()).collect();
+        let next_states: Vec<Observation> = batch.iter().map(|t| t.next_state.clone()).collect();
+        let mut actions = [0; BATCH];
+        for (action, t) in actions.iter_mut().zip(batch.iter()) {
+            if let Action::Discrete(a) = t.action {
+                *action = a.max(0) as usize;
+            }
+        }
+
+        // target = r + gamma * max_a' Q_target(s', a'), without bootstrapping past a failure
+        let next_q: Tensor2D<BATCH, DQN_ACTIONS> =
+            self.target_q_net
+                .forward(to_batch::<BATCH, HUMANOID_OBS>(&next_states));
+        let mut targets = [0.; BATCH];
+        for (i, t) in batch.iter().enumerate() {
+            let max_next_q = next_q.data()[i].iter().copied().fold(f32::MIN, f32::max);
+            targets[i] = match t.done {
+                true => t.reward,
+                false => t.reward + self.config.gamma * max_next_q,
+            };
+        }
+
+        let q_values: Tensor2D<BATCH, DQN_ACTIONS, OwnedTape> = self
+            .q_net
+            .forward(to_batch::<BATCH, HUMANOID_OBS>(&states).trace());
+        let action_q = q_values.select(&actions);
+        let loss = huber_loss(action_q, &Tensor1D::new(targets), 1.);
+        let loss_value = *loss.data();
+        let gradients = loss.backward();
+        self.optimizer
+            .update(&mut self.q_net, gradients)
+            .expect("unused params");
+
+        self.train_steps += 1;
+        if self.train_steps % self.config.target_sync_steps == 0 {
+            self.target_q_net = self.q_net.clone();
+        }
+        loss_value
+    }
+}
+
+impl Agent for DqnAgent {
+    fn name(&self) -> &'static str {
+        "dqn"
+    }
+
+    fn config(&self) -> serde_json::Value {
+        serde_json::to_value(&self.config).unwrap()
+    }
+
+    fn action_mode(&self) -> ActionMode {
+        ActionMode::Discrete {
+            step: self.config.action_step,
+        }
+    }
+
+    fn act(&mut self, observations: &[Observation]) -> Vec<Action> {
+        let mut actions = vec![];
+        for observation in observations {
+            let action = match self.rng.gen::<f32>() < self.epsilon() {
+                true => self.rng.gen_range(0..DQN_ACTIONS),
+                false => self.greedy(observation),
+            };
+            actions.push(Action::Discrete(action as i32));
+            self.env_steps += 1;
+        }
+        actions
+    }
+
+    fn act_deterministic(&mut self, observations: &[Observation]) -> Vec<Action> {
+        observations
+            .iter()
+            .map(|observation| Action::Discrete(self.greedy(observation) as i32))
+            .collect()
+    }
+
+    fn observe(&mut self, transitions: Vec<(usize, Transition)>) {
+        for (_, transition) in transitions {
+            self.replay.push(transition);
+        }
+        if self.replay.len() < self.config.learning_starts {
+            return;
+        }
+        let loss = self.train_step();
+        if self.train_steps % 1000 == 0 {
+            println!(
+                "dqn train step {}: loss {:.4}, epsilon {:.3}, replay {}",
+                self.train_steps,
+                loss,
+                self.epsilon(),
+                self.replay.len()
+            );
+        }
+    }
+
+    fn replay_buffer(&mut self) -> Option<&mut ReplayBuffer> {
+        Some(&mut self.replay)
+    }
+
+    fn save(&mut self, dir: &Path) -> Result<serde_json::Value, Box<dyn Error>> {
+        save_net(dir, "q_net", &self.q_net)?;
+        save_net(dir, "target_q_net", &self.target_q_net)?;
+        self.optimizer.save(dir, "q_net_adam")?;
+        Ok(serde_json::to_value(DqnState {
+            seed: reseed(&mut self.rng),
+            env_steps: self.env_steps,
+            train_steps: self.train_steps,
+            adam_t: self.optimizer.t,
+        })?)
+    }
+
+    fn load(&mut self, dir: &Path, state: serde_json::Value) -> Result<(), Box<dyn Error>> {
+        let state: DqnState = serde_json::from_value(state)?;
+        load_net(dir, "q_net", &mut self.q_net)?;
+        load_net(dir, "target_q_net", &mut self.target_q_net)?;
+        self.optimizer.load(dir, "q_net_adam", state.adam_t)?;
+        self.rng = StdRng::seed_from_u64(state.seed);
+        self.env_steps = state.env_steps;
+        self.train_steps = state.train_steps;
+        Ok(())
+    }
+}
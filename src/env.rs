@@ -0,0 +1,587 @@
+use bevy::{app::PluginGroupBuilder, prelude::*};
+use bevy_rapier3d::{prelude::*, rapier::prelude::JointAxis};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{collections::HashMap, error::Error};
+
+use crate::{
+    action::{action_system, Action, ActionConfig},
+    ground::{ground_start_system, GROUND_HALF_SIZE},
+    humanoid::{
+        get_collision_groups, get_spawn_transform, joint_motor_system, spawn_humanoid, Humanoid,
+        HumanoidConfig, InitialPose, JointFrame, JointMode, JointMotors, MAX_SELF_COLLISION_ENVS,
+    },
+    observation::{observation_system, ObservationConfig, ObservationNormalizer},
+    reward::{reward_system, RewardConfig},
+    skeleton::SkeletonDesc,
+    termination::{termination_system, EpisodeEnded, TerminationConfig, TerminationReason},
+};
+
+pub const FPS: f32 = 60.;
+pub const SUBSTEPS: usize = 10;
+/// Torso height below which the humanoid is considered fallen.
+pub const FALLEN_HEIGHT: f32 = 0.5;
+
+pub fn get_rapier_configuration() -> RapierConfiguration {
+    RapierConfiguration {
+        timestep_mode: TimestepMode::Fixed {
+            dt: 1. / FPS,
+            substeps: SUBSTEPS,
+        },
+        ..default()
+    }
+}
+
+pub fn rapier_config_start_system(mut c: ResMut<RapierContext>, config: Res<HumanoidConfig>) {
+    // multibody joints are solved exactly, impulse joints need many iterations to hold together
+    if config.joint_mode == JointMode::Impulse {
+        c.integration_parameters.max_velocity_iterations = 128;
+        c.integration_parameters.max_stabilization_iterations = 128;
+    }
+}
+
+/// See `ObservationConfig` for the layout.
+pub type Observation = Vec<f32>;
+
+/// Result of the last simulation step, updated every frame in `CoreStage::PostUpdate`.
+#[derive(Component, Debug, Clone, Default)]
+pub struct EnvStep {
+    pub observation: Observation,
+    pub reward: f32,
+    /// Weighted value of every term of `RewardConfig`, summing up to `reward`.
+    pub reward_terms: Vec<f32>,
+    /// Sum of the rewards since the last reset.
+    pub episode_reward: f32,
+    /// Sum of every reward term since the last reset, in `reward_terms` order.
+    pub episode_reward_terms: Vec<f32>,
+    pub done: bool,
+    pub termination: Option<TerminationReason>,
+    /// Actions taken since the last reset, counted by `action_system`. The reset frame is none.
+    pub steps: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct StepInfo {
+    pub steps: usize,
+    /// Weighted reward terms by name, `Rb.reward` only stores their sum.
+    pub reward_terms: Vec<(&'static str, f32)>,
+    pub termination: Option<TerminationReason>,
+}
+
+/// Number of humanoids simulated side by side in one rapier world.
+pub struct EnvConfig {
+    pub num_envs: usize,
+    /// Distance between neighbouring humanoids on the square grid they are spawned on.
+    pub spacing: f32,
+    /// Seed of the `ResetEvent` sent on startup, checkpoints restore the one they saved.
+    pub seed: u64,
+}
+impl Default for EnvConfig {
+    fn default() -> Self {
+        Self {
+            num_envs: 1,
+            spacing: 2.,
+            seed: 0,
+        }
+    }
+}
+impl EnvConfig {
+    /// Errors without envs, when the grid of `spacing` wide cells doesn't fit on the ground,
+    /// and with `HumanoidConfig::self_collision` for more than `MAX_SELF_COLLISION_ENVS` envs.
+    pub fn validate(&self, config: &HumanoidConfig) -> Result<(), Box<dyn Error>> {
+        if self.num_envs == 0 {
+            return Err("at least one env is needed".into());
+        }
+        let columns = (self.num_envs as f32).sqrt().ceil();
+        if columns * self.spacing > 2. * GROUND_HALF_SIZE {
+            return Err(format!(
+                "{} envs {} m apart don't fit on the {} m wide ground",
+                self.num_envs,
+                self.spacing,
+                2. * GROUND_HALF_SIZE
+            )
+            .into());
+        }
+        if config.self_collision && self.num_envs > MAX_SELF_COLLISION_ENVS {
+            return Err(format!(
+                "self-collision supports at most {} envs, humanoids of other envs would collide",
+                MAX_SELF_COLLISION_ENVS
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Spawn transform of the humanoid of env `index`, the grid is centered on the origin.
+    pub fn get_spawn_transform(&self, skeleton: &SkeletonDesc, index: usize) -> Transform {
+        let columns = (self.num_envs as f32).sqrt().ceil() as usize;
+        let rows = (self.num_envs + columns - 1) / columns;
+        let (column, row) = (index % columns, index / columns);
+        let offset = Vec3::new(
+            (column as f32 - (columns - 1) as f32 / 2.) * self.spacing,
+            0.,
+            (row as f32 - (rows - 1) as f32 / 2.) * self.spacing,
+        );
+        let transform = get_spawn_transform(skeleton);
+        transform.with_translation(transform.translation + offset)
+    }
+}
+
+/// Index of a humanoid among `EnvConfig::num_envs`, batched observations and actions use this order.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EnvIndex(pub usize);
+
+/// Resets every humanoid and reseeds `EnvRng`.
+pub struct ResetEvent {
+    pub seed: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResetMode {
+    /// Despawn the humanoid and spawn it again with `spawn_humanoid`.
+    Respawn,
+    /// Move every part back to its `InitialPose` with zero velocity, keeping the entities.
+    /// Only impulse joints, multibody links are placed by their joint coordinates and always respawn.
+    Teleport,
+}
+
+pub struct ResetConfig {
+    pub mode: ResetMode,
+    /// Reset a humanoid at the start of the frame after it terminated.
+    pub auto_reset: bool,
+    /// Uniform noise in radians around the standing pose on every free angular joint axis,
+    /// within the joint limits. The motor targets start at the sampled angles.
+    pub joint_noise: f32,
+    /// Uniform noise on every component of the linear and angular velocities of every part.
+    pub velocity_noise: f32,
+    /// Uniform random yaw in radians of the whole humanoid around its torso.
+    pub yaw_range: f32,
+}
+impl Default for ResetConfig {
+    fn default() -> Self {
+        Self {
+            mode: ResetMode::Teleport,
+            auto_reset: true,
+            joint_noise: 0.05,
+            velocity_noise: 0.05,
+            yaw_range: 0.,
+        }
+    }
+}
+
+/// Marks a humanoid reset this frame, its pose is sampled by `randomize_pose_system`.
+#[derive(Component)]
+pub struct RandomizePose;
+
+/// Seeded by every `ResetEvent`, so episodes are reproducible.
+pub struct EnvRng(pub StdRng);
+impl Default for EnvRng {
+    fn default() -> Self {
+        Self(StdRng::seed_from_u64(0))
+    }
+}
+
+#[derive(SystemLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EnvSystem {
+    Reset,
+    Randomize,
+    Action,
+    Motors,
+    Observation,
+    Reward,
+    Termination,
+}
+
+pub struct HumanoidEnvPlugin;
+impl Plugin for HumanoidEnvPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ResetEvent>()
+            .add_event::<EpisodeEnded>()
+            .init_resource::<EnvRng>()
+            .init_resource::<EnvConfig>()
+            .init_resource::<ResetConfig>()
+            .init_resource::<TerminationConfig>()
+            .init_resource::<ActionConfig>()
+            .init_resource::<ObservationConfig>()
+            .init_resource::<ObservationNormalizer>()
+            .init_resource::<RewardConfig>()
+            // in its own stage, so the humanoids it spawns exist for `randomize_pose_system`
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                humanoid_reset_system.label(EnvSystem::Reset),
+            )
+            .add_system(randomize_pose_system.label(EnvSystem::Randomize))
+            .add_system(
+                action_system
+                    .label(EnvSystem::Action)
+                    .after(EnvSystem::Randomize),
+            )
+            .add_system(
+                joint_motor_system
+                    .label(EnvSystem::Motors)
+                    .after(EnvSystem::Action),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                observation_system.label(EnvSystem::Observation),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                reward_system
+                    .label(EnvSystem::Reward)
+                    .after(EnvSystem::Observation),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                termination_system
+                    .label(EnvSystem::Termination)
+                    .after(EnvSystem::Reward),
+            );
+    }
+}
+
+/// Spawns the humanoids through the first reset.
+pub fn env_start_system(mut events: EventWriter<ResetEvent>, config: Res<EnvConfig>) {
+    events.send(ResetEvent { seed: config.seed });
+}
+
+fn spawn_env_humanoid(
+    commands: &mut Commands,
+    skeleton: &SkeletonDesc,
+    config: &HumanoidConfig,
+    env_config: &EnvConfig,
+    index: usize,
+) {
+    let root = spawn_humanoid(
+        commands,
+        skeleton,
+        env_config.get_spawn_transform(skeleton, index),
+        config.joint_mode,
+        get_collision_groups(index, config.self_collision),
+    );
+    commands
+        .entity(root)
+        .insert(EnvIndex(index))
+        .insert(Action::default())
+        .insert(EnvStep::default())
+        .insert(RandomizePose);
+}
+
+/// Resets every humanoid on `ResetEvent`, and terminated ones when `ResetConfig::auto_reset` is set.
+/// The first `ResetEvent` spawns `EnvConfig::num_envs` humanoids.
+#[allow(clippy::too_many_arguments)]
+pub fn humanoid_reset_system(
+    mut commands: Commands,
+    mut events: EventReader<ResetEvent>,
+    mut rng: ResMut<EnvRng>,
+    skeleton: Res<SkeletonDesc>,
+    config: Res<HumanoidConfig>,
+    env_config: Res<EnvConfig>,
+    reset_config: Res<ResetConfig>,
+    mut roots: Query<(Entity, &EnvIndex, &Humanoid, &mut EnvStep, &mut Action)>,
+    mut parts: Query<(
+        &InitialPose,
+        &mut Transform,
+        &mut Velocity,
+        &mut ExternalForce,
+    )>,
+    mut motors: Query<&mut JointMotors>,
+) {
+    let reset_all = match events.iter().last() {
+        Some(event) => {
+            *rng = EnvRng(StdRng::seed_from_u64(event.seed));
+            true
+        }
+        None => false,
+    };
+    if reset_all && roots.is_empty() {
+        for index in 0..env_config.num_envs {
+            spawn_env_humanoid(&mut commands, &skeleton, &config, &env_config, index);
+        }
+        return;
+    }
+    let mode = match config.joint_mode {
+        JointMode::Impulse => reset_config.mode,
+        JointMode::Multibody => ResetMode::Respawn,
+    };
+    for (root, index, humanoid, mut step, mut action) in roots.iter_mut() {
+        if !(reset_all || (step.done && reset_config.auto_reset)) {
+            continue;
+        }
+        match mode {
+            ResetMode::Respawn => {
+                for part in humanoid.parts.iter() {
+                    commands.entity(*part).despawn_recursive();
+                }
+                spawn_env_humanoid(&mut commands, &skeleton, &config, &env_config, index.0);
+            }
+            ResetMode::Teleport => {
+                for part in humanoid.parts.iter() {
+                    if let Ok((pose, mut transform, mut velocity, mut force)) = parts.get_mut(*part)
+                    {
+                        *transform = pose.0;
+                        *velocity = Velocity::zero();
+                        *force = ExternalForce::default();
+                    }
+                }
+                for joint in humanoid.joints.iter() {
+                    if let Ok(mut joint_motors) = motors.get_mut(*joint) {
+                        for motor in joint_motors.motors.iter_mut() {
+                            motor.target_pos = 0.;
+                            motor.target_vel = 0.;
+                        }
+                    }
+                }
+                *step = EnvStep::default();
+                *action = Action::default();
+                commands.entity(root).insert(RandomizePose);
+            }
+        }
+    }
+}
+
+fn uniform(rng: &mut EnvRng, range: f32) -> f32 {
+    rng.0.gen_range(-range..=range)
+}
+
+/// Samples the pose of freshly reset humanoids from `InitialPose` and `ResetConfig`,
+/// placing every part by forward kinematics from its parent through the `JointFrame`.
+/// Multibody links are placed from their joint coordinates, only the root pose applies to them.
+pub fn randomize_pose_system(
+    mut commands: Commands,
+    config: Res<ResetConfig>,
+    mut rng: ResMut<EnvRng>,
+    roots: Query<(Entity, &Humanoid), With<RandomizePose>>,
+    mut parts: Query<(&InitialPose, &mut Transform, &mut Velocity)>,
+    mut joints: Query<(&JointFrame, &mut JointMotors)>,
+) {
+    for (root, humanoid) in roots.iter() {
+        commands.entity(root).remove::<RandomizePose>();
+        let yaw = Quat::from_rotation_y(uniform(&mut rng, config.yaw_range));
+        let mut poses: HashMap<Entity, Transform> = HashMap::new();
+        // parents are spawned, and listed, before their children
+        for part in humanoid.parts.iter() {
+            let rest = match parts.get(*part) {
+                Ok((pose, _, _)) => pose.0,
+                Err(_) => continue,
+            };
+            let pose = match joints.get_mut(*part) {
+                Ok((frame, mut motors)) => {
+                    let parent = match poses.get(&frame.parent) {
+                        Some(parent) => *parent,
+                        None => continue,
+                    };
+                    let mut angles = Vec3::ZERO;
+                    for motor in motors.motors.iter_mut() {
+                        let index = match motor.axis {
+                            JointAxis::AngX => 0,
+                            JointAxis::AngY => 1,
+                            JointAxis::AngZ => 2,
+                            _ => continue,
+                        };
+                        let [min, max] = motor.limits;
+                        angles[index] = uniform(&mut rng, config.joint_noise).clamp(min, max);
+                        motor.target_pos = angles[index];
+                    }
+                    let rotation = frame.rotation(&parent)
+                        * Quat::from_euler(EulerRot::XYZ, angles.x, angles.y, angles.z)
+                        * frame.basis.inverse();
+                    Transform {
+                        translation: parent.translation + parent.rotation * frame.anchor1
+                            - rotation * frame.anchor2,
+                        rotation,
+                        ..rest
+                    }
+                }
+                Err(_) => Transform {
+                    rotation: yaw * rest.rotation,
+                    ..rest
+                },
+            };
+            if let Ok((_, mut transform, mut velocity)) = parts.get_mut(*part) {
+                *transform = pose;
+                let mut noise = || uniform(&mut rng, config.velocity_noise);
+                velocity.linvel = Vec3::new(noise(), noise(), noise());
+                velocity.angvel = Vec3::new(noise(), noise(), noise());
+            }
+            poses.insert(*part, pose);
+        }
+    }
+}
+
+/// `MinimalPlugins` with transforms and hierarchy, enough for rapier without a window or renderer.
+/// The schedule runner loops without waiting, so physics steps as fast as the CPU allows.
+pub struct HeadlessPlugins;
+impl PluginGroup for HeadlessPlugins {
+    fn build(&mut self, group: &mut PluginGroupBuilder) {
+        MinimalPlugins.build(group);
+        group.add(TransformPlugin).add(HierarchyPlugin);
+    }
+}
+
+/// Step/reset API over a headless bevy app, for training code driving the simulation itself.
+/// Every `step` advances rapier by one fixed `1 / FPS` timestep of `SUBSTEPS` substeps.
+/// All `EnvConfig::num_envs` humanoids step together, results are batched in `EnvIndex` order
+/// and every humanoid resets on its own once done, see `ResetConfig::auto_reset`.
+pub struct HumanoidEnv {
+    pub app: App,
+}
+impl HumanoidEnv {
+    /// Errors when `env_config` doesn't fit, see `EnvConfig::validate`.
+    pub fn new(
+        skeleton: SkeletonDesc,
+        config: HumanoidConfig,
+        env_config: EnvConfig,
+    ) -> Result<Self, Box<dyn Error>> {
+        env_config.validate(&config)?;
+        let mut app = App::new();
+        app.insert_resource(get_rapier_configuration())
+            .insert_resource(skeleton)
+            .insert_resource(config)
+            .insert_resource(env_config)
+            .add_plugins(HeadlessPlugins)
+            .add_plugin(RapierPhysicsPlugin::<NoUserData>::default())
+            .add_plugin(HumanoidEnvPlugin)
+            .add_startup_system(ground_start_system)
+            .add_startup_system(rapier_config_start_system);
+        Ok(Self { app })
+    }
+
+    pub fn num_envs(&self) -> usize {
+        self.app.world.resource::<EnvConfig>().num_envs
+    }
+
+    pub fn reset(&mut self, seed: u64) -> Vec<Observation> {
+        self.app
+            .world
+            .resource_mut::<Events<ResetEvent>>()
+            .send(ResetEvent { seed });
+        self.app.update();
+        self.env_steps()
+            .into_iter()
+            .map(|step| step.observation)
+            .collect()
+    }
+
+    /// One action per env, interpreted according to the `ActionConfig` resource.
+    /// Once `done`, the next step of that env starts a new episode when `ResetConfig::auto_reset` is set.
+    pub fn step(
+        &mut self,
+        actions: Vec<Action>,
+    ) -> (Vec<Observation>, Vec<f32>, Vec<bool>, Vec<StepInfo>) {
+        assert_eq!(actions.len(), self.num_envs(), "one action per env");
+        let mut query = self.app.world.query::<(&EnvIndex, &mut Action)>();
+        for (index, mut current) in query.iter_mut(&mut self.app.world) {
+            *current = actions[index.0].clone();
+        }
+        self.app.update();
+        let names = self.app.world.resource::<RewardConfig>().names();
+        let mut observations = vec![];
+        let mut rewards = vec![];
+        let mut dones = vec![];
+        let mut infos = vec![];
+        for step in self.env_steps() {
+            infos.push(StepInfo {
+                steps: step.steps,
+                reward_terms: names.iter().copied().zip(step.reward_terms).collect(),
+                termination: step.termination,
+            });
+            observations.push(step.observation);
+            rewards.push(step.reward);
+            dones.push(step.done);
+        }
+        (observations, rewards, dones, infos)
+    }
+
+    fn env_steps(&mut self) -> Vec<EnvStep> {
+        let mut query = self.app.world.query::<(&EnvIndex, &EnvStep)>();
+        let mut steps: Vec<(EnvIndex, EnvStep)> = query
+            .iter(&self.app.world)
+            .map(|(index, step)| (*index, step.clone()))
+            .collect();
+        assert!(!steps.is_empty(), "no humanoid in the env");
+        steps.sort_by_key(|(index, _)| *index);
+        steps.into_iter().map(|(_, step)| step).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{observation::HUMANOID_OBS, skeleton::HUMANOID_SKELETON_PATH};
+
+    #[test]
+    fn reset_and_step_are_batched_per_env() {
+        let mut env = HumanoidEnv::new(
+            SkeletonDesc::load(HUMANOID_SKELETON_PATH).unwrap(),
+            HumanoidConfig::default(),
+            EnvConfig {
+                num_envs: 2,
+                ..default()
+            },
+        )
+        .unwrap();
+        let observations = env.reset(0);
+        assert_eq!(observations.len(), 2);
+        assert!(observations.iter().all(|o| o.len() == HUMANOID_OBS));
+
+        let (observations, rewards, dones, infos) = env.step(vec![Action::default(); 2]);
+        assert_eq!(observations.len(), 2);
+        assert_eq!(dones.len(), 2);
+        let names = RewardConfig::default().names();
+        for (reward, info) in rewards.iter().zip(infos.iter()) {
+            assert_eq!(info.steps, 1);
+            assert_eq!(info.reward_terms.len(), names.len());
+            let sum: f32 = info.reward_terms.iter().map(|(_, value)| value).sum();
+            assert!((sum - reward).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn episodes_time_out_after_max_steps_actions() {
+        let mut env = HumanoidEnv::new(
+            SkeletonDesc::load(HUMANOID_SKELETON_PATH).unwrap(),
+            HumanoidConfig::default(),
+            EnvConfig::default(),
+        )
+        .unwrap();
+        env.app.insert_resource(TerminationConfig {
+            max_steps: 3,
+            ..default()
+        });
+        env.reset(0);
+        for steps in 1..=3 {
+            let (_, _, dones, infos) = env.step(vec![Action::default()]);
+            assert_eq!(infos[0].steps, steps);
+            assert_eq!(dones[0], steps == 3);
+        }
+        // the reset frame takes no action
+        let (_, _, dones, infos) = env.step(vec![Action::default()]);
+        assert_eq!((infos[0].steps, dones[0]), (0, false));
+    }
+
+    #[test]
+    fn rejects_env_configs_that_do_not_fit() {
+        let config = |num_envs| EnvConfig {
+            num_envs,
+            ..default()
+        };
+        let collide = HumanoidConfig {
+            self_collision: true,
+            ..default()
+        };
+        assert!(config(0).validate(&default()).is_err());
+        // a 10 by 10 grid 2 m apart covers the 20 m ground, 101 envs need an 11th column
+        assert!(config(100).validate(&default()).is_ok());
+        assert!(config(101).validate(&default()).is_err());
+        let sparse = EnvConfig {
+            spacing: 5.,
+            ..config(17)
+        };
+        assert!(sparse.validate(&default()).is_err());
+        assert!(config(MAX_SELF_COLLISION_ENVS).validate(&collide).is_ok());
+        let too_many = config(MAX_SELF_COLLISION_ENVS + 1);
+        assert!(too_many.validate(&collide).is_err());
+        let skeleton = SkeletonDesc::load(HUMANOID_SKELETON_PATH).unwrap();
+        assert!(HumanoidEnv::new(skeleton, default(), config(0)).is_err());
+    }
+}
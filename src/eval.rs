@@ -0,0 +1,221 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use bevy::prelude::*;
+
+use crate::{
+    action::{Action, ActionConfig},
+    agent::Agent,
+    checkpoint::load_checkpoint,
+    env::{EnvIndex, EnvStep, EnvSystem, Observation, ResetEvent},
+    termination::EpisodeEnded,
+};
+
+/// Font of the `EvalStatsText`, relative to the assets folder.
+pub const EVAL_FONT_PATH: &str = "fonts/DejaVuSansMono.ttf";
+
+/// Checkpoints to evaluate, `N` and `P` switch between them.
+pub struct EvalConfig {
+    pub checkpoints: Vec<PathBuf>,
+    pub current: usize,
+}
+impl EvalConfig {
+    /// A single checkpoint, or every checkpoint of a `CheckpointConfig::dir` by step,
+    /// starting with the latest. Errors when `path` holds no checkpoint.
+    pub fn new(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let mut checkpoints: Vec<PathBuf> = match path.join("state.json").exists() {
+            true => vec![path.to_path_buf()],
+            false => fs::read_dir(path)
+                .map_err(|e| format!("can't read checkpoints in {:?}: {}", path, e))?
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .filter(|p| p.join("state.json").exists())
+                .collect(),
+        };
+        checkpoints.sort_by_key(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str()?.parse::<usize>().ok())
+                .unwrap_or(0)
+        });
+        if checkpoints.is_empty() {
+            return Err(format!("no checkpoints in {:?}", path).into());
+        }
+        Ok(Self {
+            current: checkpoints.len() - 1,
+            checkpoints,
+        })
+    }
+}
+
+/// Episodes of the current checkpoint.
+#[derive(Default)]
+pub struct EvalStats {
+    pub episodes: usize,
+    pub last_return: f32,
+    pub last_length: usize,
+    pub total_return: f32,
+}
+
+/// UI text node showing the `EvalStats` in the top left corner.
+#[derive(Component)]
+pub struct EvalStatsText;
+
+/// Drives the humanoids with the deterministic policy of a checkpoint, no learning.
+pub fn add_eval_agent<A: Agent>(app: &mut App, mut agent: A, config: EvalConfig) {
+    let path = &config.checkpoints[config.current];
+    load_checkpoint(&mut agent, path).unwrap();
+    println!(
+        "evaluating {} from {:?}, N/P - next/previous checkpoint",
+        agent.name(),
+        path
+    );
+    app.insert_resource(ActionConfig {
+        mode: agent.action_mode(),
+    })
+    .insert_resource(config)
+    .init_resource::<EvalStats>()
+    .insert_non_send_resource(agent)
+    .add_startup_system(eval_stats_start_system)
+    .add_system(eval_switch_system::<A>)
+    .add_system_to_stage(
+        CoreStage::PostUpdate,
+        eval_agent_system::<A>.after(EnvSystem::Termination),
+    )
+    .add_system_to_stage(
+        CoreStage::PostUpdate,
+        eval_stats_system.after(EnvSystem::Termination),
+    );
+}
+
+pub fn eval_agent_system<A: Agent>(
+    mut agent: NonSendMut<A>,
+    mut roots: Query<(&EnvIndex, &EnvStep, &mut Action)>,
+) {
+    let mut envs: Vec<_> = roots.iter_mut().collect();
+    envs.sort_by_key(|(index, _, _)| **index);
+    let observations: Vec<Observation> = envs
+        .iter()
+        .map(|(_, step, _)| step.observation.clone())
+        .collect();
+    let actions = agent.act_deterministic(&observations);
+    for ((_, _, action), next) in envs.iter_mut().zip(actions) {
+        **action = next;
+    }
+}
+
+/// Loads the next or previous checkpoint and restarts the episodes from the same seed.
+pub fn eval_switch_system<A: Agent>(
+    input: Option<Res<Input<KeyCode>>>,
+    mut agent: NonSendMut<A>,
+    mut config: ResMut<EvalConfig>,
+    mut stats: ResMut<EvalStats>,
+    mut resets: EventWriter<ResetEvent>,
+) {
+    let input = match input {
+        Some(input) => input,
+        None => return,
+    };
+    let count = config.checkpoints.len();
+    let offset = match (
+        input.just_pressed(KeyCode::N),
+        input.just_pressed(KeyCode::P),
+    ) {
+        (true, _) => 1,
+        (_, true) => count - 1,
+        _ => return,
+    };
+    config.current = (config.current + offset) % count;
+    let path = &config.checkpoints[config.current];
+    match load_checkpoint(&mut *agent, path) {
+        Ok(step) => println!("evaluating checkpoint {:?} of step {}", path, step),
+        Err(e) => eprintln!("failed to load checkpoint {:?}: {}", path, e),
+    }
+    *stats = EvalStats::default();
+    resets.send(ResetEvent { seed: 0 });
+}
+
+pub fn eval_stats_start_system(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let style = TextStyle {
+        font: asset_server.load(EVAL_FONT_PATH),
+        font_size: 18.,
+        color: Color::WHITE,
+    };
+    commands
+        .spawn_bundle(TextBundle::from_section("", style).with_style(Style {
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                top: Val::Px(10.),
+                left: Val::Px(10.),
+                ..default()
+            },
+            ..default()
+        }))
+        .insert(Name::new("eval stats"))
+        .insert(EvalStatsText);
+}
+
+/// Checkpoint, last episode return and length and mean return in the `EvalStatsText`.
+pub fn eval_stats_system(
+    mut events: EventReader<EpisodeEnded>,
+    config: Res<EvalConfig>,
+    mut stats: ResMut<EvalStats>,
+    mut texts: Query<&mut Text, With<EvalStatsText>>,
+) {
+    for event in events.iter() {
+        stats.episodes += 1;
+        stats.last_return = event.reward;
+        stats.last_length = event.steps;
+        stats.total_return += event.reward;
+    }
+    if !stats.is_changed() && !config.is_changed() {
+        return;
+    }
+    let checkpoint = config.checkpoints[config.current]
+        .file_name()
+        .map_or(String::new(), |n| n.to_string_lossy().to_string());
+    let mean_return = match stats.episodes {
+        0 => 0.,
+        n => stats.total_return / n as f32,
+    };
+    let value = format!(
+        "checkpoint {} ({}/{})\nepisode {} return {:.2} length {}\nmean return {:.2}",
+        checkpoint,
+        config.current + 1,
+        config.checkpoints.len(),
+        stats.episodes,
+        stats.last_return,
+        stats.last_length,
+        mean_return
+    );
+    for mut text in texts.iter_mut() {
+        text.sections[0].value = value.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_checkpoints_by_step() {
+        let dir = std::env::temp_dir().join(format!("eval_checkpoints_{}", std::process::id()));
+        for step in ["12", "3", "unfinished"] {
+            fs::create_dir_all(dir.join(step)).unwrap();
+        }
+        fs::write(dir.join("12/state.json"), "{}").unwrap();
+        fs::write(dir.join("3/state.json"), "{}").unwrap();
+        let config = EvalConfig::new(&dir).unwrap();
+        assert_eq!(config.checkpoints, [dir.join("3"), dir.join("12")]);
+        assert_eq!(config.current, 1);
+        let single = EvalConfig::new(&dir.join("3")).unwrap();
+        assert_eq!(single.checkpoints, [dir.join("3")]);
+        // the error names the path
+        let error = EvalConfig::new(&dir.join("unfinished")).err().unwrap();
+        assert!(error.to_string().contains("unfinished"));
+        let error = EvalConfig::new(&dir.join("missing")).err().unwrap();
+        assert!(error.to_string().contains("missing"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
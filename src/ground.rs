@@ -3,31 +3,26 @@ use bevy_rapier3d::prelude::*;
 
 pub const STATIC_GROUP: u32 = 0b010;
 
-pub fn ground_start_system(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-) {
+/// Half the side of the square ground centered on the origin.
+pub const GROUND_HALF_SIZE: f32 = 10.;
+
+#[derive(Component)]
+pub struct Ground {
+    pub half_size: Vec3,
+}
+
+pub fn ground_start_system(mut commands: Commands) {
     let num_cols: usize = 20;
     let num_rows: usize = 20;
-    let hx = 10.;
+    let hx = GROUND_HALF_SIZE;
     let hy = 0.;
-    let hz = 10.;
+    let hz = GROUND_HALF_SIZE;
     let heights: Vec<Real> = vec![hy; num_rows * num_cols];
     commands
         .spawn()
         .insert(Name::new("ground"))
-        .insert_bundle(PbrBundle {
-            mesh: meshes.add(Mesh::from(shape::Box {
-                max_x: hx,
-                min_x: -hx,
-                max_y: hy,
-                min_y: -hy,
-                max_z: hz,
-                min_z: -hz,
-            })),
-            material: materials.add(Color::rgb(0.2, 0.4, 0.15).into()),
-            ..default()
+        .insert(Ground {
+            half_size: Vec3::new(hx, hy, hz),
         })
         .insert(RigidBody::Fixed)
         .insert_bundle(TransformBundle::from_transform(Transform::identity()))
@@ -1,556 +1,492 @@
 use bevy::prelude::*;
-use bevy_rapier3d::{prelude::*, rapier::prelude::JointAxesMask};
+use bevy_rapier3d::{
+    prelude::*,
+    rapier::prelude::{JointAxesMask, JointAxis},
+};
+use obj::{load_obj, Obj, Position};
+use serde::Deserialize;
 
-use crate::ground::STATIC_GROUP;
+use crate::{
+    ground::STATIC_GROUP,
+    skeleton::{AxisDesc, JointDesc, PartDesc, ShapeDesc, SkeletonDesc},
+};
 
 pub const HUMANOID_TRAINING_GROUP: u32 = 0b001;
 
-pub fn humanoid_start_system(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-) {
-    spawn_humanoid(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        Transform::from_xyz(0., 1., 0.),
+/// Direction the humanoid faces in the part frames of its rest pose, with +Y up: knees bend
+/// and toes point along it. Skeleton descriptions and the forward velocity reward share it.
+pub const FORWARD: Vec3 = Vec3::Z;
+
+/// Envs with a collision group of their own, see `get_collision_groups`.
+pub const MAX_SELF_COLLISION_ENVS: usize = 31;
+
+/// Collision groups of the humanoid of env `index`, humanoids never collide with each other.
+/// Bits above `STATIC_GROUP` give `MAX_SELF_COLLISION_ENVS` distinct groups. Envs further apart
+/// share them, which only matters with `self_collision`, so it panics for those envs.
+pub fn get_collision_groups(index: usize, self_collision: bool) -> CollisionGroups {
+    assert!(
+        !self_collision || index < MAX_SELF_COLLISION_ENVS,
+        "self-collision supports at most {} envs",
+        MAX_SELF_COLLISION_ENVS
     );
+    let group = match index {
+        0 => HUMANOID_TRAINING_GROUP,
+        _ => 1 << (2 + (index - 1) % 30),
+    };
+    match self_collision {
+        true => CollisionGroups::new(group, STATIC_GROUP | group),
+        false => CollisionGroups::new(group, STATIC_GROUP),
+    }
 }
 
-#[derive(Debug, Clone)]
+/// How parts are jointed: `Impulse` joints are solved as constraints between free bodies,
+/// `Multibody` joints build a reduced-coordinate articulation which cannot drift apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JointMode {
+    Impulse,
+    Multibody,
+}
+
+pub struct HumanoidConfig {
+    pub joint_mode: JointMode,
+    /// Limbs collide with each other, except for parts jointed directly to each other.
+    pub self_collision: bool,
+}
+impl Default for HumanoidConfig {
+    fn default() -> Self {
+        Self {
+            joint_mode: JointMode::Impulse,
+            self_collision: false,
+        }
+    }
+}
+
+pub fn get_spawn_transform(skeleton: &SkeletonDesc) -> Transform {
+    match skeleton.root_position {
+        Some(position) => Transform::from_translation(Vec3::from(position)),
+        None => Transform::from_xyz(0., 1.2, 0.),
+    }
+}
+
+/// Root of a spawned humanoid, lists its parts in spawn order.
+/// `joints` are the parts jointed to a parent, their motors form the action space.
+#[derive(Component, Debug, Clone)]
+pub struct Humanoid {
+    pub parts: Vec<Entity>,
+    pub joints: Vec<Entity>,
+}
+
+/// Rest pose a part was spawned at, to teleport it back on reset.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct InitialPose(pub Transform);
+
+/// Shape of a part collider, rendered by `humanoid_visuals_system` when a renderer is present.
+#[derive(Component, Debug, Clone)]
+pub struct PartGeom {
+    pub shape: ShapeDesc,
+    /// RGBA.
+    pub color: [f32; 4],
+}
+
+#[derive(Debug, Clone, Deserialize)]
 pub struct BodySize {
     pub hw: f32,
     pub hh: f32,
     pub hl: f32,
     pub br: f32,
 }
-impl BodySize {
-    pub fn body() -> Self {
-        Self {
-            hw: 0.2,
-            hh: 0.3,
-            hl: 0.1,
-            br: 0.05,
-        }
+
+pub fn get_dir_unit(i: usize) -> f32 {
+    match i {
+        0 => 1.,
+        _ => -1.,
     }
-    pub fn head() -> Self {
-        Self {
-            hw: 0.1,
-            hh: 0.12,
-            hl: 0.13,
-            br: 0.05,
-        }
+}
+
+/// Twist (ANG_Y) and abduction (ANG_Z) change sign on the mirrored side, flexion (ANG_X) does not.
+/// Angular limits are converted from degrees to radians.
+pub fn get_mirrored_limits(axis: JointAxis, limits: &[f32; 2], i: usize) -> [f32; 2] {
+    let [min, max] = match axis {
+        JointAxis::X | JointAxis::Y | JointAxis::Z => *limits,
+        _ => [limits[0].to_radians(), limits[1].to_radians()],
+    };
+    match axis {
+        JointAxis::X | JointAxis::AngY | JointAxis::AngZ if get_dir_unit(i) < 0. => [-max, -min],
+        _ => [min, max],
     }
-    pub fn femur() -> Self {
-        Self {
-            hw: 0.05,
-            hh: 0.2,
-            hl: 0.05,
-            br: 0.02,
+}
+
+pub fn get_mirrored_anchor(anchor: &[f32; 3], i: usize) -> Vec3 {
+    Vec3::new(anchor[0] * get_dir_unit(i), anchor[1], anchor[2])
+}
+
+pub fn get_collider(shape: &ShapeDesc) -> Collider {
+    match shape {
+        ShapeDesc::RoundBox(size) => Collider::round_cuboid(
+            size.hw - size.br,
+            size.hh - size.br,
+            size.hl - size.br,
+            size.br,
+        ),
+        ShapeDesc::Capsule {
+            half_height,
+            radius,
+        } => Collider::capsule_y(*half_height, *radius),
+        ShapeDesc::Sphere { radius } => Collider::ball(*radius),
+        ShapeDesc::Cylinder {
+            half_height,
+            radius,
+        } => Collider::cylinder(*half_height, *radius),
+        ShapeDesc::Mesh { path, scale } => {
+            let (vertices, _) = load_obj_mesh(path, Vec3::from(*scale));
+            Collider::convex_hull(&vertices)
+                .unwrap_or_else(|| panic!("mesh {} has no convex hull", path))
         }
     }
-    pub fn tibia() -> Self {
-        Self {
-            hw: 0.04,
-            hh: 0.2,
-            hl: 0.04,
-            br: 0.02,
-        }
+}
+
+pub fn load_obj_mesh(path: &str, scale: Vec3) -> (Vec<Vec3>, Vec<[u32; 3]>) {
+    let file = std::fs::File::open(path).unwrap_or_else(|e| panic!("mesh {}: {}", path, e));
+    let obj: Obj<Position, u32> = load_obj(std::io::BufReader::new(file))
+        .unwrap_or_else(|e| panic!("mesh {}: {}", path, e));
+    let vertices = obj
+        .vertices
+        .iter()
+        .map(|v| Vec3::from(v.position) * scale)
+        .collect();
+    let indices = obj
+        .indices
+        .chunks_exact(3)
+        .map(|t| [t[0], t[1], t[2]])
+        .collect();
+    (vertices, indices)
+}
+
+pub fn get_axis_mask(axis: JointAxis) -> JointAxesMask {
+    match axis {
+        JointAxis::X => JointAxesMask::X,
+        JointAxis::Y => JointAxesMask::Y,
+        JointAxis::Z => JointAxesMask::Z,
+        JointAxis::AngX => JointAxesMask::ANG_X,
+        JointAxis::AngY => JointAxesMask::ANG_Y,
+        JointAxis::AngZ => JointAxesMask::ANG_Z,
     }
-    pub fn foot() -> Self {
-        Self {
-            hw: 0.04,
-            hh: 0.02,
-            hl: 0.1,
-            br: 0.01,
+}
+
+/// With the default `axis` joint frames use the parent part axes:
+/// ANG_X - flexion/extension, ANG_Y - twist along the bone, ANG_Z - abduction.
+pub fn get_joint(joint: &JointDesc, i: usize) -> GenericJoint {
+    let mask = joint
+        .axes
+        .iter()
+        .fold(JointAxesMask::LOCKED_FIXED_AXES, |mask, a| {
+            mask - get_axis_mask(a.axis)
+        });
+    let axis = Vec3::from(joint.axis).normalize();
+    let mut builder = GenericJointBuilder::new(mask)
+        .local_axis1(axis)
+        .local_axis2(axis)
+        .local_anchor1(get_mirrored_anchor(&joint.anchor1, i))
+        .local_anchor2(get_mirrored_anchor(&joint.anchor2, i))
+        // jointed parts overlap around the anchor, they never collide even with self-collision
+        .contacts_enabled(false);
+    for a in joint.axes.iter() {
+        builder = builder
+            .motor_position(a.axis, 0., a.stiffness, a.damping)
+            .motor_max_force(a.axis, a.max_force);
+        if let Some(limits) = a.limits {
+            builder = builder.limits(a.axis, get_mirrored_limits(a.axis, &limits, i));
         }
     }
-    pub fn upperarm() -> Self {
+    builder.build()
+}
+
+#[derive(Debug, Clone)]
+pub struct AxisMotor {
+    pub axis: JointAxis,
+    pub target_pos: f32,
+    pub target_vel: f32,
+    pub stiffness: f32,
+    pub damping: f32,
+    pub max_force: f32,
+    /// Position range in radians or meters, the whole turn for unlimited angular axes.
+    pub limits: [f32; 2],
+}
+impl AxisMotor {
+    pub fn new(a: &AxisDesc, i: usize) -> Self {
+        let limits = match (a.limits, a.axis) {
+            (Some(limits), _) => get_mirrored_limits(a.axis, &limits, i),
+            (None, JointAxis::X | JointAxis::Y | JointAxis::Z) => [-1., 1.],
+            (None, _) => [-std::f32::consts::PI, std::f32::consts::PI],
+        };
         Self {
-            hw: 0.04,
-            hh: 0.15,
-            hl: 0.04,
-            br: 0.02,
+            axis: a.axis,
+            target_pos: 0.,
+            target_vel: 0.,
+            stiffness: a.stiffness,
+            damping: a.damping,
+            max_force: a.max_force,
+            limits,
         }
     }
-    pub fn forearm() -> Self {
+}
+
+/// Motor targets of every free axis of a part's joint to its parent.
+/// Controllers and policies write targets here, `joint_motor_system` pushes them to rapier.
+#[derive(Component, Debug, Clone)]
+pub struct JointMotors {
+    pub motors: Vec<AxisMotor>,
+}
+impl JointMotors {
+    pub fn new(joint: &JointDesc, i: usize) -> Self {
         Self {
-            hw: 0.03,
-            hh: 0.15,
-            hl: 0.03,
-            br: 0.02,
+            motors: joint.axes.iter().map(|a| AxisMotor::new(a, i)).collect(),
         }
     }
-    pub fn palm() -> Self {
-        Self {
-            hw: 0.04,
-            hh: 0.1,
-            hl: 0.01,
-            br: 0.01,
-        }
+    pub fn axes(&self) -> Vec<JointAxis> {
+        self.motors.iter().map(|m| m.axis).collect()
     }
 }
 
-trait BodyPartPbrBundle {
-    fn from_halfsize(
-        hs: &BodySize,
-        meshes: &mut ResMut<Assets<Mesh>>,
-        materials: &mut ResMut<Assets<StandardMaterial>>,
-        color: &Color,
-    ) -> Self;
+/// Joint geometry kept alongside rapier's joint, to measure joint positions from part transforms.
+#[derive(Component, Debug, Clone)]
+pub struct JointFrame {
+    pub parent: Entity,
+    /// Rotation from the part axes to the joint axes, the same in both part frames.
+    pub basis: Quat,
+    pub anchor1: Vec3,
+    pub anchor2: Vec3,
 }
-impl BodyPartPbrBundle for PbrBundle {
-    fn from_halfsize(
-        hs: &BodySize,
-        meshes: &mut ResMut<Assets<Mesh>>,
-        materials: &mut ResMut<Assets<StandardMaterial>>,
-        color: &Color,
-    ) -> Self {
-        PbrBundle {
-            mesh: meshes.add(Mesh::from(shape::Box {
-                max_x: hs.hw,
-                min_x: -hs.hw,
-                max_y: hs.hh,
-                min_y: -hs.hh,
-                max_z: hs.hl,
-                min_z: -hs.hl,
-            })),
-            material: materials.add((*color).into()),
-            ..default()
+impl JointFrame {
+    pub fn new(parent: Entity, joint: &JointDesc, i: usize) -> Self {
+        Self {
+            parent,
+            basis: Quat::from_rotation_arc(Vec3::X, Vec3::from(joint.axis).normalize()),
+            anchor1: get_mirrored_anchor(&joint.anchor1, i),
+            anchor2: get_mirrored_anchor(&joint.anchor2, i),
         }
     }
+    /// Joint frame rotation in world space, attached to the parent part.
+    pub fn rotation(&self, parent: &Transform) -> Quat {
+        parent.rotation * self.basis
+    }
+    /// Position of every free axis: angles in radians for angular axes,
+    /// offsets along the joint axes for linear ones.
+    pub fn positions(&self, axes: &[JointAxis], parent: &Transform, child: &Transform) -> Vec<f32> {
+        let frame = self.rotation(parent);
+        let relative = frame.inverse() * child.rotation * self.basis;
+        let (ax, ay, az) = relative.to_euler(EulerRot::XYZ);
+        let offset = frame.inverse()
+            * (child.translation + child.rotation * self.anchor2
+                - parent.translation
+                - parent.rotation * self.anchor1);
+        axes.iter()
+            .map(|axis| match axis {
+                JointAxis::X => offset.x,
+                JointAxis::Y => offset.y,
+                JointAxis::Z => offset.z,
+                JointAxis::AngX => ax,
+                JointAxis::AngY => ay,
+                JointAxis::AngZ => az,
+            })
+            .collect()
+    }
+    /// Relative velocity of every free axis, in the joint frame.
+    pub fn velocities(
+        &self,
+        axes: &[JointAxis],
+        parent: (&Transform, &Velocity),
+        child: &Velocity,
+    ) -> Vec<f32> {
+        let frame = self.rotation(parent.0).inverse();
+        let angvel = frame * (child.angvel - parent.1.angvel);
+        let linvel = frame * (child.linvel - parent.1.linvel);
+        axes.iter()
+            .map(|axis| match axis {
+                JointAxis::X => linvel.x,
+                JointAxis::Y => linvel.y,
+                JointAxis::Z => linvel.z,
+                JointAxis::AngX => angvel.x,
+                JointAxis::AngY => angvel.y,
+                JointAxis::AngZ => angvel.z,
+            })
+            .collect()
+    }
 }
 
-pub fn get_dir_unit(i: usize) -> f32 {
-    match i {
-        0 => 1.,
-        _ => -1.,
+fn set_joint_motors(joint: &mut GenericJoint, joint_motors: &JointMotors) {
+    for m in joint_motors.motors.iter() {
+        joint
+            .set_motor(m.axis, m.target_pos, m.target_vel, m.stiffness, m.damping)
+            .set_motor_max_force(m.axis, m.max_force);
     }
 }
 
-pub fn get_collider(size: &BodySize) -> Collider {
-    Collider::round_cuboid(
-        size.hw - size.br,
-        size.hh - size.br,
-        size.hl - size.br,
-        size.br,
-    )
+pub fn joint_motor_system(
+    mut query: Query<
+        (
+            &JointMotors,
+            Option<&mut ImpulseJoint>,
+            Option<&mut MultibodyJoint>,
+        ),
+        Changed<JointMotors>,
+    >,
+) {
+    for (joint_motors, impulse_joint, multibody_joint) in query.iter_mut() {
+        if let Some(mut joint) = impulse_joint {
+            set_joint_motors(&mut joint.data, joint_motors);
+        }
+        if let Some(mut joint) = multibody_joint {
+            set_joint_motors(&mut joint.data, joint_motors);
+        }
+    }
 }
 
-pub fn spawn_humanoid(
+fn spawn_part(
     commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
+    part: &PartDesc,
     transform: Transform,
+    side: usize,
+    groups: CollisionGroups,
 ) -> Entity {
-    let body_size = BodySize::body();
-    let body_id = commands
-        .spawn()
-        .insert(Name::new("body"))
-        .insert(Sleeping::disabled())
-        .insert_bundle(PbrBundle::from_halfsize(
-            &body_size,
-            meshes,
-            materials,
-            &Color::rgba(0.3, 0.2, 0.2, 0.5),
-        ))
-        .insert(RigidBody::Dynamic)
-        .insert(Ccd::enabled())
-        .insert(Damping {
-            linear_damping: 0.05,
-            angular_damping: 0.05,
-        })
-        .insert(Velocity::zero())
-        .insert(ExternalForce::default())
-        .insert_bundle(TransformBundle::from(transform))
-        .insert(ReadMassProperties::default())
-        .with_children(|children| {
-            children
-                .spawn()
-                .insert(Name::new("body_collider"))
-                .insert_bundle(TransformBundle::from(Transform::identity()))
-                .insert(get_collider(&body_size))
-                .insert(ColliderScale::Absolute(Vec3::ONE))
-                .insert(Friction::coefficient(0.5))
-                .insert(Restitution::coefficient(0.))
-                .insert(CollisionGroups::new(HUMANOID_TRAINING_GROUP, STATIC_GROUP))
-                .insert(CollidingEntities::default())
-                .insert(ActiveEvents::COLLISION_EVENTS)
-                .insert(ContactForceEventThreshold(0.1))
-                .insert(ColliderMassProperties::MassProperties(MassProperties {
-                    mass: 20.0,
-                    principal_inertia: Vec3::new(5., 2., 5.),
-                    ..default()
-                }));
-        })
-        .id();
-
-    let head_size = BodySize::head();
-    let body_head_joint_mask = JointAxesMask::LOCKED_FIXED_AXES;
-    commands
-        .spawn()
-        .insert(Name::new("head"))
+    let mut entity_commands = commands.spawn();
+    entity_commands
+        .insert(Name::new(part.name.clone()))
         .insert(Sleeping::disabled())
-        .insert_bundle(PbrBundle::from_halfsize(
-            &head_size,
-            meshes,
-            materials,
-            &Color::rgba(0.3, 0.3, 0.3, 0.5),
-        ))
         .insert(RigidBody::Dynamic)
         .insert(Ccd::enabled())
         .insert(Velocity::zero())
         .insert(ExternalForce::default())
         .insert_bundle(TransformBundle::from(transform))
+        .insert(InitialPose(transform))
         .insert(ReadMassProperties::default())
         .with_children(|children| {
-            children
-                .spawn()
-                .insert(Name::new("head_collider"))
-                .insert_bundle(TransformBundle::from(Transform::identity()))
-                .insert(get_collider(&head_size))
-                .insert(ColliderScale::Absolute(Vec3::ONE))
-                .insert(Friction::coefficient(0.5))
-                .insert(Restitution::coefficient(0.))
-                .insert(CollisionGroups::new(HUMANOID_TRAINING_GROUP, STATIC_GROUP))
-                .insert(CollidingEntities::default())
-                .insert(ActiveEvents::COLLISION_EVENTS)
-                .insert(ContactForceEventThreshold(0.1))
-                .insert(ColliderMassProperties::MassProperties(MassProperties {
-                    mass: 5.0,
-                    principal_inertia: Vec3::new(0.5, 0.2, 0.5),
-                    ..default()
-                }));
-        })
-        .insert(ImpulseJoint::new(
-            body_id,
-            GenericJointBuilder::new(body_head_joint_mask)
-                .local_axis1(Vec3::Y)
-                .local_axis2(Vec3::Y)
-                .local_anchor1(Vec3::new(0., body_size.hh + head_size.hh + 0.1, 0.))
-                .local_anchor2(Vec3::new(0., head_size.hh, 0.))
-                .build(),
-        ));
-
-    // LEGS
-    let femur_size = BodySize::femur();
-    let tibia_size = BodySize::tibia();
-    let foot_size = BodySize::foot();
-    let body_femur_joint_mask = JointAxesMask::LOCKED_FIXED_AXES;
-    // JointAxesMask::X
-    // | JointAxesMask::Y // vertical suspension
-    // | JointAxesMask::Z // tire suspension along car
-    // | JointAxesMask::ANG_X // wheel main axis
-    // JointAxesMask::ANG_Y
-    // | JointAxesMask::ANG_Z;
-
-    let mut femur_entities: Vec<Entity> = vec![];
-    for i in 0..2 {
-        let femur_id = commands
-            .spawn()
-            .insert(Name::new("femur"))
-            .insert(Sleeping::disabled())
-            .insert_bundle(PbrBundle::from_halfsize(
-                &femur_size,
-                meshes,
-                materials,
-                &Color::rgba(0.2, 0.3, 0.2, 0.5),
-            ))
-            .insert(RigidBody::Dynamic)
-            .insert(Ccd::enabled())
-            .insert(Velocity::zero())
-            .insert(ExternalForce::default())
-            .insert_bundle(TransformBundle::from(transform))
-            .insert(ReadMassProperties::default())
-            .with_children(|children| {
-                children
-                    .spawn()
-                    .insert(Name::new("femur_collider"))
-                    .insert_bundle(TransformBundle::from(Transform::identity()))
-                    .insert(get_collider(&femur_size))
-                    .insert(ColliderScale::Absolute(Vec3::ONE))
-                    .insert(Friction::coefficient(0.5))
-                    .insert(Restitution::coefficient(0.))
-                    .insert(CollisionGroups::new(HUMANOID_TRAINING_GROUP, STATIC_GROUP))
-                    .insert(CollidingEntities::default())
-                    .insert(ActiveEvents::COLLISION_EVENTS)
-                    .insert(ContactForceEventThreshold(0.1))
-                    .insert(ColliderMassProperties::MassProperties(MassProperties {
-                        mass: 5.0,
-                        principal_inertia: Vec3::new(0.5, 0.2, 0.5),
+            for (geom_index, geom) in part.geoms.iter().enumerate() {
+                let mass_properties = match (part.density, geom_index) {
+                    (Some(density), _) => ColliderMassProperties::Density(density),
+                    (None, 0) => ColliderMassProperties::MassProperties(MassProperties {
+                        mass: part.mass,
+                        principal_inertia: Vec3::from(part.inertia),
                         ..default()
-                    }));
-            })
-            .insert(ImpulseJoint::new(
-                body_id,
-                GenericJointBuilder::new(body_femur_joint_mask)
-                    .local_axis1(Vec3::Y)
-                    .local_axis2(Vec3::Y)
-                    .local_anchor1(Vec3::new(0.1 * get_dir_unit(i), -body_size.hh, 0.))
-                    .local_anchor2(Vec3::new(0., femur_size.hh, 0.))
-                    .build(),
-            ))
-            .id();
-        femur_entities.push(femur_id);
-    }
-
-    let femur_tibia_joint_mask = JointAxesMask::LOCKED_FIXED_AXES;
-
-    let mut tibia_entities: Vec<Entity> = vec![];
-    for i in 0..2 {
-        let tibia_id = commands
-            .spawn()
-            .insert(Name::new("tibia"))
-            .insert(Sleeping::disabled())
-            .insert_bundle(PbrBundle::from_halfsize(
-                &tibia_size,
-                meshes,
-                materials,
-                &Color::rgba(0.2, 0.2, 0.3, 0.5),
-            ))
-            .insert(RigidBody::Dynamic)
-            .insert(Ccd::enabled())
-            .insert(Velocity::zero())
-            .insert(ExternalForce::default())
-            .insert_bundle(TransformBundle::from(transform))
-            .insert(ReadMassProperties::default())
-            .with_children(|children| {
+                    }),
+                    (None, _) => ColliderMassProperties::Density(0.),
+                };
+                let mut geom_transform = geom.transform();
+                geom_transform.translation.x *= get_dir_unit(side);
                 children
                     .spawn()
-                    .insert(Name::new("tibia_collider"))
-                    .insert_bundle(TransformBundle::from(Transform::identity()))
-                    .insert(get_collider(&tibia_size))
+                    .insert(Name::new(format!("{}_collider", part.name)))
+                    .insert_bundle(TransformBundle::from(geom_transform))
+                    .insert(PartGeom {
+                        shape: geom.shape.clone(),
+                        color: part.color,
+                    })
+                    .insert(get_collider(&geom.shape))
                     .insert(ColliderScale::Absolute(Vec3::ONE))
                     .insert(Friction::coefficient(0.5))
                     .insert(Restitution::coefficient(0.))
-                    .insert(CollisionGroups::new(HUMANOID_TRAINING_GROUP, STATIC_GROUP))
+                    .insert(groups)
                     .insert(CollidingEntities::default())
                     .insert(ActiveEvents::COLLISION_EVENTS)
                     .insert(ContactForceEventThreshold(0.1))
-                    .insert(ColliderMassProperties::MassProperties(MassProperties {
-                        mass: 5.0,
-                        principal_inertia: Vec3::new(0.5, 0.2, 0.5),
-                        ..default()
-                    }));
-            })
-            .insert(ImpulseJoint::new(
-                femur_entities[i],
-                GenericJointBuilder::new(femur_tibia_joint_mask)
-                    .local_axis1(Vec3::Y)
-                    .local_axis2(Vec3::Y)
-                    .local_anchor1(Vec3::new(0., -femur_size.hh, 0.))
-                    .local_anchor2(Vec3::new(0., tibia_size.hh, 0.))
-                    .build(),
-            ))
-            .id();
-        tibia_entities.push(tibia_id);
+                    .insert(mass_properties);
+            }
+        });
+    if let Some(damping) = part.damping {
+        entity_commands.insert(Damping {
+            linear_damping: damping,
+            angular_damping: damping,
+        });
     }
+    entity_commands.id()
+}
 
-    let tibia_foot_joint_mask = JointAxesMask::LOCKED_FIXED_AXES;
-    for i in 0..2 {
-        commands
-            .spawn()
-            .insert(Name::new("foot"))
-            .insert(Sleeping::disabled())
-            .insert_bundle(PbrBundle::from_halfsize(
-                &foot_size,
-                meshes,
-                materials,
-                &Color::rgba(0.2, 0.2, 0.2, 0.5),
-            ))
-            .insert(RigidBody::Dynamic)
-            .insert(Ccd::enabled())
-            .insert(Velocity::zero())
-            .insert(ExternalForce::default())
-            .insert_bundle(TransformBundle::from(transform))
-            .insert(ReadMassProperties::default())
-            .with_children(|children| {
-                children
-                    .spawn()
-                    .insert(Name::new("foot_collider"))
-                    .insert_bundle(TransformBundle::from(Transform::identity()))
-                    .insert(get_collider(&foot_size))
-                    .insert(ColliderScale::Absolute(Vec3::ONE))
-                    .insert(Friction::coefficient(0.5))
-                    .insert(Restitution::coefficient(0.))
-                    .insert(CollisionGroups::new(HUMANOID_TRAINING_GROUP, STATIC_GROUP))
-                    .insert(CollidingEntities::default())
-                    .insert(ActiveEvents::COLLISION_EVENTS)
-                    .insert(ContactForceEventThreshold(0.1))
-                    .insert(ColliderMassProperties::MassProperties(MassProperties {
-                        mass: 1.0,
-                        principal_inertia: Vec3::new(0.1, 0.1, 0.1),
-                        ..default()
-                    }));
-            })
-            .insert(ImpulseJoint::new(
-                tibia_entities[i],
-                GenericJointBuilder::new(tibia_foot_joint_mask)
-                    .local_axis1(Vec3::Y)
-                    .local_axis2(Vec3::Y)
-                    .local_anchor1(Vec3::new(0., -tibia_size.hh, 0.))
-                    .local_anchor2(Vec3::new(0., foot_size.hh, foot_size.hl - tibia_size.hl))
-                    .build(),
-            ));
-    }
+struct SpawnedParts {
+    parts: Vec<Entity>,
+    joints: Vec<Entity>,
+}
 
-    // ARMS
-    let upperarm_size = BodySize::upperarm();
-    let forearm_size = BodySize::forearm();
-    let palm_size = BodySize::palm();
-    let body_upperarm_joint_mask = JointAxesMask::LOCKED_FIXED_AXES;
-    let mut upperarm_entities: Vec<Entity> = vec![];
-    for i in 0..2 {
-        let upperarm_id = commands
-            .spawn()
-            .insert(Name::new("upperarm"))
-            .insert(Sleeping::disabled())
-            .insert_bundle(PbrBundle::from_halfsize(
-                &upperarm_size,
-                meshes,
-                materials,
-                &Color::rgba(0.2, 0.3, 0.2, 0.5),
-            ))
-            .insert(RigidBody::Dynamic)
-            .insert(Ccd::enabled())
-            .insert(Velocity::zero())
-            .insert(ExternalForce::default())
-            .insert_bundle(TransformBundle::from(transform))
-            .insert(ReadMassProperties::default())
-            .with_children(|children| {
-                children
-                    .spawn()
-                    .insert(Name::new("upperarm_collider"))
-                    .insert_bundle(TransformBundle::from(Transform::identity()))
-                    .insert(get_collider(&upperarm_size))
-                    .insert(ColliderScale::Absolute(Vec3::ONE))
-                    .insert(Friction::coefficient(0.5))
-                    .insert(Restitution::coefficient(0.))
-                    .insert(CollisionGroups::new(HUMANOID_TRAINING_GROUP, STATIC_GROUP))
-                    .insert(CollidingEntities::default())
-                    .insert(ActiveEvents::COLLISION_EVENTS)
-                    .insert(ContactForceEventThreshold(0.1))
-                    .insert(ColliderMassProperties::MassProperties(MassProperties {
-                        mass: 5.0,
-                        principal_inertia: Vec3::new(0.5, 0.2, 0.5),
-                        ..default()
-                    }));
-            })
-            .insert(ImpulseJoint::new(
-                body_id,
-                GenericJointBuilder::new(body_upperarm_joint_mask)
-                    .local_axis1(Vec3::Y)
-                    .local_axis2(Vec3::Y)
-                    .local_anchor1(Vec3::new(
-                        (body_size.hw + upperarm_size.hw) * get_dir_unit(i),
-                        body_size.hh,
-                        0.,
-                    ))
-                    .local_anchor2(Vec3::new(0., upperarm_size.hh, 0.))
-                    .build(),
-            ))
-            .id();
-        upperarm_entities.push(upperarm_id);
-    }
-    let upperarm_forearm_joint_mask = JointAxesMask::LOCKED_FIXED_AXES;
-    let mut forearm_entities: Vec<Entity> = vec![];
-    for i in 0..2 {
-        let forearm_id = commands
-            .spawn()
-            .insert(Name::new("forearm"))
-            .insert(Sleeping::disabled())
-            .insert_bundle(PbrBundle::from_halfsize(
-                &forearm_size,
-                meshes,
-                materials,
-                &Color::rgba(0.2, 0.2, 0.3, 0.5),
-            ))
-            .insert(RigidBody::Dynamic)
-            .insert(Ccd::enabled())
-            .insert(Velocity::zero())
-            .insert(ExternalForce::default())
-            .insert_bundle(TransformBundle::from(transform))
-            .insert(ReadMassProperties::default())
-            .with_children(|children| {
-                children
-                    .spawn()
-                    .insert(Name::new("forearm_collider"))
-                    .insert_bundle(TransformBundle::from(Transform::identity()))
-                    .insert(get_collider(&forearm_size))
-                    .insert(ColliderScale::Absolute(Vec3::ONE))
-                    .insert(Friction::coefficient(0.5))
-                    .insert(Restitution::coefficient(0.))
-                    .insert(CollisionGroups::new(HUMANOID_TRAINING_GROUP, STATIC_GROUP))
-                    .insert(CollidingEntities::default())
-                    .insert(ActiveEvents::COLLISION_EVENTS)
-                    .insert(ContactForceEventThreshold(0.1))
-                    .insert(ColliderMassProperties::MassProperties(MassProperties {
-                        mass: 5.0,
-                        principal_inertia: Vec3::new(0.5, 0.2, 0.5),
-                        ..default()
-                    }));
-            })
-            .insert(ImpulseJoint::new(
-                upperarm_entities[i],
-                GenericJointBuilder::new(upperarm_forearm_joint_mask)
-                    .local_axis1(Vec3::Y)
-                    .local_axis2(Vec3::Y)
-                    .local_anchor1(Vec3::new(0., -upperarm_size.hh, 0.))
-                    .local_anchor2(Vec3::new(0., forearm_size.hh, 0.))
-                    .build(),
-            ))
-            .id();
-        forearm_entities.push(forearm_id);
-    }
+struct SpawnedPart<'a> {
+    desc: &'a PartDesc,
+    id: Entity,
+    transform: Transform,
+    side: usize,
+}
 
-    let forearm_palm_joint_mask = JointAxesMask::LOCKED_FIXED_AXES;
-    for i in 0..2 {
-        commands
-            .spawn()
-            .insert(Name::new("palm"))
-            .insert(Sleeping::disabled())
-            .insert_bundle(PbrBundle::from_halfsize(
-                &palm_size,
-                meshes,
-                materials,
-                &Color::rgba(0.2, 0.2, 0.2, 0.5),
-            ))
-            .insert(RigidBody::Dynamic)
-            .insert(Ccd::enabled())
-            .insert(Velocity::zero())
-            .insert(ExternalForce::default())
-            .insert_bundle(TransformBundle::from(transform))
-            .insert(ReadMassProperties::default())
-            .with_children(|children| {
-                children
-                    .spawn()
-                    .insert(Name::new("palm_collider"))
-                    .insert_bundle(TransformBundle::from(Transform::identity()))
-                    .insert(get_collider(&palm_size))
-                    .insert(ColliderScale::Absolute(Vec3::ONE))
-                    .insert(Friction::coefficient(0.5))
-                    .insert(Restitution::coefficient(0.))
-                    .insert(CollisionGroups::new(HUMANOID_TRAINING_GROUP, STATIC_GROUP))
-                    .insert(CollidingEntities::default())
-                    .insert(ActiveEvents::COLLISION_EVENTS)
-                    .insert(ContactForceEventThreshold(0.1))
-                    .insert(ColliderMassProperties::MassProperties(MassProperties {
-                        mass: 1.0,
-                        principal_inertia: Vec3::new(0.1, 0.1, 0.1),
-                        ..default()
-                    }));
-            })
-            .insert(ImpulseJoint::new(
-                forearm_entities[i],
-                GenericJointBuilder::new(forearm_palm_joint_mask)
-                    .local_axis1(Vec3::Y)
-                    .local_axis2(Vec3::Y)
-                    .local_anchor1(Vec3::new(0., -forearm_size.hh, 0.))
-                    .local_anchor2(Vec3::new(0., palm_size.hh, 0.))
-                    .build(),
-            ));
-    }
+/// Walks the skeleton tree from the root, spawning every part at its rest pose
+/// and jointing it to its parent.
+pub fn spawn_humanoid(
+    commands: &mut Commands,
+    skeleton: &SkeletonDesc,
+    transform: Transform,
+    joint_mode: JointMode,
+    groups: CollisionGroups,
+) -> Entity {
+    let root = SpawnedPart {
+        desc: skeleton.root(),
+        id: spawn_part(commands, skeleton.root(), transform, 0, groups),
+        transform,
+        side: 0,
+    };
+    let mut spawned = SpawnedParts {
+        parts: vec![root.id],
+        joints: vec![],
+    };
+    spawn_children(commands, skeleton, &root, joint_mode, groups, &mut spawned);
+    commands.entity(root.id).insert(Humanoid {
+        parts: spawned.parts,
+        joints: spawned.joints,
+    });
+    return root.id;
+}
 
-    return body_id;
+fn spawn_children(
+    commands: &mut Commands,
+    skeleton: &SkeletonDesc,
+    parent: &SpawnedPart,
+    joint_mode: JointMode,
+    groups: CollisionGroups,
+    spawned: &mut SpawnedParts,
+) {
+    for part in skeleton.children(&parent.desc.name) {
+        let joint = part
+            .joint
+            .as_ref()
+            .unwrap_or_else(|| panic!("part {} has a parent but no joint", part.name));
+        let sides = match (parent.desc.mirror, part.mirror) {
+            (false, true) => 0..2,
+            _ => parent.side..parent.side + 1,
+        };
+        for i in sides {
+            let offset =
+                get_mirrored_anchor(&joint.anchor1, i) - get_mirrored_anchor(&joint.anchor2, i);
+            let transform = Transform {
+                translation: parent.transform.translation + parent.transform.rotation * offset,
+                ..parent.transform
+            };
+            let id = spawn_part(commands, part, transform, i, groups);
+            let data = get_joint(joint, i);
+            let mut entity_commands = commands.entity(id);
+            match joint_mode {
+                JointMode::Impulse => entity_commands.insert(ImpulseJoint::new(parent.id, data)),
+                JointMode::Multibody => {
+                    entity_commands.insert(MultibodyJoint::new(parent.id, data))
+                }
+            };
+            entity_commands
+                .insert(JointMotors::new(joint, i))
+                .insert(JointFrame::new(parent.id, joint, i));
+            spawned.parts.push(id);
+            if !joint.axes.is_empty() {
+                spawned.joints.push(id);
+            }
+            let child = SpawnedPart {
+                desc: part,
+                id,
+                transform,
+                side: i,
+            };
+            spawn_children(commands, skeleton, &child, joint_mode, groups, spawned);
+        }
+    }
 }
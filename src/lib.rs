@@ -0,0 +1,46 @@
+//! Humanoid locomotion environment on bevy and rapier, with dfdx agents training in it.
+//! `HumanoidEnv` steps the simulation from Rust code, the binary trains and renders it.
+
+pub mod action;
+#[cfg(feature = "train")]
+pub mod agent;
+#[cfg(feature = "render")]
+pub mod camera;
+#[cfg(feature = "train")]
+pub mod checkpoint;
+pub mod cli;
+#[cfg(feature = "db")]
+pub mod db;
+#[cfg(feature = "db")]
+pub mod db_client;
+#[cfg(feature = "train")]
+pub mod dqn;
+pub mod env;
+#[cfg(all(feature = "train", feature = "render"))]
+pub mod eval;
+pub mod ground;
+pub mod humanoid;
+#[cfg(feature = "render")]
+pub mod light;
+pub mod mjcf;
+#[cfg(feature = "train")]
+pub mod nets;
+pub mod observation;
+#[cfg(feature = "train")]
+pub mod optim;
+#[cfg(feature = "train")]
+pub mod ppo;
+#[cfg(feature = "train")]
+pub mod replay;
+pub mod reward;
+#[cfg(feature = "train")]
+pub mod sac;
+pub mod skeleton;
+#[cfg(feature = "train")]
+pub mod td3;
+pub mod termination;
+#[cfg(test)]
+mod test_util;
+pub mod urdf;
+#[cfg(feature = "render")]
+pub mod visuals;
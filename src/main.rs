@@ -1,78 +1,223 @@
-mod camera;
-mod db;
-mod db_client;
-mod ground;
-mod humanoid;
-mod light;
-use bevy::{diagnostic::FrameTimeDiagnosticsPlugin, prelude::*};
-use bevy_atmosphere::prelude::*;
-use bevy_framepace::{FramepacePlugin, FramepaceSettings, Limiter};
-use bevy_prototype_debug_lines::DebugLinesPlugin;
+use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
-use camera::*;
-use db_client::DbClientResource;
-use ground::*;
-use humanoid::*;
-use light::*;
+#[cfg(feature = "db")]
+use bevy_rapier_dfdx_humanoid::db_client;
+#[cfg(all(feature = "train", feature = "render"))]
+use bevy_rapier_dfdx_humanoid::eval;
+use bevy_rapier_dfdx_humanoid::{
+    action::ActionConfig,
+    cli::Args,
+    env::*,
+    ground::*,
+    humanoid::*,
+    observation::{ObservationConfig, Orientation},
+    reward::RewardConfig,
+    skeleton::SkeletonDesc,
+    termination::episode_log_system,
+};
+#[cfg(feature = "train")]
+use bevy_rapier_dfdx_humanoid::{agent, checkpoint, dqn, ppo, sac, td3};
 
-fn rapier_config_start_system(mut c: ResMut<RapierContext>) {
-    c.integration_parameters.max_velocity_iterations = 128;
-    c.integration_parameters.max_stabilization_iterations = 128;
-    dbg!(c.integration_parameters);
-}
-
-const FPS: f32 = 60.;
 fn main() {
-    App::new()
-        .insert_resource(RapierConfiguration {
-            timestep_mode: TimestepMode::Fixed {
-                dt: 1. / FPS,
-                substeps: 10,
-            },
-            ..default()
-        })
-        .insert_resource(FramepaceSettings {
-            limiter: Limiter::from_framerate(FPS as f64),
-            ..default()
-        })
-        .insert_resource(DbClientResource::default())
-        // .insert_resource(DqnResource::default())
-        .insert_resource(WindowDescriptor {
-            title: "humanoid deep learning".to_string(),
-            width: 1024.,
-            height: 768.,
-            ..default()
-        })
-        .insert_resource(Msaa { samples: 4 })
-        .insert_resource(CameraConfig::default())
-        .insert_resource(AtmosphereSettings { resolution: 1024 })
-        .add_plugins(DefaultPlugins)
-        .add_plugin(AtmospherePlugin)
-        .add_plugin(FramepacePlugin)
-        .add_startup_system(ground_start_system)
-        .add_startup_system(camera_start_system)
-        .add_startup_system(light_start_system)
-        .add_startup_system(humanoid_start_system)
-        .add_system(camera_controller_system)
-        .add_system(camera_switch_system)
-        .add_plugin(FrameTimeDiagnosticsPlugin::default())
-        .add_plugin(RapierPhysicsPlugin::<NoUserData>::default())
-        .add_plugin(RapierDebugRenderPlugin {
-            style: DebugRenderStyle {
-                rigid_body_axes_length: 0.5,
-                subdivisions: 50,
-                ..default()
-            },
-            // | DebugRenderMode::COLLIDER_AABBS
-            mode: DebugRenderMode::COLLIDER_SHAPES
-                | DebugRenderMode::RIGID_BODY_AXES
-                | DebugRenderMode::JOINTS
-                | DebugRenderMode::CONTACTS
-                | DebugRenderMode::SOLVER_CONTACTS,
+    let args = Args::parse();
+    if args.runs || args.delete_run.is_some() {
+        #[cfg(feature = "db")]
+        {
+            let db = db_client::DbClientResource::default();
+            if let Some(run) = &args.delete_run {
+                db.delete_run(run);
+            }
+            if args.runs {
+                db.print_runs();
+            }
+        }
+        #[cfg(not(feature = "db"))]
+        eprintln!("built without the db feature, --runs and --delete-run need it");
+        return;
+    }
+    let humanoid_config = HumanoidConfig {
+        joint_mode: args.joint_mode,
+        self_collision: args.self_collision,
+    };
+    let env_config = EnvConfig {
+        num_envs: args.num_envs,
+        ..default()
+    };
+    if let Err(e) = env_config.validate(&humanoid_config) {
+        eprintln!("--envs={}: {}", args.num_envs, e);
+        std::process::exit(1);
+    }
+    let reward_config = match &args.reward_path {
+        Some(path) => RewardConfig::load(path).unwrap(),
+        None => RewardConfig::default(),
+    };
+    let mut app = App::new();
+    app.insert_resource(get_rapier_configuration())
+        .insert_resource(SkeletonDesc::load(&args.skeleton_path).unwrap())
+        .insert_resource(humanoid_config)
+        .insert_resource(reward_config)
+        .insert_resource(ObservationConfig {
+            orientation: args.orientation,
             ..default()
         })
-        .add_plugin(DebugLinesPlugin::with_depth_test(true))
-        // .add_startup_system(dqn_exclusive_start_system.exclusive_system())
+        .insert_resource(env_config);
+    if let Some(mode) = args.action {
+        app.insert_resource(ActionConfig { mode });
+    }
+    #[cfg(feature = "db")]
+    app.insert_resource(db_client::DbClientResource::default());
+    #[cfg(feature = "render")]
+    match args.headless {
+        true => add_headless_plugins(&mut app),
+        false => add_render_plugins(&mut app),
+    };
+    #[cfg(not(feature = "render"))]
+    {
+        if !args.headless {
+            eprintln!("built without the render feature, running headless");
+        }
+        add_headless_plugins(&mut app);
+    }
+    #[cfg(feature = "train")]
+    {
+        let mut checkpoint = checkpoint::CheckpointConfig::default();
+        if let Some(dir) = &args.checkpoint_dir {
+            checkpoint.dir = dir.into();
+        }
+        if let Some(every) = args.checkpoint_every {
+            checkpoint.every = every;
+        }
+        checkpoint.resume = args.resume.as_ref().map(Into::into);
+        app.insert_resource(checkpoint);
+    }
+    #[cfg(feature = "train")]
+    {
+        let torque = args.torque();
+        match args.agent.as_deref() {
+            Some("dqn") => add_agent(&mut app, dqn::DqnAgent::new(default()), &args),
+            Some("ppo") => {
+                let config = ppo::PpoConfig {
+                    torque,
+                    ..default()
+                };
+                add_agent(&mut app, ppo::PpoAgent::new(config), &args)
+            }
+            Some("sac") => {
+                let config = sac::SacConfig {
+                    torque,
+                    ..default()
+                };
+                add_agent(&mut app, sac::SacAgent::new(config), &args)
+            }
+            Some("td3") => {
+                let config = td3::Td3Config {
+                    torque,
+                    ..default()
+                };
+                add_agent(&mut app, td3::Td3Agent::new(config), &args)
+            }
+            Some("ddpg") => {
+                let config = td3::Td3Config {
+                    torque,
+                    ..td3::Td3Config::ddpg()
+                };
+                add_agent(&mut app, td3::Td3Agent::new(config), &args)
+            }
+            Some(other) => panic!("unknown agent {}", other),
+            None => {}
+        }
+    }
+    app.add_startup_system(ground_start_system)
+        .add_startup_system(env_start_system)
         .add_startup_system(rapier_config_start_system)
         .run();
 }
+
+/// Trains the agent, or watches a checkpoint of it with `--eval`.
+#[cfg(feature = "train")]
+fn add_agent<A: agent::Agent>(app: &mut App, agent: A, args: &Args) {
+    if args.orientation != Orientation::Gravity {
+        eprintln!("agents are sized for --orientation=gravity observations");
+        std::process::exit(1);
+    }
+    if let Some(mode) = args.action {
+        if mode != agent.action_mode() {
+            eprintln!("--agent={} can't act with {:?}", agent.name(), mode);
+            std::process::exit(1);
+        }
+    }
+    #[cfg(feature = "render")]
+    if let Some(path) = &args.eval {
+        match eval::EvalConfig::new(path.as_ref()) {
+            Ok(config) => eval::add_eval_agent(app, agent, config),
+            Err(e) => {
+                eprintln!("--eval: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+    #[cfg(not(feature = "render"))]
+    if args.eval.is_some() {
+        eprintln!("built without the render feature, --eval needs it");
+        std::process::exit(1);
+    }
+    agent::add_agent(app, agent);
+}
+
+fn add_headless_plugins(app: &mut App) {
+    app.add_plugins(HeadlessPlugins)
+        .add_plugin(RapierPhysicsPlugin::<NoUserData>::default())
+        .add_plugin(HumanoidEnvPlugin)
+        .add_system(episode_log_system);
+}
+
+#[cfg(feature = "render")]
+fn add_render_plugins(app: &mut App) {
+    use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
+    use bevy_atmosphere::prelude::*;
+    use bevy_framepace::{FramepacePlugin, FramepaceSettings, Limiter};
+    use bevy_prototype_debug_lines::DebugLinesPlugin;
+    use bevy_rapier_dfdx_humanoid::{camera::*, light::*, visuals::*};
+
+    app.insert_resource(FramepaceSettings {
+        limiter: Limiter::from_framerate(FPS as f64),
+        ..default()
+    })
+    .insert_resource(WindowDescriptor {
+        title: "humanoid deep learning".to_string(),
+        width: 1024.,
+        height: 768.,
+        ..default()
+    })
+    .insert_resource(Msaa { samples: 4 })
+    .insert_resource(CameraConfig::default())
+    .insert_resource(AtmosphereSettings { resolution: 1024 })
+    .add_plugins(DefaultPlugins)
+    .add_plugin(AtmospherePlugin)
+    .add_plugin(FramepacePlugin)
+    .add_startup_system(camera_start_system)
+    .add_startup_system(light_start_system)
+    .add_system(camera_controller_system)
+    .add_system(camera_switch_system)
+    .add_system(ground_visuals_system)
+    .add_system(humanoid_visuals_system)
+    .add_plugin(FrameTimeDiagnosticsPlugin::default())
+    .add_plugin(RapierPhysicsPlugin::<NoUserData>::default())
+    .add_plugin(HumanoidEnvPlugin)
+    .add_plugin(RapierDebugRenderPlugin {
+        style: DebugRenderStyle {
+            rigid_body_axes_length: 0.5,
+            subdivisions: 50,
+            ..default()
+        },
+        // | DebugRenderMode::COLLIDER_AABBS
+        mode: DebugRenderMode::COLLIDER_SHAPES
+            | DebugRenderMode::RIGID_BODY_AXES
+            | DebugRenderMode::JOINTS
+            | DebugRenderMode::CONTACTS
+            | DebugRenderMode::SOLVER_CONTACTS,
+        ..default()
+    })
+    .add_plugin(DebugLinesPlugin::with_depth_test(true));
+}
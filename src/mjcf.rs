@@ -0,0 +1,447 @@
+//! Importer for the subset of MuJoCo MJCF used by locomotion benchmarks such as `humanoid.xml`:
+//! nested bodies, capsule/box/sphere geoms, hinge and ball joints with ranges,
+//! and motor actuators with gear. Body orientations are not supported, the rest pose
+//! keeps every part aligned with the root. The root body `pos` becomes the spawn position,
+//! the root is always free whether or not it has a free joint.
+use bevy::prelude::*;
+use bevy_rapier3d::rapier::prelude::JointAxis;
+use roxmltree::{Document, Node};
+use std::{collections::HashMap, error::Error};
+
+use crate::{
+    humanoid::BodySize,
+    skeleton::{AxisDesc, GeomDesc, JointDesc, PartDesc, ShapeDesc, SkeletonDesc},
+};
+
+const DEFAULT_DENSITY: f32 = 1000.;
+const DEFAULT_RGBA: [f32; 4] = [0.8, 0.6, 0.4, 1.];
+
+/// Top level `<default>` elements, default classes are not supported.
+#[derive(Default)]
+struct Defaults<'a, 'input> {
+    joint: Option<Node<'a, 'input>>,
+    geom: Option<Node<'a, 'input>>,
+    motor: Option<Node<'a, 'input>>,
+}
+
+struct Context<'a, 'input> {
+    degrees: bool,
+    defaults: Defaults<'a, 'input>,
+    /// Joint name to (gear, max |ctrl|) of the motor driving it.
+    actuators: HashMap<String, (f32, f32)>,
+}
+
+pub fn load(path: &str) -> Result<SkeletonDesc, Box<dyn Error>> {
+    let xml = std::fs::read_to_string(path)?;
+    parse(&xml)
+}
+
+pub fn parse(xml: &str) -> Result<SkeletonDesc, Box<dyn Error>> {
+    let doc = Document::parse(xml)?;
+    let mujoco = doc.root_element();
+    let degrees = child(mujoco, "compiler")
+        .and_then(|c| c.attribute("angle"))
+        .map_or(true, |angle| angle == "degree");
+    let defaults = match child(mujoco, "default") {
+        Some(d) => Defaults {
+            joint: child(d, "joint"),
+            geom: child(d, "geom"),
+            motor: child(d, "motor"),
+        },
+        None => Defaults::default(),
+    };
+    let mut ctx = Context {
+        degrees,
+        defaults,
+        actuators: HashMap::new(),
+    };
+    if let Some(actuator) = child(mujoco, "actuator") {
+        for motor in actuator.children().filter(|n| n.has_tag_name("motor")) {
+            let joint = match motor.attribute("joint") {
+                Some(joint) => joint.to_string(),
+                None => continue,
+            };
+            let gear = floats(attr(motor, ctx.defaults.motor, "gear"))
+                .first()
+                .copied()
+                .unwrap_or(1.);
+            let ctrl = floats(attr(motor, ctx.defaults.motor, "ctrlrange"))
+                .iter()
+                .fold(0_f32, |max, c| max.max(c.abs()));
+            let ctrl = if ctrl > 0. { ctrl } else { 1. };
+            ctx.actuators.insert(joint, (gear, ctrl));
+        }
+    }
+    let worldbody = child(mujoco, "worldbody").ok_or("MJCF has no worldbody")?;
+    let body = child(worldbody, "body").ok_or("MJCF worldbody has no body")?;
+    let free = child(body, "freejoint").is_some()
+        || body.children().any(|j| {
+            j.has_tag_name("joint") && attr(j, ctx.defaults.joint, "type") == Some("free")
+        });
+    if !free {
+        eprintln!("MJCF root body has no free joint, it is simulated free anyway");
+    }
+    let mut parts: Vec<PartDesc> = vec![];
+    parse_body(body, None, &ctx, &mut parts)?;
+    Ok(SkeletonDesc {
+        parts,
+        root_position: vec3(body.attribute("pos")).map(|p| p.to_array()),
+    })
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, tag: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|n| n.has_tag_name(tag))
+}
+
+fn attr<'a>(node: Node<'a, '_>, default: Option<Node<'a, '_>>, name: &str) -> Option<&'a str> {
+    node.attribute(name)
+        .or_else(|| default.and_then(|d| d.attribute(name)))
+}
+
+fn floats(value: Option<&str>) -> Vec<f32> {
+    value
+        .map(|v| v.split_whitespace().filter_map(|f| f.parse().ok()).collect())
+        .unwrap_or_default()
+}
+
+/// MJCF is Z-up, bevy is Y-up.
+fn to_bevy(v: Vec3) -> Vec3 {
+    Vec3::new(v.x, v.z, -v.y)
+}
+
+fn vec3(value: Option<&str>) -> Option<Vec3> {
+    match floats(value)[..] {
+        [x, y, z, ..] => Some(to_bevy(Vec3::new(x, y, z))),
+        _ => None,
+    }
+}
+
+fn parse_body(
+    body: Node,
+    parent: Option<&str>,
+    ctx: &Context,
+    parts: &mut Vec<PartDesc>,
+) -> Result<(), Box<dyn Error>> {
+    let name = body
+        .attribute("name")
+        .map(String::from)
+        .unwrap_or_else(|| format!("body{}", parts.len()));
+    let geom_nodes: Vec<Node> = body.children().filter(|n| n.has_tag_name("geom")).collect();
+    let geoms: Vec<GeomDesc> = geom_nodes
+        .iter()
+        .filter_map(|g| parse_geom(*g, ctx))
+        .collect();
+    if geoms.is_empty() {
+        return Err(format!("body {} has no supported geom", name).into());
+    }
+    let color = geom_nodes
+        .first()
+        .and_then(|g| match floats(attr(*g, ctx.defaults.geom, "rgba"))[..] {
+            [r, g, b, a] => Some([r, g, b, a]),
+            _ => None,
+        })
+        .unwrap_or(DEFAULT_RGBA);
+    let density = geom_nodes
+        .first()
+        .and_then(|g| floats(attr(*g, ctx.defaults.geom, "density")).first().copied())
+        .unwrap_or(DEFAULT_DENSITY);
+    let joint = match parent {
+        Some(_) => Some(parse_joint(body, ctx)),
+        None => None,
+    };
+    parts.push(PartDesc {
+        name: name.clone(),
+        geoms,
+        mass: 0.,
+        inertia: [0.; 3],
+        density: Some(density),
+        color,
+        damping: None,
+        mirror: false,
+        parent: parent.map(String::from),
+        joint,
+    });
+    for child_body in body.children().filter(|n| n.has_tag_name("body")) {
+        parse_body(child_body, Some(&name), ctx, parts)?;
+    }
+    Ok(())
+}
+
+fn parse_geom(geom: Node, ctx: &Context) -> Option<GeomDesc> {
+    let default = ctx.defaults.geom;
+    let size = floats(attr(geom, default, "size"));
+    let mut translation = vec3(geom.attribute("pos")).unwrap_or(Vec3::ZERO);
+    let mut rotation = match floats(geom.attribute("quat"))[..] {
+        [w, x, y, z] => {
+            let z_up_to_y_up = Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2);
+            z_up_to_y_up * Quat::from_xyzw(x, y, z, w).normalize() * z_up_to_y_up.inverse()
+        }
+        _ => Quat::IDENTITY,
+    };
+    let shape = match attr(geom, default, "type").unwrap_or("sphere") {
+        "sphere" => ShapeDesc::Sphere {
+            radius: *size.first()?,
+        },
+        "capsule" => {
+            let radius = *size.first()?;
+            match floats(geom.attribute("fromto"))[..] {
+                [x1, y1, z1, x2, y2, z2] => {
+                    let from = to_bevy(Vec3::new(x1, y1, z1));
+                    let to = to_bevy(Vec3::new(x2, y2, z2));
+                    translation = (from + to) / 2.;
+                    rotation = Quat::from_rotation_arc(Vec3::Y, (to - from).normalize());
+                    ShapeDesc::Capsule {
+                        half_height: (to - from).length() / 2.,
+                        radius,
+                    }
+                }
+                _ => ShapeDesc::Capsule {
+                    half_height: *size.get(1)?,
+                    radius,
+                },
+            }
+        }
+        "box" => match size[..] {
+            [x, y, z] => ShapeDesc::RoundBox(BodySize {
+                hw: x,
+                hh: z,
+                hl: y,
+                br: 0.,
+            }),
+            _ => return None,
+        },
+        other => {
+            eprintln!("MJCF geom type {} is not supported, skipped", other);
+            return None;
+        }
+    };
+    Some(GeomDesc {
+        shape,
+        translation: translation.to_array(),
+        rotation: rotation.to_array(),
+    })
+}
+
+/// All hinge and ball joints of a body are merged into a single joint to its parent.
+/// The first hinge axis becomes ANG_X, other hinges take the closest of ANG_Y and ANG_Z.
+fn parse_joint(body: Node, ctx: &Context) -> JointDesc {
+    let default = ctx.defaults.joint;
+    let joints: Vec<Node> = body
+        .children()
+        .filter(|n| n.has_tag_name("joint"))
+        .collect();
+    let body_pos = vec3(body.attribute("pos")).unwrap_or(Vec3::ZERO);
+    let joint_pos = joints
+        .first()
+        .and_then(|j| vec3(j.attribute("pos")))
+        .unwrap_or(Vec3::ZERO);
+    let hinge_axes: Vec<Vec3> = joints
+        .iter()
+        .filter(|j| attr(**j, default, "type").unwrap_or("hinge") == "hinge")
+        .map(|j| {
+            vec3(attr(*j, default, "axis"))
+                .unwrap_or(Vec3::Y)
+                .normalize()
+        })
+        .collect();
+    let axis = hinge_axes.first().copied().unwrap_or(Vec3::X);
+    let basis = Quat::from_rotation_arc(Vec3::X, axis);
+
+    let mut axes: Vec<AxisDesc> = vec![];
+    for joint in joints.iter() {
+        let name = joint.attribute("name").unwrap_or_default();
+        let range = floats(attr(*joint, default, "range"));
+        let limited = attr(*joint, default, "limited") != Some("false") && range.len() == 2;
+        let to_degrees = |a: f32| if ctx.degrees { a } else { a.to_degrees() };
+        let (stiffness, damping, max_force) = match ctx.actuators.get(name) {
+            Some((gear, ctrl)) => (gear * ctrl, 0.1 * gear * ctrl, gear * ctrl),
+            None => (
+                floats(attr(*joint, default, "stiffness"))
+                    .first()
+                    .copied()
+                    .unwrap_or(0.),
+                floats(attr(*joint, default, "damping"))
+                    .first()
+                    .copied()
+                    .unwrap_or(0.),
+                f32::MAX,
+            ),
+        };
+        match attr(*joint, default, "type").unwrap_or("hinge") {
+            "hinge" => {
+                let local = basis.inverse()
+                    * vec3(attr(*joint, default, "axis"))
+                        .unwrap_or(Vec3::Y)
+                        .normalize();
+                let (joint_axis, sign) = [JointAxis::AngX, JointAxis::AngY, JointAxis::AngZ]
+                    .into_iter()
+                    .zip(local.to_array())
+                    .max_by(|(_, a), (_, b)| a.abs().total_cmp(&b.abs()))
+                    .map(|(joint_axis, c)| (joint_axis, c.signum()))
+                    .unwrap();
+                if axes.iter().any(|a| a.axis == joint_axis) {
+                    eprintln!("MJCF joint {} duplicates an axis, skipped", name);
+                    continue;
+                }
+                let limits = limited.then(|| {
+                    let [min, max] = [to_degrees(range[0]), to_degrees(range[1])];
+                    if sign < 0. {
+                        [-max, -min]
+                    } else {
+                        [min, max]
+                    }
+                });
+                axes.push(AxisDesc {
+                    axis: joint_axis,
+                    limits,
+                    stiffness,
+                    damping,
+                    max_force,
+                });
+            }
+            "ball" => {
+                let limits = limited.then(|| {
+                    let max = to_degrees(range[1]);
+                    [-max, max]
+                });
+                for joint_axis in [JointAxis::AngX, JointAxis::AngY, JointAxis::AngZ] {
+                    if axes.iter().any(|a| a.axis == joint_axis) {
+                        eprintln!(
+                            "MJCF joint {} duplicates axis {:?}, skipped",
+                            name, joint_axis
+                        );
+                        continue;
+                    }
+                    axes.push(AxisDesc {
+                        axis: joint_axis,
+                        limits,
+                        stiffness,
+                        damping,
+                        max_force,
+                    });
+                }
+            }
+            other => eprintln!("MJCF joint type {} is not supported, skipped", other),
+        }
+    }
+    JointDesc {
+        anchor1: (body_pos + joint_pos).to_array(),
+        anchor2: joint_pos.to_array(),
+        axis: axis.to_array(),
+        axes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    const MODEL: &str = r#"
+<mujoco>
+  <compiler angle="radian"/>
+  <default>
+    <geom density="500"/>
+  </default>
+  <worldbody>
+    <body name="torso" pos="0 0 1.4">
+      <freejoint/>
+      <geom type="capsule" fromto="0 -0.07 0 0 0.07 0" size="0.07"/>
+      <body name="thigh" pos="0 0 -0.2">
+        <joint name="hip_x" type="hinge" axis="1 0 0" range="-0.5 0.5"/>
+        <joint name="hip_y" type="hinge" axis="0 1 0" range="-1 0.3"/>
+        <geom type="sphere" size="0.05"/>
+        <geom type="cylinder" size="0.05 0.1"/>
+      </body>
+    </body>
+  </worldbody>
+  <actuator>
+    <motor joint="hip_x" gear="100" ctrlrange="-0.4 0.4"/>
+  </actuator>
+</mujoco>"#;
+
+    #[test]
+    fn parses_bodies_into_parts() {
+        let skeleton = parse(MODEL).unwrap();
+        let names: Vec<&str> = skeleton.parts.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["torso", "thigh"]);
+        assert_eq!(skeleton.root().name, "torso");
+        assert_eq!(skeleton.parts[1].parent.as_deref(), Some("torso"));
+        assert_eq!(skeleton.parts[0].density, Some(500.));
+
+        // Z-up to Y-up
+        let [x, y, z] = skeleton.root_position.unwrap();
+        assert_close(x, 0.);
+        assert_close(y, 1.4);
+        assert_close(z, 0.);
+
+        match skeleton.parts[0].geoms[0].shape {
+            ShapeDesc::Capsule {
+                half_height,
+                radius,
+            } => {
+                assert_close(half_height, 0.07);
+                assert_close(radius, 0.07);
+            }
+            ref other => panic!("expected a capsule, got {:?}", other),
+        }
+        // the cylinder is not supported
+        assert_eq!(skeleton.parts[1].geoms.len(), 1);
+    }
+
+    #[test]
+    fn merges_hinges_into_one_joint() {
+        let skeleton = parse(MODEL).unwrap();
+        let joint = skeleton.parts[1].joint.as_ref().unwrap();
+        assert_close(joint.anchor1[1], -0.2);
+        assert_eq!(joint.axes.len(), 2);
+
+        let hip_x = &joint.axes[0];
+        assert_eq!(hip_x.axis, JointAxis::AngX);
+        let [min, max] = hip_x.limits.unwrap();
+        assert_close(min, -0.5_f32.to_degrees());
+        assert_close(max, 0.5_f32.to_degrees());
+        assert_close(hip_x.max_force, 40.);
+
+        // MJCF Y is bevy -Z, the range flips with the axis
+        let hip_y = &joint.axes[1];
+        assert_eq!(hip_y.axis, JointAxis::AngZ);
+        let [min, max] = hip_y.limits.unwrap();
+        assert_close(min, -0.3_f32.to_degrees());
+        assert_close(max, 1_f32.to_degrees());
+        assert_eq!(hip_y.max_force, f32::MAX);
+    }
+
+    #[test]
+    fn ball_joints_skip_the_axes_of_hinges() {
+        let model = r#"
+<mujoco>
+  <worldbody>
+    <body name="torso">
+      <geom type="sphere" size="0.1"/>
+      <body name="arm" pos="0 0 -0.3">
+        <joint name="twist" type="hinge" axis="1 0 0" range="-30 30"/>
+        <joint name="swing" type="ball" range="0 60"/>
+        <geom type="sphere" size="0.05"/>
+      </body>
+    </body>
+  </worldbody>
+</mujoco>"#;
+        let skeleton = parse(model).unwrap();
+        let joint = skeleton.parts[1].joint.as_ref().unwrap();
+        let axes: Vec<JointAxis> = joint.axes.iter().map(|a| a.axis).collect();
+        assert_eq!(axes, [JointAxis::AngX, JointAxis::AngY, JointAxis::AngZ]);
+        // the hinge keeps its range, the ball fills the other axes
+        assert_eq!(joint.axes[0].limits, Some([-30., 30.]));
+        assert_eq!(joint.axes[1].limits, Some([-60., 60.]));
+        assert_eq!(joint.axes[2].limits, Some([-60., 60.]));
+    }
+
+    #[test]
+    fn rejects_models_without_bodies_or_geoms() {
+        assert!(parse("<mujoco/>").is_err());
+        assert!(parse("<mujoco><worldbody/></mujoco>").is_err());
+        let no_geom = r#"<mujoco><worldbody><body name="a"/></worldbody></mujoco>"#;
+        assert!(parse(no_geom).is_err());
+    }
+}
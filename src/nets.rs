@@ -0,0 +1,180 @@
+use dfdx::prelude::*;
+use rand::Rng;
+
+use crate::observation::HUMANOID_DOF;
+
+pub const HIDDEN: usize = 256;
+/// Continuous action size of the humanoid in `assets/humanoid.json`, one per free joint axis.
+pub const HUMANOID_ACT: usize = HUMANOID_DOF;
+pub const LOG_STD_MIN: f32 = -5.;
+pub const LOG_STD_MAX: f32 = 2.;
+
+/// Two hidden layer ReLU perceptron, the building block of every agent.
+pub type Mlp<const I: usize, const O: usize> = (
+    (Linear<I, HIDDEN>, ReLU),
+    (Linear<HIDDEN, HIDDEN>, ReLU),
+    Linear<HIDDEN, O>,
+);
+
+/// Diagonal Gaussian policy: an MLP for the mean and a linear layer for the log-std.
+pub type GaussianActor<const I: usize, const A: usize> = (Mlp<I, A>, Linear<I, A>);
+
+/// Mean and log-std of a batch, the log-std clamped to [`LOG_STD_MIN`, `LOG_STD_MAX`].
+/// Only the log-std carries the tape, the mean was recorded on it.
+pub fn gaussian_forward<const B: usize, const I: usize, const A: usize>(
+    actor: &GaussianActor<I, A>,
+    observations: Tensor2D<B, I, OwnedTape>,
+) -> (Tensor2D<B, A>, Tensor2D<B, A, OwnedTape>) {
+    let (observations, tape) = observations.split_tape();
+    let (mean, tape) = actor
+        .0
+        .forward(observations.duplicate().put_tape(tape))
+        .split_tape();
+    let log_std = clamp(
+        actor.1.forward(observations.put_tape(tape)),
+        LOG_STD_MIN,
+        LOG_STD_MAX,
+    );
+    (mean, log_std)
+}
+
+/// Log-density of `actions` under the Gaussians of `gaussian_forward`, summed over actions:
+/// `-((a - mean) / std)^2 / 2 - log_std - ln(2 pi) / 2`.
+pub fn gaussian_log_prob<const B: usize, const A: usize>(
+    mean: Tensor2D<B, A>,
+    log_std: Tensor2D<B, A, OwnedTape>,
+    actions: &Tensor2D<B, A>,
+) -> Tensor1D<B, OwnedTape> {
+    let (log_std, tape) = log_std.split_tape();
+    let (inv_std, tape) = exp(negate(log_std.duplicate().put_tape(tape))).split_tape();
+    let (diff, tape) = sub(mean.put_tape(tape), actions).split_tape();
+    let z = mul(diff.put_tape(tape), &inv_std);
+    let log_prob = add(mul_scalar(square(z), 0.5), &log_std);
+    let log_prob = add_scalar(log_prob, 0.5 * (2. * std::f32::consts::PI).ln());
+    negate(log_prob.sum_axis::<-1>())
+}
+
+/// Log-density of one action in plain floats, for acting without a tape.
+pub fn gaussian_log_prob_single(mean: &[f32], log_std: &[f32], action: &[f32]) -> f32 {
+    mean.iter()
+        .zip(log_std)
+        .zip(action)
+        .map(|((m, s), a)| {
+            let z = (a - m) / s.exp();
+            -0.5 * z * z - s - 0.5 * (2. * std::f32::consts::PI).ln()
+        })
+        .sum()
+}
+
+/// Standard normal sample, Box-Muller.
+pub fn standard_normal<R: Rng>(rng: &mut R) -> f32 {
+    let u1: f32 = rng.gen_range(f32::EPSILON..1.);
+    let u2: f32 = rng.gen();
+    (-2. * u1.ln()).sqrt() * (2. * std::f32::consts::PI * u2).cos()
+}
+
+pub fn argmax(values: &[f32]) -> usize {
+    values
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(0, |(i, _)| i)
+}
+
+/// Q-network of an observation and an action: both embedded, summed, then an MLP.
+/// Kept a tuple of modules so it updates and resets like any other network.
+pub type QCritic<const I: usize, const A: usize> = (
+    Linear<I, HIDDEN>,
+    Linear<A, HIDDEN>,
+    (ReLU, (Linear<HIDDEN, HIDDEN>, ReLU), Linear<HIDDEN, 1>),
+);
+
+/// Q-values of a batch, `[B]`. The tape of `actions` carries through, so traced actions
+/// train the critic and actions of a traced actor train the actor.
+pub fn q_forward<const B: usize, const I: usize, const A: usize, T: Tape>(
+    critic: &QCritic<I, A>,
+    observations: Tensor2D<B, I>,
+    actions: Tensor2D<B, A, T>,
+) -> Tensor1D<B, T> {
+    let (actions, tape) = actions.split_tape();
+    let (embedding, tape) = critic.0.forward(observations.put_tape(tape)).split_tape();
+    let hidden = add(critic.1.forward(actions.put_tape(tape)), &embedding);
+    let q: Tensor2D<B, 1, T> = critic.2.forward(hidden);
+    q.sum_axis::<-1>()
+}
+
+/// Reparameterized tanh-squashed Gaussian sample `tanh(mean + std * noise)` of a batch and
+/// its log-density, with the tanh change of variables. Only the log-density carries the tape.
+pub fn squashed_sample<const B: usize, const A: usize>(
+    mean: Tensor2D<B, A>,
+    log_std: Tensor2D<B, A, OwnedTape>,
+    noise: [[f32; A]; B],
+) -> (Tensor2D<B, A>, Tensor1D<B, OwnedTape>) {
+    let mut gaussian = [0.; B];
+    for (g, row) in gaussian.iter_mut().zip(noise.iter()) {
+        *g = row
+            .iter()
+            .map(|n| -0.5 * n * n - 0.5 * (2. * std::f32::consts::PI).ln())
+            .sum();
+    }
+    let (log_std, tape) = log_std.split_tape();
+    let std = exp(log_std.duplicate().put_tape(tape));
+    let (u, tape) = add(mul(std, &Tensor2D::new(noise)), &mean).split_tape();
+    let (actions, tape) = tanh(u.put_tape(tape)).split_tape();
+    // ln(1 - tanh(u)^2)
+    let (correction, tape) = ln(add_scalar(
+        negate(square(actions.duplicate().put_tape(tape))),
+        1. + 1e-6,
+    ))
+    .split_tape();
+    let log_prob = negate(add(log_std.put_tape(tape), &correction).sum_axis::<-1>());
+    (actions, add(log_prob, &Tensor1D::new(gaussian)))
+}
+
+/// `tanh(mean + std * noise)` and its log-density for one observation, without a tape.
+pub fn squashed_sample_single(mean: &[f32], log_std: &[f32], noise: &[f32]) -> (Vec<f32>, f32) {
+    let mut log_prob = 0.;
+    let mut action = vec![];
+    for ((m, s), n) in mean.iter().zip(log_std).zip(noise) {
+        let a = (m + s.exp() * n).tanh();
+        log_prob += -0.5 * n * n - s - 0.5 * (2. * std::f32::consts::PI).ln();
+        log_prob -= (1. - a * a + 1e-6).ln();
+        action.push(a);
+    }
+    (action, log_prob)
+}
+
+/// Polyak averaging of target networks, `target = tau * source + (1 - tau) * target`.
+pub trait SoftUpdate {
+    fn soft_update(&mut self, source: &Self, tau: f32);
+}
+impl<const I: usize, const O: usize> SoftUpdate for Linear<I, O> {
+    fn soft_update(&mut self, source: &Self, tau: f32) {
+        let weights = self.weight.mut_data().iter_mut().flatten();
+        for (t, s) in weights.zip(source.weight.data().iter().flatten()) {
+            *t = tau * s + (1. - tau) * *t;
+        }
+        for (t, s) in self.bias.mut_data().iter_mut().zip(source.bias.data()) {
+            *t = tau * s + (1. - tau) * *t;
+        }
+    }
+}
+impl SoftUpdate for ReLU {
+    fn soft_update(&mut self, _: &Self, _: f32) {}
+}
+impl SoftUpdate for Tanh {
+    fn soft_update(&mut self, _: &Self, _: f32) {}
+}
+impl<A: SoftUpdate, B: SoftUpdate> SoftUpdate for (A, B) {
+    fn soft_update(&mut self, source: &Self, tau: f32) {
+        self.0.soft_update(&source.0, tau);
+        self.1.soft_update(&source.1, tau);
+    }
+}
+impl<A: SoftUpdate, B: SoftUpdate, C: SoftUpdate> SoftUpdate for (A, B, C) {
+    fn soft_update(&mut self, source: &Self, tau: f32) {
+        self.0.soft_update(&source.0, tau);
+        self.1.soft_update(&source.1, tau);
+        self.2.soft_update(&source.2, tau);
+    }
+}
@@ -0,0 +1,231 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+#[cfg(feature = "train")]
+use dfdx::prelude::*;
+
+use crate::{
+    env::{EnvStep, Observation},
+    humanoid::{Humanoid, JointFrame, JointMotors},
+};
+
+/// Free joint axes of the humanoid in `assets/humanoid.json`.
+pub const HUMANOID_DOF: usize = 27;
+/// Observation size of the humanoid in `assets/humanoid.json` with the default config.
+pub const HUMANOID_OBS: usize = 1 + 3 + 3 + 3 + 2 * HUMANOID_DOF + 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// Unit gravity direction in the torso frame, 3 values.
+    Gravity,
+    /// Torso rotation quaternion [x, y, z, w], 4 values.
+    Quaternion,
+}
+
+/// Observation layout, in order:
+/// - torso height, 1
+/// - torso orientation, 3 or 4, see `Orientation`
+/// - torso linear velocity in the torso frame, 3
+/// - torso angular velocity in the torso frame, 3
+/// - joint positions in `Humanoid::joints` and `JointMotors` order, one per free axis
+/// - joint velocities in the same order
+/// - contact flags of `contact_parts` in `Humanoid::parts` order, 1 when touching anything
+pub struct ObservationConfig {
+    pub orientation: Orientation,
+    pub contact_parts: Vec<String>,
+    /// Normalize with running mean and std, see `ObservationNormalizer`.
+    pub normalize: bool,
+}
+impl Default for ObservationConfig {
+    fn default() -> Self {
+        Self {
+            orientation: Orientation::Gravity,
+            contact_parts: vec!["foot".to_string()],
+            normalize: false,
+        }
+    }
+}
+
+/// Running mean and variance (Welford), shared by all humanoids.
+#[derive(Default)]
+pub struct ObservationNormalizer {
+    pub count: f32,
+    pub mean: Vec<f32>,
+    pub m2: Vec<f32>,
+}
+impl ObservationNormalizer {
+    pub const CLIP: f32 = 5.;
+
+    pub fn update(&mut self, observation: &[f32]) {
+        if self.mean.len() != observation.len() {
+            self.count = 0.;
+            self.mean = vec![0.; observation.len()];
+            self.m2 = vec![0.; observation.len()];
+        }
+        self.count += 1.;
+        for (i, x) in observation.iter().enumerate() {
+            let delta = x - self.mean[i];
+            self.mean[i] += delta / self.count;
+            self.m2[i] += delta * (x - self.mean[i]);
+        }
+    }
+
+    pub fn normalize(&self, observation: &[f32]) -> Vec<f32> {
+        if self.count < 2. || self.mean.len() != observation.len() {
+            return observation.to_vec();
+        }
+        observation
+            .iter()
+            .enumerate()
+            .map(|(i, x)| {
+                let std = (self.m2[i] / self.count).sqrt().max(1e-6);
+                ((x - self.mean[i]) / std).clamp(-Self::CLIP, Self::CLIP)
+            })
+            .collect()
+    }
+}
+
+/// Little-endian `f32`s, how observations and continuous actions are stored in the `Rb` table.
+/// `to_bytes(tensor.data())` stores a dfdx tensor.
+#[cfg(feature = "db")]
+pub fn to_bytes(observation: &[f32]) -> Vec<u8> {
+    observation.iter().flat_map(|x| x.to_le_bytes()).collect()
+}
+
+/// Inverse of `to_bytes`, `None` when the blob is not a whole number of floats.
+/// `to_tensor(&from_bytes(blob)?)` loads a dfdx tensor.
+#[cfg(feature = "db")]
+pub fn from_bytes(bytes: &[u8]) -> Option<Observation> {
+    if bytes.len() % 4 != 0 {
+        return None;
+    }
+    Some(
+        bytes
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect(),
+    )
+}
+
+/// Panics when the observation is not of size `N`.
+#[cfg(feature = "train")]
+pub fn to_tensor<const N: usize>(observation: &[f32]) -> Tensor1D<N> {
+    let mut data = [0.; N];
+    data.copy_from_slice(observation);
+    Tensor1D::new(data)
+}
+
+/// `[B, N]` batch of the observations of `B` envs, panics on any other size.
+#[cfg(feature = "train")]
+pub fn to_batch<const B: usize, const N: usize>(observations: &[Observation]) -> Tensor2D<B, N> {
+    assert_eq!(observations.len(), B, "one observation per env");
+    let mut data = [[0.; N]; B];
+    for (row, observation) in data.iter_mut().zip(observations) {
+        row.copy_from_slice(observation);
+    }
+    Tensor2D::new(data)
+}
+
+pub fn observation_system(
+    config: Res<ObservationConfig>,
+    mut normalizer: ResMut<ObservationNormalizer>,
+    mut roots: Query<(&Humanoid, &mut EnvStep)>,
+    bodies: Query<(&Transform, &Velocity)>,
+    joints: Query<(&JointFrame, &JointMotors)>,
+    parts: Query<(&Name, &Children)>,
+    contacts: Query<&CollidingEntities>,
+) {
+    for (humanoid, mut step) in roots.iter_mut() {
+        let (transform, velocity) = match bodies.get(humanoid.parts[0]) {
+            Ok(body) => body,
+            Err(_) => continue,
+        };
+        let to_local = transform.rotation.inverse();
+        let mut observation = vec![transform.translation.y];
+        match config.orientation {
+            Orientation::Gravity => observation.extend((to_local * -Vec3::Y).to_array()),
+            Orientation::Quaternion => observation.extend(transform.rotation.to_array()),
+        }
+        observation.extend((to_local * velocity.linvel).to_array());
+        observation.extend((to_local * velocity.angvel).to_array());
+
+        let mut positions: Vec<f32> = vec![];
+        let mut velocities: Vec<f32> = vec![];
+        for joint in humanoid.joints.iter() {
+            let (frame, motors) = match joints.get(*joint) {
+                Ok(j) => j,
+                Err(_) => continue,
+            };
+            let axes = motors.axes();
+            if let (Ok(parent), Ok(child)) = (bodies.get(frame.parent), bodies.get(*joint)) {
+                positions.extend(frame.positions(&axes, parent.0, child.0));
+                velocities.extend(frame.velocities(&axes, parent, child.1));
+            }
+        }
+        observation.extend(positions);
+        observation.extend(velocities);
+
+        for part in humanoid.parts.iter() {
+            if let Ok((name, children)) = parts.get(*part) {
+                if !config.contact_parts.iter().any(|c| c.as_str() == name.as_str()) {
+                    continue;
+                }
+                let touching = children
+                    .iter()
+                    .any(|c| contacts.get(*c).map_or(false, |e| !e.is_empty()));
+                observation.push(if touching { 1. } else { 0. });
+            }
+        }
+
+        if config.normalize {
+            normalizer.update(&observation);
+            observation = normalizer.normalize(&observation);
+        }
+        step.observation = observation;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn normalizer_tracks_mean_and_std() {
+        let mut normalizer = ObservationNormalizer::default();
+        normalizer.update(&[1., 10.]);
+        // one sample has no variance yet
+        assert_close(&normalizer.normalize(&[5., 11.]), &[5., 11.]);
+        normalizer.update(&[3., 10.]);
+        normalizer.update(&[5., 10.]);
+        assert_close(&normalizer.mean, &[3., 10.]);
+        assert_close(&normalizer.normalize(&[3., 10.]), &[0., 0.]);
+        // population std of 1, 3, 5; a constant feature is clipped
+        let std = (8_f32 / 3.).sqrt();
+        let clip = ObservationNormalizer::CLIP;
+        assert_close(&normalizer.normalize(&[5., 11.]), &[2. / std, clip]);
+        assert_close(&normalizer.normalize(&[-100., 9.]), &[-clip, -clip]);
+    }
+
+    #[test]
+    fn normalizer_restarts_on_a_new_size() {
+        let mut normalizer = ObservationNormalizer::default();
+        normalizer.update(&[1., 2.]);
+        normalizer.update(&[3., 4.]);
+        normalizer.update(&[7.]);
+        assert_eq!(normalizer.count, 1.);
+        assert_close(&normalizer.mean, &[7.]);
+        assert_close(&normalizer.normalize(&[1., 2.]), &[1., 2.]);
+    }
+
+    #[cfg(feature = "db")]
+    #[test]
+    fn bytes_round_trip() {
+        let observation = vec![0., -1.5, f32::MAX, 1e-30];
+        let bytes = to_bytes(&observation);
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(bytes[4..8], (-1.5_f32).to_le_bytes());
+        assert_eq!(from_bytes(&bytes), Some(observation));
+        assert_eq!(from_bytes(&[]), Some(vec![]));
+        assert_eq!(from_bytes(&bytes[..15]), None);
+    }
+}
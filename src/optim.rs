@@ -0,0 +1,193 @@
+use std::{error::Error, path::Path};
+
+use dfdx::prelude::*;
+
+use crate::checkpoint::{load_net, save_net};
+
+/// Adam keeping its moments in two modules shaped like the network it optimizes, so they
+/// checkpoint as npz next to the network. dfdx's `Adam` keeps its moments private.
+pub struct CheckpointedAdam<M> {
+    pub cfg: AdamConfig,
+    /// Updates so far, for the bias correction of the moments.
+    pub t: i32,
+    pub moment1: M,
+    pub moment2: M,
+}
+
+impl<M: Default + AdamUpdate + SaveToNpz + LoadFromNpz> CheckpointedAdam<M> {
+    pub fn new(cfg: AdamConfig) -> Self {
+        Self {
+            cfg,
+            t: 0,
+            moment1: M::default(),
+            moment2: M::default(),
+        }
+    }
+
+    /// One Adam step of every parameter of `module`, errors when some have no gradient.
+    pub fn update(&mut self, module: &mut M, gradients: Gradients) -> Result<(), String> {
+        self.t += 1;
+        let mut step = AdamStep {
+            gradients,
+            cfg: &self.cfg,
+            t: self.t,
+            unused: 0,
+        };
+        module.adam_update(&mut self.moment1, &mut self.moment2, &mut step);
+        match step.unused {
+            0 => Ok(()),
+            n => Err(format!("{} parameters without gradient", n)),
+        }
+    }
+
+    /// Writes the moments into `<name>_m1.npz` and `<name>_m2.npz`, `t` is left to the caller.
+    pub fn save(&self, dir: &Path, name: &str) -> Result<(), Box<dyn Error>> {
+        save_net(dir, &format!("{}_m1", name), &self.moment1)?;
+        save_net(dir, &format!("{}_m2", name), &self.moment2)
+    }
+
+    pub fn load(&mut self, dir: &Path, name: &str, t: i32) -> Result<(), Box<dyn Error>> {
+        load_net(dir, &format!("{}_m1", name), &mut self.moment1)?;
+        load_net(dir, &format!("{}_m2", name), &mut self.moment2)?;
+        self.t = t;
+        Ok(())
+    }
+}
+
+/// Gradients and settings of one `CheckpointedAdam::update`.
+pub struct AdamStep<'a> {
+    gradients: Gradients,
+    cfg: &'a AdamConfig,
+    t: i32,
+    /// Parameters without a gradient, left unchanged.
+    unused: usize,
+}
+impl AdamStep<'_> {
+    fn apply<'a>(
+        &mut self,
+        params: impl Iterator<Item = &'a mut f32>,
+        gradients: impl Iterator<Item = &'a f32>,
+        moment1: impl Iterator<Item = &'a mut f32>,
+        moment2: impl Iterator<Item = &'a mut f32>,
+    ) {
+        let [beta1, beta2] = self.cfg.betas;
+        let bias1 = 1. - beta1.powi(self.t);
+        let bias2 = 1. - beta2.powi(self.t);
+        let elements = params.zip(gradients).zip(moment1).zip(moment2);
+        for (((p, g), m), v) in elements {
+            *m = beta1 * *m + (1. - beta1) * g;
+            *v = beta2 * *v + (1. - beta2) * g * g;
+            *p -= self.cfg.lr * (*m / bias1) / ((*v / bias2).sqrt() + self.cfg.eps);
+        }
+    }
+}
+
+/// Adam step of every parameter against the moments held by two modules of the same type.
+pub trait AdamUpdate {
+    fn adam_update(&mut self, moment1: &mut Self, moment2: &mut Self, step: &mut AdamStep);
+}
+impl<const I: usize, const O: usize> AdamUpdate for Linear<I, O> {
+    fn adam_update(&mut self, moment1: &mut Self, moment2: &mut Self, step: &mut AdamStep) {
+        match step.gradients.remove(&self.weight) {
+            Some(gradient) => step.apply(
+                self.weight.mut_data().iter_mut().flatten(),
+                gradient.iter().flatten(),
+                moment1.weight.mut_data().iter_mut().flatten(),
+                moment2.weight.mut_data().iter_mut().flatten(),
+            ),
+            None => step.unused += 1,
+        }
+        match step.gradients.remove(&self.bias) {
+            Some(gradient) => step.apply(
+                self.bias.mut_data().iter_mut(),
+                gradient.iter(),
+                moment1.bias.mut_data().iter_mut(),
+                moment2.bias.mut_data().iter_mut(),
+            ),
+            None => step.unused += 1,
+        }
+    }
+}
+impl AdamUpdate for ReLU {
+    fn adam_update(&mut self, _: &mut Self, _: &mut Self, _: &mut AdamStep) {}
+}
+impl AdamUpdate for Tanh {
+    fn adam_update(&mut self, _: &mut Self, _: &mut Self, _: &mut AdamStep) {}
+}
+impl<A: AdamUpdate, B: AdamUpdate> AdamUpdate for (A, B) {
+    fn adam_update(&mut self, moment1: &mut Self, moment2: &mut Self, step: &mut AdamStep) {
+        self.0.adam_update(&mut moment1.0, &mut moment2.0, step);
+        self.1.adam_update(&mut moment1.1, &mut moment2.1, step);
+    }
+}
+impl<A: AdamUpdate, B: AdamUpdate, C: AdamUpdate> AdamUpdate for (A, B, C) {
+    fn adam_update(&mut self, moment1: &mut Self, moment2: &mut Self, step: &mut AdamStep) {
+        self.0.adam_update(&mut moment1.0, &mut moment2.0, step);
+        self.1.adam_update(&mut moment1.1, &mut moment2.1, step);
+        self.2.adam_update(&mut moment1.2, &mut moment2.2, step);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    type Net = (Linear<3, 4>, ReLU, Linear<4, 2>);
+
+    #[test]
+    fn steps_like_dfdx_adam() {
+        let mut net = Net::default();
+        net.reset_params(&mut StdRng::seed_from_u64(0));
+        let mut expected = net.clone();
+        let cfg = || AdamConfig {
+            lr: 1e-2,
+            ..Default::default()
+        };
+        let mut adam = Adam::new(cfg());
+        let mut ours = CheckpointedAdam::new(cfg());
+        let x = Tensor1D::new([1., -2., 0.5]);
+        let target = Tensor1D::new([0.3, -0.7]);
+        for _ in 0..3 {
+            let loss = mse_loss(expected.forward(x.trace()), &target);
+            adam.update(&mut expected, loss.backward())
+                .expect("unused params");
+            let loss = mse_loss(net.forward(x.trace()), &target);
+            ours.update(&mut net, loss.backward()).unwrap();
+        }
+        assert_eq!(ours.t, 3);
+        assert_close(net.0.bias.data(), expected.0.bias.data());
+        assert_close(net.2.bias.data(), expected.2.bias.data());
+        for (row, expected_row) in net.0.weight.data().iter().zip(expected.0.weight.data()) {
+            assert_close(row, expected_row);
+        }
+    }
+
+    #[test]
+    fn reports_parameters_without_gradient() {
+        let mut net = Net::default();
+        let x = Tensor1D::new([1., -2., 0.5]);
+        // the loss only reaches the last layer
+        let loss = mse_loss(net.2.forward(x.trace()), &Tensor1D::zeros());
+        let mut ours = CheckpointedAdam::<Net>::new(Default::default());
+        assert!(ours.update(&mut net, loss.backward()).is_err());
+    }
+
+    #[test]
+    fn moments_round_trip() {
+        let dir = std::env::temp_dir().join(format!("optim_moments_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut ours = CheckpointedAdam::<Net>::new(Default::default());
+        ours.moment1.0.bias = Tensor1D::new([1., 2., 3., 4.]);
+        ours.moment2.2.weight = Tensor2D::new([[5., 6., 7., 8.], [9., 10., 11., 12.]]);
+        ours.save(&dir, "net_adam").unwrap();
+
+        let mut loaded = CheckpointedAdam::<Net>::new(Default::default());
+        loaded.load(&dir, "net_adam", 7).unwrap();
+        assert_eq!(loaded.t, 7);
+        assert_eq!(loaded.moment1.0.bias.data(), ours.moment1.0.bias.data());
+        assert_eq!(loaded.moment2.2.weight.data(), ours.moment2.2.weight.data());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,498 @@
+use std::{
+    collections::HashSet,
+    error::Error,
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use dfdx::prelude::*;
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    action::{Action, ActionMode},
+    agent::Agent,
+    checkpoint::{load_net, reseed, save_net},
+    env::Observation,
+    nets::{
+        gaussian_forward, gaussian_log_prob, gaussian_log_prob_single, standard_normal,
+        GaussianActor, Mlp, HUMANOID_ACT, LOG_STD_MAX, LOG_STD_MIN,
+    },
+    observation::{to_batch, to_tensor, HUMANOID_OBS},
+    optim::CheckpointedAdam,
+    replay::{Decoder, Encoder, Transition},
+};
+
+const BATCH: usize = 64;
+
+pub type PpoActor = GaussianActor<HUMANOID_OBS, HUMANOID_ACT>;
+pub type PpoCritic = Mlp<HUMANOID_OBS, 1>;
+
+#[derive(Serialize)]
+pub struct PpoConfig {
+    pub gamma: f32,
+    /// GAE lambda.
+    pub lambda: f32,
+    pub clip: f32,
+    pub actor_lr: f32,
+    pub critic_lr: f32,
+    /// Transitions over all envs collected before each update, the rollout also keeps the
+    /// other transitions of the frame reaching it.
+    pub rollout_length: usize,
+    /// Passes over the rollout per update, in minibatches of 64. The last minibatch of a pass
+    /// is filled up with transitions from the start of the pass.
+    pub epochs: usize,
+    /// Joint torques instead of position targets, see `ActionMode::Torque`.
+    pub torque: bool,
+    pub seed: u64,
+}
+impl Default for PpoConfig {
+    fn default() -> Self {
+        Self {
+            gamma: 0.99,
+            lambda: 0.95,
+            clip: 0.2,
+            actor_lr: 3e-4,
+            critic_lr: 1e-3,
+            rollout_length: 2048,
+            epochs: 10,
+            torque: false,
+            seed: 0,
+        }
+    }
+}
+
+/// Policy output recorded when acting, paired with the transition in `observe`.
+#[derive(Clone)]
+struct Pending {
+    action: Vec<f32>,
+    log_prob: f32,
+    value: f32,
+}
+
+struct RolloutStep {
+    env: usize,
+    transition: Transition,
+    action: Vec<f32>,
+    log_prob: f32,
+    value: f32,
+}
+
+/// Proximal policy optimization over `ActionMode::Position` or `Torque`: Gaussian actor with
+/// a learned log-std, critic, GAE advantages and the clipped surrogate objective, trained for
+/// `epochs` over minibatches of every rollout.
+pub struct PpoAgent {
+    pub config: PpoConfig,
+    pub actor: PpoActor,
+    pub critic: PpoCritic,
+    pub actor_optimizer: CheckpointedAdam<PpoActor>,
+    pub critic_optimizer: CheckpointedAdam<PpoCritic>,
+    pub rng: StdRng,
+    pub updates: usize,
+    pending: Vec<Option<Pending>>,
+    rollout: Vec<RolloutStep>,
+}
+/// Checkpointed training state, the rollout in progress goes to `rollout.bin`.
+#[derive(Serialize, Deserialize)]
+struct PpoState {
+    seed: u64,
+    updates: usize,
+    /// Steps of the actor and the critic optimizer.
+    adam_t: [i32; 2],
+}
+
+impl PpoAgent {
+    pub fn new(config: PpoConfig) -> Self {
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let mut actor = PpoActor::default();
+        actor.reset_params(&mut rng);
+        let mut critic = PpoCritic::default();
+        critic.reset_params(&mut rng);
+        let actor_optimizer = CheckpointedAdam::new(AdamConfig {
+            lr: config.actor_lr,
+            ..Default::default()
+        });
+        let critic_optimizer = CheckpointedAdam::new(AdamConfig {
+            lr: config.critic_lr,
+            ..Default::default()
+        });
+        Self {
+            config,
+            actor,
+            critic,
+            actor_optimizer,
+            critic_optimizer,
+            rng,
+            updates: 0,
+            pending: vec![],
+            rollout: vec![],
+        }
+    }
+
+    pub fn value(&self, observation: &[f32]) -> f32 {
+        let value: Tensor1D<1> = self.critic.forward(to_tensor::<HUMANOID_OBS>(observation));
+        value.data()[0]
+    }
+
+    /// Mean and clamped log-std of the policy.
+    pub fn policy(&self, observation: &[f32]) -> ([f32; HUMANOID_ACT], [f32; HUMANOID_ACT]) {
+        assert_eq!(
+            observation.len(),
+            HUMANOID_OBS,
+            "PPO is sized for the default humanoid and observation config"
+        );
+        let observation = to_tensor::<HUMANOID_OBS>(observation);
+        let mean: Tensor1D<HUMANOID_ACT> = self.actor.0.forward(observation.clone());
+        let log_std: Tensor1D<HUMANOID_ACT> = self.actor.1.forward(observation);
+        let mut log_std = *log_std.data();
+        for s in log_std.iter_mut() {
+            *s = s.clamp(LOG_STD_MIN, LOG_STD_MAX);
+        }
+        (*mean.data(), log_std)
+    }
+
+    /// Advantages and returns of the rollout, per env so trajectories don't mix.
+    fn advantages(&self) -> (Vec<f32>, Vec<f32>) {
+        let gamma = self.config.gamma;
+        let lambda = self.config.lambda;
+        let mut advantages = vec![0.; self.rollout.len()];
+        let envs = self
+            .rollout
+            .iter()
+            .map(|s| s.env)
+            .max()
+            .map_or(0, |e| e + 1);
+        for env in 0..envs {
+            let mut next_advantage = 0.;
+            for (i, step) in self.rollout.iter().enumerate().rev() {
+                if step.env != env {
+                    continue;
+                }
+                let t = &step.transition;
+                let next_value = match t.done {
+                    true => 0.,
+                    false => self.value(&t.next_state),
+                };
+                if t.done || t.truncated {
+                    next_advantage = 0.;
+                }
+                let delta = t.reward + gamma * next_value - step.value;
+                advantages[i] = delta + gamma * lambda * next_advantage;
+                next_advantage = advantages[i];
+            }
+        }
+        let returns = advantages
+            .iter()
+            .zip(self.rollout.iter())
+            .map(|(a, s)| a + s.value)
+            .collect();
+        (advantages, returns)
+    }
+
+    fn update(&mut self) -> (f32, f32) {
+        let (mut advantages, returns) = self.advantages();
+        let n = advantages.len() as f32;
+        let advantage_mean = advantages.iter().sum::<f32>() / n;
+        let advantage_std = (advantages
+            .iter()
+            .map(|a| (a - advantage_mean).powi(2))
+            .sum::<f32>()
+            / n)
+            .sqrt();
+        for a in advantages.iter_mut() {
+            *a = (*a - advantage_mean) / (advantage_std + 1e-8);
+        }
+
+        let mut indices: Vec<usize> = (0..self.rollout.len()).collect();
+        let minibatches = (indices.len() + BATCH - 1) / BATCH;
+        let (mut policy_loss, mut value_loss) = (0., 0.);
+        for _ in 0..self.config.epochs {
+            indices.shuffle(&mut self.rng);
+            let order: Vec<usize> = indices
+                .iter()
+                .copied()
+                .cycle()
+                .take(minibatches * BATCH)
+                .collect();
+            for minibatch in order.chunks_exact(BATCH) {
+                let states: Vec<Observation> = minibatch
+                    .iter()
+                    .map(|i| self.rollout[*i].transition.state.clone())
+                    .collect();
+                let actions: Vec<Vec<f32>> = minibatch
+                    .iter()
+                    .map(|i| self.rollout[*i].action.clone())
+                    .collect();
+                let mut old_log_probs = [0.; BATCH];
+                let mut batch_advantages = [0.; BATCH];
+                let mut batch_returns = [[0.; 1]; BATCH];
+                for (b, i) in minibatch.iter().enumerate() {
+                    old_log_probs[b] = self.rollout[*i].log_prob;
+                    batch_advantages[b] = advantages[*i];
+                    batch_returns[b] = [returns[*i]];
+                }
+                let states = to_batch::<BATCH, HUMANOID_OBS>(&states);
+                let actions = to_batch::<BATCH, HUMANOID_ACT>(&actions);
+                let advantage = Tensor1D::new(batch_advantages);
+
+                // -min(ratio * A, clip(ratio, 1 - eps, 1 + eps) * A)
+                let (mu, log_std) = gaussian_forward(&self.actor, states.clone().trace());
+                let log_probs = gaussian_log_prob(mu, log_std, &actions);
+                let (ratio, tape) = exp(sub(log_probs, &Tensor1D::new(old_log_probs))).split_tape();
+                let (surrogate, tape) =
+                    mul(ratio.duplicate().put_tape(tape), &advantage).split_tape();
+                let clipped = mul(
+                    clamp(
+                        ratio.put_tape(tape),
+                        1. - self.config.clip,
+                        1. + self.config.clip,
+                    ),
+                    &advantage,
+                );
+                let loss = negate(mean(minimum(clipped, &surrogate)));
+                policy_loss = *loss.data();
+                self.actor_optimizer
+                    .update(&mut self.actor, loss.backward())
+                    .expect("unused params");
+
+                let values: Tensor2D<BATCH, 1, OwnedTape> = self.critic.forward(states.trace());
+                let loss = mse_loss(values, &Tensor2D::new(batch_returns));
+                value_loss = *loss.data();
+                self.critic_optimizer
+                    .update(&mut self.critic, loss.backward())
+                    .expect("unused params");
+            }
+        }
+        self.updates += 1;
+        (policy_loss, value_loss)
+    }
+}
+
+impl Agent for PpoAgent {
+    fn name(&self) -> &'static str {
+        "ppo"
+    }
+
+    fn config(&self) -> serde_json::Value {
+        serde_json::to_value(&self.config).unwrap()
+    }
+
+    fn action_mode(&self) -> ActionMode {
+        ActionMode::continuous(self.config.torque)
+    }
+
+    fn act(&mut self, observations: &[Observation]) -> Vec<Action> {
+        self.pending.resize(observations.len(), None);
+        let mut actions = vec![];
+        for (env, observation) in observations.iter().enumerate() {
+            let (mean, log_std) = self.policy(observation);
+            let action: Vec<f32> = mean
+                .iter()
+                .zip(log_std.iter())
+                .map(|(m, s)| m + s.exp() * standard_normal(&mut self.rng))
+                .collect();
+            self.pending[env] = Some(Pending {
+                log_prob: gaussian_log_prob_single(&mean, &log_std, &action),
+                value: self.value(observation),
+                action: action.clone(),
+            });
+            // unclamped, `action_system` clamps into [-1, 1]
+            actions.push(Action::Continuous(action));
+        }
+        actions
+    }
+
+    fn act_deterministic(&mut self, observations: &[Observation]) -> Vec<Action> {
+        observations
+            .iter()
+            .map(|observation| Action::Continuous(self.policy(observation).0.to_vec()))
+            .collect()
+    }
+
+    fn log_prob(&self, env: usize) -> Option<f32> {
+        Some(self.pending.get(env)?.as_ref()?.log_prob)
+    }
+
+    fn observe(&mut self, transitions: Vec<(usize, Transition)>) {
+        for (env, transition) in transitions {
+            if let Some(Pending {
+                action,
+                log_prob,
+                value,
+            }) = self.pending.get_mut(env).and_then(Option::take)
+            {
+                self.rollout.push(RolloutStep {
+                    env,
+                    transition,
+                    action,
+                    log_prob,
+                    value,
+                });
+            }
+        }
+        if self.rollout.len() < self.config.rollout_length {
+            return;
+        }
+        let reward = self
+            .rollout
+            .iter()
+            .map(|s| s.transition.reward)
+            .sum::<f32>()
+            / self.rollout.len() as f32;
+        let (policy_loss, value_loss) = self.update();
+        println!(
+            "ppo update {}: mean reward {:.3}, policy loss {:.4}, value loss {:.4}",
+            self.updates, reward, policy_loss, value_loss
+        );
+        self.rollout.clear();
+    }
+
+    fn save(&mut self, dir: &Path) -> Result<serde_json::Value, Box<dyn Error>> {
+        save_net(dir, "actor", &self.actor)?;
+        save_net(dir, "critic", &self.critic)?;
+        self.actor_optimizer.save(dir, "actor_adam")?;
+        self.critic_optimizer.save(dir, "critic_adam")?;
+        let mut out = Encoder(BufWriter::new(File::create(dir.join("rollout.bin"))?));
+        out.u64(self.rollout.len() as u64)?;
+        for step in &self.rollout {
+            out.u64(step.env as u64)?;
+            out.transition(&step.transition)?;
+            out.floats(&step.action)?;
+            out.f32(step.log_prob)?;
+            out.f32(step.value)?;
+        }
+        out.0.flush()?;
+        Ok(serde_json::to_value(PpoState {
+            seed: reseed(&mut self.rng),
+            updates: self.updates,
+            adam_t: [self.actor_optimizer.t, self.critic_optimizer.t],
+        })?)
+    }
+
+    fn load(&mut self, dir: &Path, state: serde_json::Value) -> Result<(), Box<dyn Error>> {
+        let state: PpoState = serde_json::from_value(state)?;
+        load_net(dir, "actor", &mut self.actor)?;
+        load_net(dir, "critic", &mut self.critic)?;
+        self.actor_optimizer
+            .load(dir, "actor_adam", state.adam_t[0])?;
+        self.critic_optimizer
+            .load(dir, "critic_adam", state.adam_t[1])?;
+        self.rng = StdRng::seed_from_u64(state.seed);
+        self.updates = state.updates;
+        let mut input = Decoder(BufReader::new(File::open(dir.join("rollout.bin"))?));
+        self.rollout.clear();
+        for _ in 0..input.u64()? {
+            self.rollout.push(RolloutStep {
+                env: input.u64()? as usize,
+                transition: input.transition()?,
+                action: input.floats()?,
+                log_prob: input.f32()?,
+                value: input.f32()?,
+            });
+        }
+        // the envs restart on resume, the episodes in progress were cut at the checkpoint
+        let mut cut = HashSet::new();
+        for step in self.rollout.iter_mut().rev() {
+            if cut.insert(step.env) && !step.transition.done {
+                step.transition.truncated = true;
+            }
+        }
+        self.pending.clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    fn step(env: usize, reward: f32, value: f32, done: bool, truncated: bool) -> RolloutStep {
+        RolloutStep {
+            env,
+            transition: Transition {
+                state: vec![0.; HUMANOID_OBS],
+                action: Action::Continuous(vec![0.; HUMANOID_ACT]),
+                reward,
+                next_state: vec![0.; HUMANOID_OBS],
+                log_prob: None,
+                done,
+                truncated,
+            },
+            action: vec![0.; HUMANOID_ACT],
+            log_prob: 0.,
+            value,
+        }
+    }
+
+    /// Zero critic, so bootstrapped next values are 0 and only the stored values count.
+    fn agent(rollout: Vec<RolloutStep>) -> PpoAgent {
+        let mut agent = PpoAgent::new(PpoConfig {
+            gamma: 0.9,
+            lambda: 0.5,
+            ..Default::default()
+        });
+        agent.critic = PpoCritic::default();
+        agent.rollout = rollout;
+        agent
+    }
+
+    #[test]
+    fn advantages_are_discounted_per_env() {
+        // env 0 ends in a failure, env 1 times out then starts a new episode
+        let agent = agent(vec![
+            step(0, 1., 0., false, false),
+            step(1, 2., 0., false, true),
+            step(0, 1., 0., false, false),
+            step(1, 3., 1., false, false),
+            step(0, 1., 0., true, false),
+        ]);
+        let (advantages, returns) = agent.advantages();
+        // gamma * lambda = 0.45
+        let env0 = [1. + 0.45 * 1.45, 1. + 0.45 * 1., 1.];
+        assert_close(&advantages, &[env0[0], 2., env0[1], 2., env0[2]]);
+        assert_close(&returns, &[env0[0], 2., env0[1], 3., env0[2]]);
+    }
+
+    #[test]
+    fn failures_do_not_bootstrap() {
+        let mut agent = agent(vec![step(0, 1., 0.5, true, false)]);
+        // a critic valuing every state at its bias, ignored past a failure
+        agent.critic.2.bias = Tensor1D::new([10.]);
+        let (advantages, _) = agent.advantages();
+        assert_close(&advantages, &[0.5]);
+        agent.rollout[0].transition.done = false;
+        let (advantages, _) = agent.advantages();
+        assert_close(&advantages, &[1. + 0.9 * 10. - 0.5]);
+    }
+    #[test]
+    fn checkpoints_cut_the_episodes_of_the_rollout() {
+        let dir = std::env::temp_dir().join(format!("ppo_rollout_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut saved = agent(vec![
+            step(0, 1., 0.1, false, false),
+            step(1, 2., 0.2, false, false),
+            step(0, 3., 0.3, true, false),
+            step(2, 4., 0.4, false, false),
+        ]);
+        let state = saved.save(&dir).unwrap();
+        let mut loaded = agent(vec![]);
+        loaded.load(&dir, state).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let rewards: Vec<f32> = loaded.rollout.iter().map(|s| s.transition.reward).collect();
+        assert_eq!(rewards, [1., 2., 3., 4.]);
+        assert_eq!(loaded.rollout[3].value, 0.4);
+        let ends: Vec<(bool, bool)> = loaded
+            .rollout
+            .iter()
+            .map(|s| (s.transition.done, s.transition.truncated))
+            .collect();
+        // the last step of every unfinished episode is truncated
+        assert_eq!(
+            ends,
+            [(false, false), (false, true), (true, false), (false, true)]
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use std::{
+    error::Error,
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use rand::Rng;
+
+use crate::{action::Action, env::Observation};
+
+/// One env step, the row layout of the `Rb` table.
+#[derive(Debug, Clone)]
+pub struct Transition {
+    pub state: Observation,
+    pub action: Action,
+    pub reward: f32,
+    pub next_state: Observation,
+    /// Log-probability of `action` under the policy that chose it, see `Agent::log_prob`.
+    pub log_prob: Option<f32>,
+    /// The episode failed, `next_state` has no value to bootstrap from.
+    /// Timeouts are not done, the episode was only cut short.
+    pub done: bool,
+    /// The episode hit the step limit, `next_state` still has a value.
+    /// Not stored in the `Rb` table.
+    pub truncated: bool,
+}
+
+/// Fixed capacity ring buffer of transitions, the oldest are overwritten once full.
+pub struct ReplayBuffer {
+    pub capacity: usize,
+    transitions: Vec<Transition>,
+    next: usize,
+}
+impl ReplayBuffer {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "replay buffer capacity must be positive");
+        Self {
+            capacity,
+            transitions: Vec::with_capacity(capacity),
+            next: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.transitions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.transitions.is_empty()
+    }
+
+    pub fn push(&mut self, transition: Transition) {
+        if self.transitions.len() < self.capacity {
+            self.transitions.push(transition);
+        } else {
+            self.transitions[self.next] = transition;
+        }
+        self.next = (self.next + 1) % self.capacity;
+    }
+
+    /// `n` transitions drawn uniformly with replacement, panics when empty.
+    pub fn sample<R: Rng>(&self, rng: &mut R, n: usize) -> Vec<&Transition> {
+        assert!(!self.is_empty(), "sampling an empty replay buffer");
+        (0..n)
+            .map(|_| &self.transitions[rng.gen_range(0..self.transitions.len())])
+            .collect()
+    }
+
+    /// Writes the capacity, the write position and every transition to `path`.
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let mut out = Encoder(BufWriter::new(File::create(path)?));
+        out.u64(self.capacity as u64)?;
+        out.u64(self.next as u64)?;
+        out.u64(self.transitions.len() as u64)?;
+        for transition in &self.transitions {
+            out.transition(transition)?;
+        }
+        out.0.flush()?;
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let mut input = Decoder(BufReader::new(File::open(path)?));
+        let capacity = input.u64()? as usize;
+        let next = input.u64()? as usize;
+        let len = input.u64()? as usize;
+        if len > capacity || next >= capacity || (len < capacity && next != len) {
+            return Err(format!("corrupt replay buffer {:?}", path).into());
+        }
+        let mut buffer = Self::new(capacity);
+        for _ in 0..len {
+            buffer.transitions.push(input.transition()?);
+        }
+        buffer.next = next;
+        Ok(buffer)
+    }
+}
+
+/// Little-endian binary writer of transitions, for checkpoints. serde_json would turn
+/// hundreds of thousands of observations into text.
+pub struct Encoder<W: Write>(pub W);
+impl<W: Write> Encoder<W> {
+    pub fn u8(&mut self, value: u8) -> io::Result<()> {
+        self.0.write_all(&[value])
+    }
+
+    pub fn u64(&mut self, value: u64) -> io::Result<()> {
+        self.0.write_all(&value.to_le_bytes())
+    }
+
+    pub fn f32(&mut self, value: f32) -> io::Result<()> {
+        self.0.write_all(&value.to_le_bytes())
+    }
+
+    /// Length prefixed.
+    pub fn floats(&mut self, values: &[f32]) -> io::Result<()> {
+        self.u64(values.len() as u64)?;
+        values.iter().try_for_each(|value| self.f32(*value))
+    }
+
+    pub fn transition(&mut self, transition: &Transition) -> io::Result<()> {
+        self.floats(&transition.state)?;
+        match &transition.action {
+            Action::Discrete(action) => {
+                self.u8(0)?;
+                self.0.write_all(&action.to_le_bytes())?;
+            }
+            Action::Continuous(action) => {
+                self.u8(1)?;
+                self.floats(action)?;
+            }
+        }
+        self.f32(transition.reward)?;
+        self.floats(&transition.next_state)?;
+        match transition.log_prob {
+            Some(log_prob) => {
+                self.u8(1)?;
+                self.f32(log_prob)?;
+            }
+            None => self.u8(0)?,
+        }
+        self.u8(transition.done as u8)?;
+        self.u8(transition.truncated as u8)
+    }
+}
+
+/// Reads what `Encoder` wrote.
+pub struct Decoder<R: Read>(pub R);
+impl<R: Read> Decoder<R> {
+    pub fn u8(&mut self) -> io::Result<u8> {
+        let mut bytes = [0; 1];
+        self.0.read_exact(&mut bytes)?;
+        Ok(bytes[0])
+    }
+
+    pub fn u64(&mut self) -> io::Result<u64> {
+        let mut bytes = [0; 8];
+        self.0.read_exact(&mut bytes)?;
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    pub fn f32(&mut self) -> io::Result<f32> {
+        let mut bytes = [0; 4];
+        self.0.read_exact(&mut bytes)?;
+        Ok(f32::from_le_bytes(bytes))
+    }
+
+    pub fn floats(&mut self) -> io::Result<Vec<f32>> {
+        let len = self.u64()?;
+        (0..len).map(|_| self.f32()).collect()
+    }
+
+    pub fn transition(&mut self) -> io::Result<Transition> {
+        let state = self.floats()?;
+        let action = match self.u8()? {
+            0 => {
+                let mut bytes = [0; 4];
+                self.0.read_exact(&mut bytes)?;
+                Action::Discrete(i32::from_le_bytes(bytes))
+            }
+            1 => Action::Continuous(self.floats()?),
+            tag => {
+                let message = format!("unknown action tag {}", tag);
+                return Err(io::Error::new(io::ErrorKind::InvalidData, message));
+            }
+        };
+        let reward = self.f32()?;
+        let next_state = self.floats()?;
+        let log_prob = match self.u8()? {
+            0 => None,
+            _ => Some(self.f32()?),
+        };
+        Ok(Transition {
+            state,
+            action,
+            reward,
+            next_state,
+            log_prob,
+            done: self.u8()? != 0,
+            truncated: self.u8()? != 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn transition(reward: f32) -> Transition {
+        Transition {
+            state: vec![reward; 3],
+            action: Action::Discrete(reward as i32),
+            reward,
+            next_state: vec![reward + 1.; 3],
+            log_prob: None,
+            done: false,
+            truncated: false,
+        }
+    }
+
+    fn rewards(buffer: &ReplayBuffer) -> Vec<f32> {
+        buffer.transitions.iter().map(|t| t.reward).collect()
+    }
+
+    #[test]
+    fn overwrites_the_oldest_once_full() {
+        let mut buffer = ReplayBuffer::new(3);
+        assert!(buffer.is_empty());
+        for reward in 0..5 {
+            buffer.push(transition(reward as f32));
+        }
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(rewards(&buffer), [3., 4., 2.]);
+        buffer.push(transition(5.));
+        assert_eq!(rewards(&buffer), [3., 4., 5.]);
+    }
+
+    #[test]
+    fn samples_only_stored_transitions() {
+        let mut buffer = ReplayBuffer::new(10);
+        buffer.push(transition(1.));
+        buffer.push(transition(2.));
+        let mut rng = StdRng::seed_from_u64(0);
+        let sample = buffer.sample(&mut rng, 50);
+        assert_eq!(sample.len(), 50);
+        assert!(sample.iter().all(|t| t.reward == 1. || t.reward == 2.));
+        assert!(sample.iter().any(|t| t.reward == 1.) && sample.iter().any(|t| t.reward == 2.));
+    }
+
+    #[test]
+    #[should_panic(expected = "empty replay buffer")]
+    fn sampling_an_empty_buffer_panics() {
+        ReplayBuffer::new(1).sample(&mut StdRng::seed_from_u64(0), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity must be positive")]
+    fn zero_capacity_panics() {
+        ReplayBuffer::new(0);
+    }
+    #[test]
+    fn save_and_load_keep_contents_and_write_position() {
+        let path = std::env::temp_dir().join(format!("replay_{}.bin", std::process::id()));
+        let mut buffer = ReplayBuffer::new(3);
+        for reward in 0..4 {
+            buffer.push(transition(reward as f32));
+        }
+        buffer.push(Transition {
+            action: Action::Continuous(vec![0.5, -1.]),
+            log_prob: Some(-2.5),
+            done: true,
+            truncated: true,
+            ..transition(7.)
+        });
+        buffer.save(&path).unwrap();
+        let mut loaded = ReplayBuffer::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.capacity, 3);
+        assert_eq!(rewards(&loaded), [3., 7., 2.]);
+        let saved = &buffer.transitions[1];
+        let restored = &loaded.transitions[1];
+        assert_eq!(restored.state, saved.state);
+        assert_eq!(restored.next_state, saved.next_state);
+        assert!(matches!(&restored.action, Action::Continuous(a) if a == &[0.5, -1.]));
+        assert_eq!(restored.log_prob, Some(-2.5));
+        assert!(restored.done && restored.truncated);
+        assert!(matches!(loaded.transitions[0].action, Action::Discrete(3)));
+        // the next push overwrites the oldest, as it would have before saving
+        loaded.push(transition(8.));
+        assert_eq!(rewards(&loaded), [3., 7., 8.]);
+    }
+
+    #[test]
+    fn load_rejects_truncated_files() {
+        let path = std::env::temp_dir().join(format!("replay_cut_{}.bin", std::process::id()));
+        let mut buffer = ReplayBuffer::new(2);
+        buffer.push(transition(1.));
+        buffer.save(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+        assert!(ReplayBuffer::load(&path).is_err());
+        std::fs::write(&path, [0_u8; 24]).unwrap();
+        assert!(ReplayBuffer::load(&path).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
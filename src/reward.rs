@@ -0,0 +1,251 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use serde::Deserialize;
+use std::error::Error;
+
+use crate::{
+    action::Action,
+    env::EnvStep,
+    humanoid::{Humanoid, JointFrame, JointMotors, FORWARD},
+};
+
+/// Raw measurements, signs and scales come from the weights in `RewardConfig`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RewardTerm {
+    /// 1 every step.
+    Alive,
+    /// Horizontal torso velocity along the direction the humanoid faces,
+    /// the torso `humanoid::FORWARD` axis projected on the ground.
+    ForwardVelocity,
+    /// Height of the `part` named part, or of the torso when absent.
+    Height { part: String },
+    /// Cosine between the torso up axis and the world up axis.
+    Upright,
+    /// Sum of squared continuous actions, 1 for any discrete action but the no-op.
+    Energy,
+    /// Free joint axes within `margin` of their limits, as a fraction of the range.
+    JointLimit { margin: f32 },
+    /// 1 when some but not all of the named parts touch something, rewarding single support.
+    Contact { parts: Vec<String> },
+}
+impl RewardTerm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RewardTerm::Alive => "alive",
+            RewardTerm::ForwardVelocity => "forward_velocity",
+            RewardTerm::Height { .. } => "height",
+            RewardTerm::Upright => "upright",
+            RewardTerm::Energy => "energy",
+            RewardTerm::JointLimit { .. } => "joint_limit",
+            RewardTerm::Contact { .. } => "contact",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct WeightedTerm {
+    pub term: RewardTerm,
+    pub weight: f32,
+}
+
+/// Reward terms of an experiment, the reward is the weighted sum of the terms.
+/// Weighted values are kept per term in `EnvStep::reward_terms`, in the same order.
+#[derive(Deserialize, Debug, Clone)]
+pub struct RewardConfig {
+    pub terms: Vec<WeightedTerm>,
+}
+impl Default for RewardConfig {
+    fn default() -> Self {
+        let terms = vec![
+            (RewardTerm::Alive, 1.),
+            (RewardTerm::ForwardVelocity, 1.),
+            (
+                RewardTerm::Height {
+                    part: "head".to_string(),
+                },
+                0.,
+            ),
+            (RewardTerm::Upright, 0.),
+            (RewardTerm::Energy, -0.01),
+            (RewardTerm::JointLimit { margin: 0.05 }, -0.1),
+            (
+                RewardTerm::Contact {
+                    parts: vec!["foot".to_string()],
+                },
+                0.,
+            ),
+        ];
+        Self {
+            terms: terms
+                .into_iter()
+                .map(|(term, weight)| WeightedTerm { term, weight })
+                .collect(),
+        }
+    }
+}
+impl RewardConfig {
+    /// JSON with the same layout as the struct, e.g.
+    /// `{"terms": [{"term": {"type": "forward_velocity"}, "weight": 1.0}]}`.
+    pub fn load(path: &str) -> Result<Self, Box<dyn Error>> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+    pub fn names(&self) -> Vec<&'static str> {
+        self.terms.iter().map(|t| t.term.name()).collect()
+    }
+}
+
+/// Torso velocity along its `FORWARD` axis flattened on the ground,
+/// see `RewardTerm::ForwardVelocity`.
+fn forward_velocity(rotation: Quat, linvel: Vec3) -> f32 {
+    let forward = rotation * FORWARD;
+    let heading = Vec3::new(forward.x, 0., forward.z).normalize_or_zero();
+    linvel.dot(heading)
+}
+
+fn part_touching(children: &Children, contacts: &Query<&CollidingEntities>) -> bool {
+    children
+        .iter()
+        .any(|c| contacts.get(*c).map_or(false, |e| !e.is_empty()))
+}
+
+pub fn reward_system(
+    config: Res<RewardConfig>,
+    mut roots: Query<(&Humanoid, &Action, &mut EnvStep)>,
+    bodies: Query<(&Transform, &Velocity)>,
+    joints: Query<(&JointFrame, &JointMotors)>,
+    parts: Query<(&Name, &Children)>,
+    contacts: Query<&CollidingEntities>,
+) {
+    for (humanoid, action, mut step) in roots.iter_mut() {
+        let (transform, velocity) = match bodies.get(humanoid.parts[0]) {
+            Ok(body) => body,
+            Err(_) => continue,
+        };
+        let find_part = |name: &str| {
+            humanoid
+                .parts
+                .iter()
+                .find(|p| parts.get(**p).map_or(false, |(n, _)| n.as_str() == name))
+        };
+        let terms: Vec<f32> = config
+            .terms
+            .iter()
+            .map(|WeightedTerm { term, weight }| {
+                let value = match term {
+                    RewardTerm::Alive => 1.,
+                    RewardTerm::ForwardVelocity => {
+                        forward_velocity(transform.rotation, velocity.linvel)
+                    }
+                    RewardTerm::Height { part } => find_part(part)
+                        .and_then(|p| bodies.get(*p).ok())
+                        .map_or(transform.translation.y, |(t, _)| t.translation.y),
+                    RewardTerm::Upright => (transform.rotation * Vec3::Y).dot(Vec3::Y),
+                    RewardTerm::Energy => match action {
+                        Action::Continuous(a) => a.iter().map(|a| a * a).sum(),
+                        Action::Discrete(0) => 0.,
+                        Action::Discrete(_) => 1.,
+                    },
+                    RewardTerm::JointLimit { margin } => {
+                        let mut count = 0;
+                        for joint in humanoid.joints.iter() {
+                            let (frame, motors) = match joints.get(*joint) {
+                                Ok(j) => j,
+                                Err(_) => continue,
+                            };
+                            if let (Ok(parent), Ok(child)) =
+                                (bodies.get(frame.parent), bodies.get(*joint))
+                            {
+                                let positions = frame.positions(&motors.axes(), parent.0, child.0);
+                                for (motor, position) in motors.motors.iter().zip(positions) {
+                                    let [min, max] = motor.limits;
+                                    let margin = margin * (max - min);
+                                    if position < min + margin || position > max - margin {
+                                        count += 1;
+                                    }
+                                }
+                            }
+                        }
+                        count as f32
+                    }
+                    RewardTerm::Contact { parts: names } => {
+                        let touching: Vec<bool> = humanoid
+                            .parts
+                            .iter()
+                            .filter_map(|p| parts.get(*p).ok())
+                            .filter(|(name, _)| names.iter().any(|n| n.as_str() == name.as_str()))
+                            .map(|(_, children)| part_touching(children, &contacts))
+                            .collect();
+                        let count = touching.iter().filter(|t| **t).count();
+                        if count > 0 && count < touching.len() {
+                            1.
+                        } else {
+                            0.
+                        }
+                    }
+                };
+                weight * value
+            })
+            .collect();
+        step.reward = terms.iter().sum();
+        step.episode_reward += step.reward;
+        step.episode_reward_terms.resize(terms.len(), 0.);
+        for (sum, term) in step.episode_reward_terms.iter_mut().zip(terms.iter()) {
+            *sum += term;
+        }
+        step.reward_terms = terms;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+    use std::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn forward_velocity_follows_the_torso_heading() {
+        assert_close(forward_velocity(Quat::IDENTITY, Vec3::new(0., 0., 2.)), 2.);
+        assert_close(forward_velocity(Quat::IDENTITY, Vec3::new(3., 0., 0.)), 0.);
+        // facing +X
+        let turned = Quat::from_rotation_y(FRAC_PI_2);
+        assert_close(forward_velocity(turned, Vec3::new(3., 0., 0.)), 3.);
+        // leaning forward doesn't shrink the heading, falling isn't walking
+        let leaning = Quat::from_rotation_x(1.);
+        assert_close(forward_velocity(leaning, Vec3::new(0., 0., 1.)), 1.);
+        assert_close(forward_velocity(leaning, Vec3::new(0., -5., 0.)), 0.);
+    }
+
+    #[test]
+    fn config_loads_tagged_terms() {
+        let json = r#"{"terms": [
+            {"term": {"type": "forward_velocity"}, "weight": 1.0},
+            {"term": {"type": "height", "part": "head"}, "weight": 0.5},
+            {"term": {"type": "joint_limit", "margin": 0.1}, "weight": -0.1}
+        ]}"#;
+        let config: RewardConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            config.names(),
+            ["forward_velocity", "height", "joint_limit"]
+        );
+        assert_eq!(
+            config.terms[1].term,
+            RewardTerm::Height {
+                part: "head".to_string()
+            }
+        );
+        assert_eq!(config.terms[2].weight, -0.1);
+        let unknown = r#"{"terms": [{"term": {"type": "speed"}, "weight": 1}]}"#;
+        assert!(serde_json::from_str::<RewardConfig>(unknown).is_err());
+    }
+
+    #[test]
+    fn default_config_names_every_term_once() {
+        let names = RewardConfig::default().names();
+        assert_eq!(names.len(), 7);
+        for (i, name) in names.iter().enumerate() {
+            assert!(!names[..i].contains(name), "{} twice", name);
+        }
+    }
+}
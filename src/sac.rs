@@ -0,0 +1,352 @@
+use std::{error::Error, path::Path};
+
+use dfdx::prelude::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    action::{Action, ActionMode},
+    agent::Agent,
+    checkpoint::{load_net, reseed, save_net},
+    env::Observation,
+    nets::{
+        gaussian_forward, q_forward, squashed_sample, squashed_sample_single, standard_normal,
+        GaussianActor, QCritic, SoftUpdate, HUMANOID_ACT, LOG_STD_MAX, LOG_STD_MIN,
+    },
+    observation::{to_batch, to_tensor, HUMANOID_OBS},
+    optim::CheckpointedAdam,
+    replay::{ReplayBuffer, Transition},
+};
+
+const BATCH: usize = 256;
+
+pub type SacActor = GaussianActor<HUMANOID_OBS, HUMANOID_ACT>;
+pub type SacCritic = QCritic<HUMANOID_OBS, HUMANOID_ACT>;
+
+#[derive(Serialize)]
+pub struct SacConfig {
+    pub gamma: f32,
+    /// Polyak averaging factor of the target critics.
+    pub tau: f32,
+    pub actor_lr: f32,
+    pub critic_lr: f32,
+    pub alpha_lr: f32,
+    pub initial_alpha: f32,
+    /// Entropy the temperature is tuned towards, `-HUMANOID_ACT` by default.
+    pub target_entropy: f32,
+    pub replay_capacity: usize,
+    /// Transitions of uniform random actions before the policy acts and trains.
+    pub learning_starts: usize,
+    /// Joint torques instead of position targets, see `ActionMode::Torque`.
+    pub torque: bool,
+    pub seed: u64,
+}
+impl Default for SacConfig {
+    fn default() -> Self {
+        Self {
+            gamma: 0.99,
+            tau: 0.005,
+            actor_lr: 3e-4,
+            critic_lr: 3e-4,
+            alpha_lr: 3e-4,
+            initial_alpha: 0.2,
+            target_entropy: -(HUMANOID_ACT as f32),
+            replay_capacity: 300_000,
+            learning_starts: 10_000,
+            torque: false,
+            seed: 0,
+        }
+    }
+}
+
+/// Soft actor-critic over `ActionMode::Position` or `Torque`: tanh-squashed Gaussian actor,
+/// twin critics with Polyak averaged targets and a temperature tuned towards `target_entropy`.
+pub struct SacAgent {
+    pub config: SacConfig,
+    pub actor: SacActor,
+    pub critics: [SacCritic; 2],
+    pub target_critics: [SacCritic; 2],
+    pub actor_optimizer: CheckpointedAdam<SacActor>,
+    pub critic_optimizers: [CheckpointedAdam<SacCritic>; 2],
+    /// Temperature in log space, stepped by plain gradient descent.
+    pub log_alpha: f32,
+    pub replay: ReplayBuffer,
+    pub rng: StdRng,
+    pub train_steps: usize,
+}
+/// Checkpointed training state, the temperature included.
+#[derive(Serialize, Deserialize)]
+struct SacState {
+    seed: u64,
+    train_steps: usize,
+    log_alpha: f32,
+    /// Steps of the actor optimizer, then of the critic optimizers.
+    adam_t: [i32; 3],
+}
+
+impl SacAgent {
+    pub fn new(config: SacConfig) -> Self {
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let mut actor = SacActor::default();
+        actor.reset_params(&mut rng);
+        let mut critics = [SacCritic::default(), SacCritic::default()];
+        for critic in critics.iter_mut() {
+            critic.reset_params(&mut rng);
+        }
+        let critic_optimizer = || {
+            CheckpointedAdam::new(AdamConfig {
+                lr: config.critic_lr,
+                ..Default::default()
+            })
+        };
+        Self {
+            actor_optimizer: CheckpointedAdam::new(AdamConfig {
+                lr: config.actor_lr,
+                ..Default::default()
+            }),
+            critic_optimizers: [critic_optimizer(), critic_optimizer()],
+            log_alpha: config.initial_alpha.ln(),
+            replay: ReplayBuffer::new(config.replay_capacity),
+            target_critics: critics.clone(),
+            config,
+            actor,
+            critics,
+            rng,
+            train_steps: 0,
+        }
+    }
+
+    pub fn alpha(&self) -> f32 {
+        self.log_alpha.exp()
+    }
+
+    /// Mean and clamped log-std of the policy before squashing.
+    pub fn policy(&self, observation: &[f32]) -> ([f32; HUMANOID_ACT], [f32; HUMANOID_ACT]) {
+        assert_eq!(
+            observation.len(),
+            HUMANOID_OBS,
+            "SAC is sized for the default humanoid and observation config"
+        );
+        let observation = to_tensor::<HUMANOID_OBS>(observation);
+        let mean: Tensor1D<HUMANOID_ACT> = self.actor.0.forward(observation.clone());
+        let log_std: Tensor1D<HUMANOID_ACT> = self.actor.1.forward(observation);
+        let mut log_std = *log_std.data();
+        for s in log_std.iter_mut() {
+            *s = s.clamp(LOG_STD_MIN, LOG_STD_MAX);
+        }
+        (*mean.data(), log_std)
+    }
+
+    fn noise(&mut self) -> Vec<f32> {
+        (0..HUMANOID_ACT)
+            .map(|_| standard_normal(&mut self.rng))
+            .collect()
+    }
+
+    fn train_step(&mut self) -> (f32, f32) {
+        let batch: Vec<Transition> = self
+            .replay
+            .sample(&mut self.rng, BATCH)
+            .into_iter()
+            .cloned()
+            .collect();
+        let states: Vec<Observation> = batch.iter().map(|t| t.state.clone()).collect();
+        let next_states: Vec<Observation> = batch.iter().map(|t| t.next_state.clone()).collect();
+        let actions: Vec<Vec<f32>> = batch
+            .iter()
+            .map(|t| match &t.action {
+                Action::Continuous(a) => a.clone(),
+                Action::Discrete(_) => vec![0.; HUMANOID_ACT],
+            })
+            .collect();
+        let states = to_batch::<BATCH, HUMANOID_OBS>(&states);
+        let actions = to_batch::<BATCH, HUMANOID_ACT>(&actions);
+        let alpha = self.alpha();
+
+        // target = r + gamma * (min_i Q_target_i(s', a') - alpha * log pi(a'|s')), a' ~ pi(s')
+        let mut next_actions = vec![];
+        let mut next_log_probs = vec![];
+        for next_state in next_states.iter() {
+            let (mean, log_std) = self.policy(next_state);
+            let noise = self.noise();
+            let (action, log_prob) = squashed_sample_single(&mean, &log_std, &noise);
+            next_actions.push(action);
+            next_log_probs.push(log_prob);
+        }
+        let next_states = to_batch::<BATCH, HUMANOID_OBS>(&next_states);
+        let next_actions = to_batch::<BATCH, HUMANOID_ACT>(&next_actions);
+        let next_q = [0, 1].map(|i| {
+            q_forward(
+                &self.target_critics[i],
+                next_states.clone(),
+                next_actions.clone(),
+            )
+        });
+        let mut targets = [0.; BATCH];
+        for (i, t) in batch.iter().enumerate() {
+            let soft_q = next_q[0].data()[i].min(next_q[1].data()[i]) - alpha * next_log_probs[i];
+            targets[i] = match t.done {
+                true => t.reward,
+                false => t.reward + self.config.gamma * soft_q,
+            };
+        }
+        let targets = Tensor1D::new(targets);
+
+        let mut critic_loss = 0.;
+        for i in 0..2 {
+            let q = q_forward(&self.critics[i], states.clone(), actions.clone().trace());
+            let loss = mse_loss(q, &targets);
+            critic_loss += *loss.data() / 2.;
+            self.critic_optimizers[i]
+                .update(&mut self.critics[i], loss.backward())
+                .expect("unused params");
+        }
+
+        // actor loss = alpha * log pi(a|s) - min_i Q_i(s, a), a reparameterized
+        let mut noise = [[0.; HUMANOID_ACT]; BATCH];
+        for row in noise.iter_mut() {
+            row.copy_from_slice(&self.noise());
+        }
+        let (mu, log_std) = gaussian_forward(&self.actor, states.clone().trace());
+        let (sampled, log_probs) = squashed_sample(mu, log_std, noise);
+        let mean_log_prob = log_probs.data().iter().sum::<f32>() / BATCH as f32;
+        let (log_probs, tape) = log_probs.split_tape();
+        let (q0, tape) = q_forward(
+            &self.critics[0],
+            states.clone(),
+            sampled.duplicate().put_tape(tape),
+        )
+        .split_tape();
+        let q1 = q_forward(&self.critics[1], states, sampled.put_tape(tape));
+        // -alpha * mean(min Q / alpha - log pi), only the tape holder may be scaled
+        let soft_q = sub(div_scalar(minimum(q1, &q0), alpha), &log_probs);
+        let actor_loss = mul_scalar(mean(soft_q), -alpha);
+        let actor_loss_value = *actor_loss.data();
+        self.actor_optimizer
+            .update(&mut self.actor, actor_loss.backward())
+            .expect("unused params");
+
+        // d/d log_alpha of -log_alpha * (log pi + target_entropy)
+        self.log_alpha += self.config.alpha_lr * (mean_log_prob + self.config.target_entropy);
+
+        for i in 0..2 {
+            self.target_critics[i].soft_update(&self.critics[i], self.config.tau);
+        }
+        self.train_steps += 1;
+        (critic_loss, actor_loss_value)
+    }
+}
+
+impl Agent for SacAgent {
+    fn name(&self) -> &'static str {
+        "sac"
+    }
+
+    fn config(&self) -> serde_json::Value {
+        serde_json::to_value(&self.config).unwrap()
+    }
+
+    fn action_mode(&self) -> ActionMode {
+        ActionMode::continuous(self.config.torque)
+    }
+
+    fn act(&mut self, observations: &[Observation]) -> Vec<Action> {
+        let mut actions = vec![];
+        for observation in observations {
+            let action = match self.replay.len() < self.config.learning_starts {
+                true => (0..HUMANOID_ACT)
+                    .map(|_| self.rng.gen_range(-1.0..1.0))
+                    .collect(),
+                false => {
+                    let (mean, log_std) = self.policy(observation);
+                    let noise = self.noise();
+                    squashed_sample_single(&mean, &log_std, &noise).0
+                }
+            };
+            actions.push(Action::Continuous(action));
+        }
+        actions
+    }
+
+    fn act_deterministic(&mut self, observations: &[Observation]) -> Vec<Action> {
+        observations
+            .iter()
+            .map(|observation| {
+                let (mean, _) = self.policy(observation);
+                Action::Continuous(mean.iter().map(|m| m.tanh()).collect())
+            })
+            .collect()
+    }
+
+    fn observe(&mut self, transitions: Vec<(usize, Transition)>) {
+        for (_, transition) in transitions {
+            self.replay.push(transition);
+        }
+        if self.replay.len() < self.config.learning_starts {
+            return;
+        }
+        let (critic_loss, actor_loss) = self.train_step();
+        if self.train_steps % 1000 == 0 {
+            println!(
+                "sac train step {}: critic loss {:.4}, actor loss {:.4}, alpha {:.3}, replay {}",
+                self.train_steps,
+                critic_loss,
+                actor_loss,
+                self.alpha(),
+                self.replay.len()
+            );
+        }
+    }
+
+    fn replay_buffer(&mut self) -> Option<&mut ReplayBuffer> {
+        Some(&mut self.replay)
+    }
+
+    fn save(&mut self, dir: &Path) -> Result<serde_json::Value, Box<dyn Error>> {
+        save_net(dir, "actor", &self.actor)?;
+        self.actor_optimizer.save(dir, "actor_adam")?;
+        for i in 0..2 {
+            save_net(dir, &format!("critic_{}", i), &self.critics[i])?;
+            save_net(
+                dir,
+                &format!("target_critic_{}", i),
+                &self.target_critics[i],
+            )?;
+            self.critic_optimizers[i].save(dir, &format!("critic_{}_adam", i))?;
+        }
+        Ok(serde_json::to_value(SacState {
+            seed: reseed(&mut self.rng),
+            train_steps: self.train_steps,
+            log_alpha: self.log_alpha,
+            adam_t: [
+                self.actor_optimizer.t,
+                self.critic_optimizers[0].t,
+                self.critic_optimizers[1].t,
+            ],
+        })?)
+    }
+
+    fn load(&mut self, dir: &Path, state: serde_json::Value) -> Result<(), Box<dyn Error>> {
+        let state: SacState = serde_json::from_value(state)?;
+        load_net(dir, "actor", &mut self.actor)?;
+        self.actor_optimizer
+            .load(dir, "actor_adam", state.adam_t[0])?;
+        for i in 0..2 {
+            load_net(dir, &format!("critic_{}", i), &mut self.critics[i])?;
+            load_net(
+                dir,
+                &format!("target_critic_{}", i),
+                &mut self.target_critics[i],
+            )?;
+            self.critic_optimizers[i].load(
+                dir,
+                &format!("critic_{}_adam", i),
+                state.adam_t[1 + i],
+            )?;
+        }
+        self.rng = StdRng::seed_from_u64(state.seed);
+        self.train_steps = state.train_steps;
+        self.log_alpha = state.log_alpha;
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use bevy::prelude::*;
+use bevy_rapier3d::rapier::prelude::JointAxis;
+use serde::Deserialize;
+
+use crate::{humanoid::BodySize, mjcf, urdf};
+
+pub const HUMANOID_SKELETON_PATH: &str = "assets/humanoid.json";
+
+/// Morphology loaded at startup. Parts form a tree through `parent` names,
+/// the single part without a parent is the root (the torso for the humanoid).
+/// The rest pose faces `humanoid::FORWARD`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SkeletonDesc {
+    pub parts: Vec<PartDesc>,
+    /// Root part position at spawn, 1.2 above the origin when absent, see `get_spawn_transform`.
+    #[serde(default)]
+    pub root_position: Option<[f32; 3]>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PartDesc {
+    pub name: String,
+    pub geoms: Vec<GeomDesc>,
+    #[serde(default)]
+    pub mass: f32,
+    #[serde(default)]
+    pub inertia: [f32; 3],
+    /// Mass properties computed by rapier from the geoms, `mass` and `inertia` are ignored.
+    #[serde(default)]
+    pub density: Option<f32>,
+    pub color: [f32; 4],
+    #[serde(default)]
+    pub damping: Option<f32>,
+    /// Spawn a left and a right copy, mirrored along X with `get_dir_unit`.
+    #[serde(default)]
+    pub mirror: bool,
+    #[serde(default)]
+    pub parent: Option<String>,
+    #[serde(default)]
+    pub joint: Option<JointDesc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ShapeDesc {
+    RoundBox(BodySize),
+    /// Capsule along the geom Y axis.
+    Capsule { half_height: f32, radius: f32 },
+    Sphere { radius: f32 },
+    /// Cylinder along the geom Y axis.
+    Cylinder { half_height: f32, radius: f32 },
+    /// Wavefront OBJ file, collides as its convex hull.
+    Mesh { path: String, scale: [f32; 3] },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GeomDesc {
+    pub shape: ShapeDesc,
+    /// Geom offset in the part frame.
+    #[serde(default)]
+    pub translation: [f32; 3],
+    /// Quaternion as [x, y, z, w].
+    #[serde(default = "default_rotation")]
+    pub rotation: [f32; 4],
+}
+fn default_rotation() -> [f32; 4] {
+    [0., 0., 0., 1.]
+}
+impl GeomDesc {
+    pub fn transform(&self) -> Transform {
+        Transform::from_translation(Vec3::from(self.translation))
+            .with_rotation(Quat::from_array(self.rotation))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct JointDesc {
+    /// Joint position in the parent part frame.
+    pub anchor1: [f32; 3],
+    /// Joint position in the child part frame.
+    pub anchor2: [f32; 3],
+    /// Direction of ANG_X in both part frames, ANG_Y and ANG_Z follow it.
+    #[serde(default = "default_axis")]
+    pub axis: [f32; 3],
+    /// Free axes, every other axis is locked.
+    #[serde(default)]
+    pub axes: Vec<AxisDesc>,
+}
+
+fn default_axis() -> [f32; 3] {
+    [1., 0., 0.]
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AxisDesc {
+    pub axis: JointAxis,
+    /// Min and max for the `get_dir_unit(0)` side, degrees for angular axes and meters for linear ones.
+    #[serde(default)]
+    pub limits: Option<[f32; 2]>,
+    pub stiffness: f32,
+    pub damping: f32,
+    pub max_force: f32,
+}
+
+impl SkeletonDesc {
+    /// Loads a JSON description, or imports MJCF for `.xml` and URDF for `.urdf` files.
+    pub fn load(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        if path.ends_with(".xml") {
+            return mjcf::load(path);
+        }
+        if path.ends_with(".urdf") {
+            return urdf::load(path);
+        }
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+    pub fn root(&self) -> &PartDesc {
+        self.parts
+            .iter()
+            .find(|p| p.parent.is_none())
+            .expect("skeleton has no root part")
+    }
+    pub fn children<'a>(&'a self, parent: &'a str) -> impl Iterator<Item = &'a PartDesc> {
+        self.parts
+            .iter()
+            .filter(move |p| p.parent.as_deref() == Some(parent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{humanoid::FORWARD, observation::HUMANOID_DOF};
+
+    #[test]
+    fn humanoid_json_forms_a_tree() {
+        let skeleton = SkeletonDesc::load(HUMANOID_SKELETON_PATH).unwrap();
+        assert_eq!(skeleton.root().name, "body");
+        assert_eq!(skeleton.root_position, None);
+        for part in skeleton.parts.iter() {
+            if let Some(parent) = &part.parent {
+                assert!(skeleton.parts.iter().any(|p| &p.name == parent));
+            }
+            assert_eq!(part.parent.is_some(), part.joint.is_some());
+        }
+        let children: Vec<&str> = skeleton.children("body").map(|p| p.name.as_str()).collect();
+        assert_eq!(children, ["head", "femur", "upperarm"]);
+    }
+
+    #[test]
+    fn humanoid_json_has_the_agent_dof() {
+        let skeleton = SkeletonDesc::load(HUMANOID_SKELETON_PATH).unwrap();
+        let dof: usize = skeleton
+            .parts
+            .iter()
+            .filter_map(|p| Some(p.joint.as_ref()?.axes.len() * if p.mirror { 2 } else { 1 }))
+            .sum();
+        assert_eq!(dof, HUMANOID_DOF);
+    }
+
+    #[test]
+    fn humanoid_json_faces_forward() {
+        let skeleton = SkeletonDesc::load(HUMANOID_SKELETON_PATH).unwrap();
+        let joint = |name: &str| {
+            let part = skeleton.parts.iter().find(|p| p.name == name).unwrap();
+            part.joint.clone().unwrap()
+        };
+        // the ankle sits at the heel, the toes point forward
+        assert!(Vec3::from(joint("foot").anchor2).dot(FORWARD) < 0.);
+        // where the lower end of a limb hanging down swings at a limit of its first axis
+        let swing = |name: &str, limit: usize| {
+            let joint = joint(name);
+            let angle = joint.axes[0].limits.unwrap()[limit].to_radians();
+            Quat::from_axis_angle(Vec3::from(joint.axis), angle) * Vec3::NEG_Y
+        };
+        // bent knees point forward, bent elbows raise the forearm forward
+        assert!(swing("tibia", 1).dot(FORWARD) < 0.);
+        assert!(swing("forearm", 0).dot(FORWARD) > 0.);
+    }
+
+    #[test]
+    fn load_fails_on_missing_files() {
+        assert!(SkeletonDesc::load("assets/missing.json").is_err());
+        assert!(SkeletonDesc::load("assets/missing.xml").is_err());
+        assert!(SkeletonDesc::load("assets/missing.urdf").is_err());
+    }
+}
@@ -0,0 +1,331 @@
+use std::{error::Error, path::Path};
+
+use dfdx::prelude::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    action::{Action, ActionMode},
+    agent::Agent,
+    checkpoint::{load_net, reseed, save_net},
+    env::Observation,
+    nets::{q_forward, standard_normal, Mlp, QCritic, SoftUpdate, HUMANOID_ACT},
+    observation::{to_batch, to_tensor, HUMANOID_OBS},
+    optim::CheckpointedAdam,
+    replay::{ReplayBuffer, Transition},
+};
+
+const BATCH: usize = 256;
+
+pub type Td3Actor = (Mlp<HUMANOID_OBS, HUMANOID_ACT>, Tanh);
+pub type Td3Critic = QCritic<HUMANOID_OBS, HUMANOID_ACT>;
+
+#[derive(Serialize)]
+pub struct Td3Config {
+    pub gamma: f32,
+    /// Polyak averaging factor of the target networks.
+    pub tau: f32,
+    pub actor_lr: f32,
+    pub critic_lr: f32,
+    /// Std of the Gaussian noise added to actions while acting.
+    pub exploration_noise: f32,
+    /// Std of the target policy smoothing noise, clipped to `noise_clip`.
+    pub target_noise: f32,
+    pub noise_clip: f32,
+    /// Critic updates per actor and target network update.
+    pub policy_delay: usize,
+    /// Clipped double Q: two critics, targets from their minimum.
+    pub twin_critics: bool,
+    pub replay_capacity: usize,
+    /// Transitions of uniform random actions before the policy acts and trains.
+    pub learning_starts: usize,
+    /// Joint torques instead of position targets, see `ActionMode::Torque`.
+    pub torque: bool,
+    pub seed: u64,
+}
+impl Default for Td3Config {
+    fn default() -> Self {
+        Self {
+            gamma: 0.99,
+            tau: 0.005,
+            actor_lr: 3e-4,
+            critic_lr: 3e-4,
+            exploration_noise: 0.1,
+            target_noise: 0.2,
+            noise_clip: 0.5,
+            policy_delay: 2,
+            twin_critics: true,
+            replay_capacity: 300_000,
+            learning_starts: 10_000,
+            torque: false,
+            seed: 0,
+        }
+    }
+}
+impl Td3Config {
+    /// DDPG: one critic, no target policy smoothing, actor updated every step.
+    pub fn ddpg() -> Self {
+        Self {
+            target_noise: 0.,
+            noise_clip: 0.,
+            policy_delay: 1,
+            twin_critics: false,
+            ..Default::default()
+        }
+    }
+}
+
+/// Twin delayed deep deterministic policy gradient over `ActionMode::Position` or `Torque`:
+/// tanh actor, clipped double Q, target policy smoothing and delayed actor updates.
+/// `Td3Config::ddpg` turns it into plain DDPG.
+pub struct Td3Agent {
+    pub config: Td3Config,
+    pub actor: Td3Actor,
+    pub target_actor: Td3Actor,
+    /// One critic, two with `twin_critics`.
+    pub critics: Vec<Td3Critic>,
+    pub target_critics: Vec<Td3Critic>,
+    pub actor_optimizer: CheckpointedAdam<Td3Actor>,
+    pub critic_optimizers: Vec<CheckpointedAdam<Td3Critic>>,
+    pub replay: ReplayBuffer,
+    pub rng: StdRng,
+    pub train_steps: usize,
+}
+/// Checkpointed training state, the actor update delay follows from `train_steps`.
+#[derive(Serialize, Deserialize)]
+struct Td3State {
+    seed: u64,
+    train_steps: usize,
+    /// Steps of the actor optimizer, then of the critic optimizers.
+    adam_t: Vec<i32>,
+}
+
+impl Td3Agent {
+    pub fn new(config: Td3Config) -> Self {
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let mut actor = Td3Actor::default();
+        actor.reset_params(&mut rng);
+        let mut critics = vec![];
+        let mut critic_optimizers = vec![];
+        for _ in 0..if config.twin_critics { 2 } else { 1 } {
+            let mut critic = Td3Critic::default();
+            critic.reset_params(&mut rng);
+            critics.push(critic);
+            critic_optimizers.push(CheckpointedAdam::new(AdamConfig {
+                lr: config.critic_lr,
+                ..Default::default()
+            }));
+        }
+        Self {
+            actor_optimizer: CheckpointedAdam::new(AdamConfig {
+                lr: config.actor_lr,
+                ..Default::default()
+            }),
+            replay: ReplayBuffer::new(config.replay_capacity),
+            config,
+            target_actor: actor.clone(),
+            actor,
+            target_critics: critics.clone(),
+            critics,
+            critic_optimizers,
+            rng,
+            train_steps: 0,
+        }
+    }
+
+    pub fn policy(&self, observation: &[f32]) -> [f32; HUMANOID_ACT] {
+        assert_eq!(
+            observation.len(),
+            HUMANOID_OBS,
+            "TD3 is sized for the default humanoid and observation config"
+        );
+        let action: Tensor1D<HUMANOID_ACT> =
+            self.actor.forward(to_tensor::<HUMANOID_OBS>(observation));
+        *action.data()
+    }
+
+    fn train_step(&mut self) -> (f32, Option<f32>) {
+        let batch: Vec<Transition> = self
+            .replay
+            .sample(&mut self.rng, BATCH)
+            .into_iter()
+            .cloned()
+            .collect();
+        let states: Vec<Observation> = batch.iter().map(|t| t.state.clone()).collect();
+        let next_states: Vec<Observation> = batch.iter().map(|t| t.next_state.clone()).collect();
+        let actions: Vec<Vec<f32>> = batch
+            .iter()
+            .map(|t| match &t.action {
+                Action::Continuous(a) => a.clone(),
+                Action::Discrete(_) => vec![0.; HUMANOID_ACT],
+            })
+            .collect();
+        let states = to_batch::<BATCH, HUMANOID_OBS>(&states);
+        let actions = to_batch::<BATCH, HUMANOID_ACT>(&actions);
+        let next_states = to_batch::<BATCH, HUMANOID_OBS>(&next_states);
+
+        // target = r + gamma * min_i Q_target_i(s', clip(pi_target(s') + clip(noise)))
+        let mut next_actions: Tensor2D<BATCH, HUMANOID_ACT> =
+            self.target_actor.forward(next_states.clone());
+        for a in next_actions.mut_data().iter_mut().flatten() {
+            let noise = (self.config.target_noise * standard_normal(&mut self.rng))
+                .clamp(-self.config.noise_clip, self.config.noise_clip);
+            *a = (*a + noise).clamp(-1., 1.);
+        }
+        let next_q: Vec<Tensor1D<BATCH>> = self
+            .target_critics
+            .iter()
+            .map(|critic| q_forward(critic, next_states.clone(), next_actions.clone()))
+            .collect();
+        let mut targets = [0.; BATCH];
+        for (i, t) in batch.iter().enumerate() {
+            let min_q = next_q.iter().map(|q| q.data()[i]).fold(f32::MAX, f32::min);
+            targets[i] = match t.done {
+                true => t.reward,
+                false => t.reward + self.config.gamma * min_q,
+            };
+        }
+        let targets = Tensor1D::new(targets);
+
+        let mut critic_loss = 0.;
+        for (critic, optimizer) in self
+            .critics
+            .iter_mut()
+            .zip(self.critic_optimizers.iter_mut())
+        {
+            let q = q_forward(critic, states.clone(), actions.clone().trace());
+            let loss = mse_loss(q, &targets);
+            critic_loss += *loss.data();
+            optimizer
+                .update(critic, loss.backward())
+                .expect("unused params");
+        }
+        critic_loss /= self.critics.len() as f32;
+        self.train_steps += 1;
+        if self.train_steps % self.config.policy_delay != 0 {
+            return (critic_loss, None);
+        }
+
+        // actor loss = -Q_0(s, pi(s))
+        let policy_actions: Tensor2D<BATCH, HUMANOID_ACT, OwnedTape> =
+            self.actor.forward(states.clone().trace());
+        let loss = negate(mean(q_forward(&self.critics[0], states, policy_actions)));
+        let actor_loss = *loss.data();
+        self.actor_optimizer
+            .update(&mut self.actor, loss.backward())
+            .expect("unused params");
+
+        self.target_actor.soft_update(&self.actor, self.config.tau);
+        for (target, critic) in self.target_critics.iter_mut().zip(self.critics.iter()) {
+            target.soft_update(critic, self.config.tau);
+        }
+        (critic_loss, Some(actor_loss))
+    }
+}
+
+impl Agent for Td3Agent {
+    fn name(&self) -> &'static str {
+        match self.config.twin_critics {
+            true => "td3",
+            false => "ddpg",
+        }
+    }
+
+    fn config(&self) -> serde_json::Value {
+        serde_json::to_value(&self.config).unwrap()
+    }
+
+    fn action_mode(&self) -> ActionMode {
+        ActionMode::continuous(self.config.torque)
+    }
+
+    fn act(&mut self, observations: &[Observation]) -> Vec<Action> {
+        let mut actions = vec![];
+        for observation in observations {
+            let action = match self.replay.len() < self.config.learning_starts {
+                true => (0..HUMANOID_ACT)
+                    .map(|_| self.rng.gen_range(-1.0..1.0))
+                    .collect(),
+                false => self
+                    .policy(observation)
+                    .iter()
+                    .map(|a| {
+                        let noise = self.config.exploration_noise * standard_normal(&mut self.rng);
+                        (a + noise).clamp(-1., 1.)
+                    })
+                    .collect(),
+            };
+            actions.push(Action::Continuous(action));
+        }
+        actions
+    }
+
+    fn act_deterministic(&mut self, observations: &[Observation]) -> Vec<Action> {
+        observations
+            .iter()
+            .map(|observation| Action::Continuous(self.policy(observation).to_vec()))
+            .collect()
+    }
+
+    fn observe(&mut self, transitions: Vec<(usize, Transition)>) {
+        for (_, transition) in transitions {
+            self.replay.push(transition);
+        }
+        if self.replay.len() < self.config.learning_starts {
+            return;
+        }
+        let (critic_loss, actor_loss) = self.train_step();
+        if self.train_steps % 1000 == 0 {
+            println!(
+                "td3 train step {}: critic loss {:.4}, actor loss {:.4}, replay {}",
+                self.train_steps,
+                critic_loss,
+                actor_loss.unwrap_or(f32::NAN),
+                self.replay.len()
+            );
+        }
+    }
+
+    fn replay_buffer(&mut self) -> Option<&mut ReplayBuffer> {
+        Some(&mut self.replay)
+    }
+
+    fn save(&mut self, dir: &Path) -> Result<serde_json::Value, Box<dyn Error>> {
+        save_net(dir, "actor", &self.actor)?;
+        save_net(dir, "target_actor", &self.target_actor)?;
+        self.actor_optimizer.save(dir, "actor_adam")?;
+        let mut adam_t = vec![self.actor_optimizer.t];
+        for (i, (critic, target)) in self.critics.iter().zip(&self.target_critics).enumerate() {
+            save_net(dir, &format!("critic_{}", i), critic)?;
+            save_net(dir, &format!("target_critic_{}", i), target)?;
+            self.critic_optimizers[i].save(dir, &format!("critic_{}_adam", i))?;
+            adam_t.push(self.critic_optimizers[i].t);
+        }
+        Ok(serde_json::to_value(Td3State {
+            seed: reseed(&mut self.rng),
+            train_steps: self.train_steps,
+            adam_t,
+        })?)
+    }
+
+    fn load(&mut self, dir: &Path, state: serde_json::Value) -> Result<(), Box<dyn Error>> {
+        let state: Td3State = serde_json::from_value(state)?;
+        if state.adam_t.len() != 1 + self.critics.len() {
+            return Err("checkpoint of a different number of critics".into());
+        }
+        load_net(dir, "actor", &mut self.actor)?;
+        load_net(dir, "target_actor", &mut self.target_actor)?;
+        self.actor_optimizer
+            .load(dir, "actor_adam", state.adam_t[0])?;
+        let critics = self.critics.iter_mut().zip(self.target_critics.iter_mut());
+        for (i, (critic, target)) in critics.enumerate() {
+            load_net(dir, &format!("critic_{}", i), critic)?;
+            load_net(dir, &format!("target_critic_{}", i), target)?;
+            let optimizer = &mut self.critic_optimizers[i];
+            optimizer.load(dir, &format!("critic_{}_adam", i), state.adam_t[1 + i])?;
+        }
+        self.rng = StdRng::seed_from_u64(state.seed);
+        self.train_steps = state.train_steps;
+        Ok(())
+    }
+}
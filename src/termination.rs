@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+use crate::{
+    env::{EnvIndex, EnvStep, FALLEN_HEIGHT},
+    ground::Ground,
+    humanoid::Humanoid,
+    reward::RewardConfig,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminationReason {
+    /// Torso below `TerminationConfig::fallen_height`.
+    Fallen,
+    /// One of `TerminationConfig::ground_contact_parts` touched the ground.
+    GroundContact,
+    /// `TerminationConfig::max_steps` reached, the episode is truncated rather than failed.
+    Timeout,
+    /// NaN or infinite transforms or velocities, the solver blew up.
+    Unstable,
+}
+
+pub struct TerminationConfig {
+    pub fallen_height: f32,
+    pub ground_contact_parts: Vec<String>,
+    /// 0 for no limit.
+    pub max_steps: usize,
+}
+impl Default for TerminationConfig {
+    fn default() -> Self {
+        Self {
+            fallen_height: FALLEN_HEIGHT,
+            ground_contact_parts: vec!["body".to_string(), "head".to_string()],
+            max_steps: 1000,
+        }
+    }
+}
+
+/// Sent once per episode, when a humanoid terminates.
+#[derive(Debug, Clone)]
+pub struct EpisodeEnded {
+    pub humanoid: Entity,
+    pub env: usize,
+    pub reason: TerminationReason,
+    pub steps: usize,
+    /// Undiscounted sum of the step rewards.
+    pub reward: f32,
+    /// Undiscounted sum of every weighted term of `RewardConfig`, in its order.
+    pub reward_terms: Vec<f32>,
+}
+
+fn is_unstable(transform: &Transform, velocity: &Velocity) -> bool {
+    !(transform.translation.is_finite()
+        && transform.rotation.is_finite()
+        && velocity.linvel.is_finite()
+        && velocity.angvel.is_finite())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn termination_system(
+    config: Res<TerminationConfig>,
+    mut collisions: EventReader<CollisionEvent>,
+    mut ended: EventWriter<EpisodeEnded>,
+    mut roots: Query<(Entity, &EnvIndex, &Humanoid, &mut EnvStep)>,
+    bodies: Query<(&Transform, &Velocity)>,
+    names: Query<&Name>,
+    parents: Query<&Parent>,
+    grounds: Query<&Ground>,
+) {
+    // collider entities are children of the parts
+    let touching_ground: Vec<Entity> = collisions
+        .iter()
+        .filter_map(|event| match event {
+            CollisionEvent::Started(e1, e2, _) => match (grounds.contains(*e1), grounds.contains(*e2)) {
+                (true, false) => Some(*e2),
+                (false, true) => Some(*e1),
+                _ => None,
+            },
+            CollisionEvent::Stopped(..) => None,
+        })
+        .filter_map(|collider| parents.get(collider).ok().map(|p| p.get()))
+        .filter(|part| {
+            names.get(*part).map_or(false, |name| {
+                config
+                    .ground_contact_parts
+                    .iter()
+                    .any(|n| n.as_str() == name.as_str())
+            })
+        })
+        .collect();
+    for (root, index, humanoid, mut step) in roots.iter_mut() {
+        if step.done {
+            continue;
+        }
+        let torso = match bodies.get(humanoid.parts[0]) {
+            Ok((transform, _)) => transform,
+            Err(_) => continue,
+        };
+        let reason = if humanoid
+            .parts
+            .iter()
+            .filter_map(|p| bodies.get(*p).ok())
+            .any(|(t, v)| is_unstable(t, v))
+        {
+            Some(TerminationReason::Unstable)
+        } else if humanoid.parts.iter().any(|p| touching_ground.contains(p)) {
+            Some(TerminationReason::GroundContact)
+        } else if torso.translation.y < config.fallen_height {
+            Some(TerminationReason::Fallen)
+        } else if config.max_steps > 0 && step.steps >= config.max_steps {
+            Some(TerminationReason::Timeout)
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            step.done = true;
+            step.termination = Some(reason);
+            ended.send(EpisodeEnded {
+                humanoid: root,
+                env: index.0,
+                reason,
+                steps: step.steps,
+                reward: step.episode_reward,
+                reward_terms: step.episode_reward_terms.clone(),
+            });
+        }
+    }
+}
+
+pub fn episode_log_system(config: Res<RewardConfig>, mut events: EventReader<EpisodeEnded>) {
+    for e in events.iter() {
+        let terms: Vec<String> = config
+            .names()
+            .iter()
+            .zip(e.reward_terms.iter())
+            .map(|(name, value)| format!("{} {:.2}", name, value))
+            .collect();
+        println!(
+            "env {} episode ended: {:?} after {} steps, reward {:.2} ({})",
+            e.env,
+            e.reason,
+            e.steps,
+            e.reward,
+            terms.join(", ")
+        );
+    }
+}
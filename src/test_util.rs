@@ -0,0 +1,39 @@
+//! Assertions shared by the unit tests.
+
+use std::fmt::Debug;
+
+/// Floats compared by `assert_close`, a single value or a slice of them.
+pub trait Floats: Debug {
+    fn floats(&self) -> &[f32];
+}
+impl Floats for f32 {
+    fn floats(&self) -> &[f32] {
+        std::slice::from_ref(self)
+    }
+}
+impl Floats for &[f32] {
+    fn floats(&self) -> &[f32] {
+        self
+    }
+}
+impl<const N: usize> Floats for &[f32; N] {
+    fn floats(&self) -> &[f32] {
+        *self
+    }
+}
+impl Floats for &Vec<f32> {
+    fn floats(&self) -> &[f32] {
+        self
+    }
+}
+
+/// Asserts `a` and `b` have the same length and equal elements up to rounding,
+/// relative to the magnitude of values above 1.
+pub fn assert_close(a: impl Floats, b: impl Floats) {
+    let (x, y) = (a.floats(), b.floats());
+    let close = x.len() == y.len()
+        && x.iter()
+            .zip(y)
+            .all(|(x, y)| (x - y).abs() <= 1e-5 * x.abs().max(y.abs()).max(1.));
+    assert!(close, "{:?} != {:?}", a, b);
+}
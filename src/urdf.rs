@@ -0,0 +1,429 @@
+//! Importer for URDF robot models: links with box/cylinder/sphere/mesh geometry
+//! and revolute, continuous, prismatic and fixed joints with limits and effort.
+//! Link frames are folded into the root orientation so the rest pose matches
+//! the `SkeletonDesc` convention of parts aligned with their parent.
+use bevy::prelude::*;
+use bevy_rapier3d::rapier::prelude::JointAxis;
+use roxmltree::{Document, Node};
+use std::{error::Error, path::Path};
+
+use crate::{
+    humanoid::BodySize,
+    skeleton::{AxisDesc, GeomDesc, JointDesc, PartDesc, ShapeDesc, SkeletonDesc},
+};
+
+const DEFAULT_DENSITY: f32 = 1000.;
+const DEFAULT_RGBA: [f32; 4] = [0.7, 0.7, 0.7, 1.];
+
+struct Context<'a, 'input> {
+    dir: &'a Path,
+    links: Vec<Node<'a, 'input>>,
+    joints: Vec<Node<'a, 'input>>,
+}
+
+pub fn load(path: &str) -> Result<SkeletonDesc, Box<dyn Error>> {
+    let xml = std::fs::read_to_string(path)?;
+    let dir = Path::new(path).parent().unwrap_or_else(|| Path::new("."));
+    parse(&xml, dir)
+}
+
+/// Mesh filenames are resolved relative to `dir`, `package://name/` prefixes are dropped.
+/// Only existing `.obj` meshes are imported, other meshes are skipped like unsupported geometry.
+pub fn parse(xml: &str, dir: &Path) -> Result<SkeletonDesc, Box<dyn Error>> {
+    let doc = Document::parse(xml)?;
+    let robot = doc.root_element();
+    let ctx = Context {
+        dir,
+        links: robot.children().filter(|n| n.has_tag_name("link")).collect(),
+        joints: robot.children().filter(|n| n.has_tag_name("joint")).collect(),
+    };
+    let root = ctx
+        .links
+        .iter()
+        .find(|link| {
+            let name = link.attribute("name");
+            !ctx.joints
+                .iter()
+                .any(|j| child(*j, "child").and_then(|c| c.attribute("link")) == name)
+        })
+        .ok_or("URDF has no root link")?;
+    let mut parts: Vec<PartDesc> = vec![];
+    // URDF is Z-up, bevy is Y-up.
+    let z_up_to_y_up = Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2);
+    parse_link(*root, None, z_up_to_y_up, &ctx, &mut parts)?;
+    Ok(SkeletonDesc {
+        parts,
+        root_position: None,
+    })
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, tag: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|n| n.has_tag_name(tag))
+}
+
+fn floats(value: Option<&str>) -> Vec<f32> {
+    value
+        .map(|v| v.split_whitespace().filter_map(|f| f.parse().ok()).collect())
+        .unwrap_or_default()
+}
+
+fn vec3(value: Option<&str>) -> Option<Vec3> {
+    match floats(value)[..] {
+        [x, y, z] => Some(Vec3::new(x, y, z)),
+        _ => None,
+    }
+}
+
+/// `<origin xyz rpy>` of an element, identity when absent.
+fn origin(node: Node) -> (Vec3, Quat) {
+    match child(node, "origin") {
+        Some(o) => {
+            let xyz = vec3(o.attribute("xyz")).unwrap_or(Vec3::ZERO);
+            let rpy = vec3(o.attribute("rpy")).unwrap_or(Vec3::ZERO);
+            (xyz, Quat::from_euler(EulerRot::ZYX, rpy.z, rpy.y, rpy.x))
+        }
+        None => (Vec3::ZERO, Quat::IDENTITY),
+    }
+}
+
+/// `rest` rotates the link frame into the root aligned part frame. `parent` is the parent
+/// link name with the joint to it, parsed in the parent frame.
+fn parse_link(
+    link: Node,
+    parent: Option<(&str, JointDesc)>,
+    rest: Quat,
+    ctx: &Context,
+    parts: &mut Vec<PartDesc>,
+) -> Result<(), Box<dyn Error>> {
+    let name = link.attribute("name").ok_or("URDF link has no name")?;
+    let shapes: Vec<Node> = {
+        let collisions: Vec<Node> = link
+            .children()
+            .filter(|n| n.has_tag_name("collision"))
+            .collect();
+        if collisions.is_empty() {
+            link.children()
+                .filter(|n| n.has_tag_name("visual"))
+                .collect()
+        } else {
+            collisions
+        }
+    };
+    let geoms: Vec<GeomDesc> = shapes
+        .iter()
+        .filter_map(|s| parse_geom(*s, rest, ctx))
+        .collect();
+    if geoms.is_empty() {
+        return Err(format!("link {} has no supported geometry", name).into());
+    }
+    let color = link
+        .descendants()
+        .find(|n| n.has_tag_name("color"))
+        .and_then(|c| match floats(c.attribute("rgba"))[..] {
+            [r, g, b, a] => Some([r, g, b, a]),
+            _ => None,
+        })
+        .unwrap_or(DEFAULT_RGBA);
+    let inertial = child(link, "inertial");
+    let mass = inertial
+        .and_then(|i| child(i, "mass"))
+        .and_then(|m| floats(m.attribute("value")).first().copied());
+    let inertia = inertial.and_then(|i| child(i, "inertia")).map(|i| {
+        let diagonal = ["ixx", "iyy", "izz"]
+            .map(|a| floats(i.attribute(a)).first().copied().unwrap_or(0.));
+        let r = Mat3::from_quat(rest);
+        [0, 1, 2].map(|row| {
+            (0..3)
+                .map(|col| r.col(col)[row].powi(2) * diagonal[col])
+                .sum::<f32>()
+        })
+    });
+    parts.push(PartDesc {
+        name: name.to_string(),
+        geoms,
+        mass: mass.unwrap_or(0.),
+        inertia: inertia.unwrap_or([0.; 3]),
+        density: match mass {
+            Some(_) => None,
+            None => Some(DEFAULT_DENSITY),
+        },
+        color,
+        damping: None,
+        mirror: false,
+        parent: parent.as_ref().map(|(p, _)| p.to_string()),
+        joint: parent.map(|(_, joint)| joint),
+    });
+    for joint in ctx
+        .joints
+        .iter()
+        .filter(|j| child(**j, "parent").and_then(|p| p.attribute("link")) == Some(name))
+    {
+        let child_name = child(*joint, "child")
+            .and_then(|c| c.attribute("link"))
+            .ok_or("URDF joint has no child link")?;
+        let child_link = ctx
+            .links
+            .iter()
+            .find(|l| l.attribute("name") == Some(child_name))
+            .ok_or_else(|| format!("URDF link {} not found", child_name))?;
+        let (_, rotation) = origin(*joint);
+        let desc = parse_joint(*joint, rest);
+        parse_link(*child_link, Some((name, desc)), rest * rotation, ctx, parts)?;
+    }
+    Ok(())
+}
+
+fn parse_geom(shape: Node, rest: Quat, ctx: &Context) -> Option<GeomDesc> {
+    let (xyz, rotation) = origin(shape);
+    let geometry = child(shape, "geometry")?.first_element_child()?;
+    // bevy cylinders and capsules are along Y, URDF ones along Z
+    let z_to_y = Quat::from_rotation_x(std::f32::consts::FRAC_PI_2);
+    let (shape, local) = match geometry.tag_name().name() {
+        "box" => match floats(geometry.attribute("size"))[..] {
+            [x, y, z] => (
+                ShapeDesc::RoundBox(BodySize {
+                    hw: x / 2.,
+                    hh: y / 2.,
+                    hl: z / 2.,
+                    br: 0.,
+                }),
+                Quat::IDENTITY,
+            ),
+            _ => return None,
+        },
+        "cylinder" => (
+            ShapeDesc::Cylinder {
+                half_height: floats(geometry.attribute("length")).first()? / 2.,
+                radius: *floats(geometry.attribute("radius")).first()?,
+            },
+            z_to_y,
+        ),
+        "sphere" => (
+            ShapeDesc::Sphere {
+                radius: *floats(geometry.attribute("radius")).first()?,
+            },
+            Quat::IDENTITY,
+        ),
+        "mesh" => {
+            let filename = geometry.attribute("filename")?;
+            let relative = match filename.strip_prefix("package://") {
+                Some(package_path) => package_path.split_once('/').map_or(package_path, |p| p.1),
+                None => filename,
+            };
+            let path = ctx.dir.join(relative);
+            if path.extension().map_or(true, |e| !e.eq_ignore_ascii_case("obj")) {
+                eprintln!("URDF mesh {} is not a Wavefront OBJ, skipped", filename);
+                return None;
+            }
+            if !path.is_file() {
+                eprintln!("URDF mesh {} not found at {:?}, skipped", filename, path);
+                return None;
+            }
+            (
+                ShapeDesc::Mesh {
+                    path: path.to_string_lossy().to_string(),
+                    scale: vec3(geometry.attribute("scale")).unwrap_or(Vec3::ONE).to_array(),
+                },
+                Quat::IDENTITY,
+            )
+        }
+        other => {
+            eprintln!("URDF geometry {} is not supported, skipped", other);
+            return None;
+        }
+    };
+    Some(GeomDesc {
+        shape,
+        translation: (rest * xyz).to_array(),
+        rotation: (rest * rotation * local).to_array(),
+    })
+}
+
+/// `rest` is the rest rotation of the parent link, the joint axis is given in the child frame.
+fn parse_joint(joint: Node, rest: Quat) -> JointDesc {
+    let (xyz, rotation) = origin(joint);
+    let axis = vec3(child(joint, "axis").and_then(|a| a.attribute("xyz"))).unwrap_or(Vec3::X);
+    let limit = child(joint, "limit");
+    let limit_value = |name: &str| {
+        limit
+            .and_then(|l| floats(l.attribute(name)).first().copied())
+    };
+    let effort = limit_value("effort");
+    let damping = child(joint, "dynamics")
+        .and_then(|d| floats(d.attribute("damping")).first().copied())
+        .unwrap_or(0.);
+    let (stiffness, damping, max_force) = match effort {
+        Some(effort) => (effort, 0.1 * effort, effort),
+        None => (0., damping, f32::MAX),
+    };
+    let limits = match (limit_value("lower"), limit_value("upper")) {
+        (Some(lower), Some(upper)) => Some([lower, upper]),
+        _ => None,
+    };
+    let joint_type = joint.attribute("type").unwrap_or("fixed");
+    let axes = match joint_type {
+        "revolute" | "continuous" => vec![AxisDesc {
+            axis: JointAxis::AngX,
+            limits: match joint_type {
+                "revolute" => limits.map(|l| l.map(f32::to_degrees)),
+                _ => None,
+            },
+            stiffness,
+            damping,
+            max_force,
+        }],
+        "prismatic" => vec![AxisDesc {
+            axis: JointAxis::X,
+            limits,
+            stiffness,
+            damping,
+            max_force,
+        }],
+        "fixed" => vec![],
+        other => {
+            eprintln!("URDF joint type {} is not supported, made fixed", other);
+            vec![]
+        }
+    };
+    JointDesc {
+        anchor1: (rest * xyz).to_array(),
+        anchor2: [0.; 3],
+        axis: (rest * rotation * axis).normalize().to_array(),
+        axes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    const MODEL: &str = r#"
+<robot name="arm">
+  <link name="base">
+    <inertial>
+      <mass value="2"/>
+      <inertia ixx="0.1" iyy="0.2" izz="0.3"/>
+    </inertial>
+    <collision><geometry><box size="0.2 0.4 0.6"/></geometry></collision>
+  </link>
+  <link name="upper">
+    <visual><geometry><cylinder length="0.5" radius="0.05"/></geometry></visual>
+    <collision><geometry><mesh filename="package://arm/meshes/upper.obj"/></geometry></collision>
+    <collision><geometry><mesh filename="meshes/upper.stl"/></geometry></collision>
+    <collision><geometry><mesh filename="meshes/missing.obj"/></geometry></collision>
+    <collision><geometry><sphere radius="0.05"/></geometry></collision>
+  </link>
+  <joint name="shoulder" type="revolute">
+    <parent link="base"/>
+    <child link="upper"/>
+    <origin xyz="0 0 0.3"/>
+    <axis xyz="0 1 0"/>
+    <limit lower="-1" upper="1" effort="50"/>
+  </joint>
+</robot>"#;
+
+    /// Parses `MODEL` next to an `upper.obj` and an `upper.stl`, the meshes are never read.
+    fn parse_model(test: &str) -> (SkeletonDesc, std::path::PathBuf) {
+        let dir = std::env::temp_dir().join(format!("urdf_{}_{}", test, std::process::id()));
+        std::fs::create_dir_all(dir.join("meshes")).unwrap();
+        std::fs::write(dir.join("meshes/upper.obj"), "").unwrap();
+        std::fs::write(dir.join("meshes/upper.stl"), "").unwrap();
+        let skeleton = parse(MODEL, &dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        (skeleton, dir)
+    }
+
+    #[test]
+    fn parses_links_into_parts() {
+        let (skeleton, _) = parse_model("links");
+        let names: Vec<&str> = skeleton.parts.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["base", "upper"]);
+        assert_eq!(skeleton.root().name, "base");
+
+        let base = &skeleton.parts[0];
+        assert_eq!(base.mass, 2.);
+        assert_eq!(base.density, None);
+        // Z-up inertia in the Y-up part frame
+        for (inertia, expected) in base.inertia.iter().zip([0.1, 0.3, 0.2]) {
+            assert_close(*inertia, expected);
+        }
+        match &base.geoms[0].shape {
+            ShapeDesc::RoundBox(size) => {
+                assert_close(size.hw, 0.1);
+                assert_close(size.hh, 0.2);
+                assert_close(size.hl, 0.3);
+            }
+            other => panic!("expected a box, got {:?}", other),
+        }
+        assert_eq!(skeleton.parts[1].density, Some(DEFAULT_DENSITY));
+    }
+
+    #[test]
+    fn skips_meshes_that_are_missing_or_not_obj() {
+        let (skeleton, dir) = parse_model("meshes");
+        // collisions win over visuals, only the OBJ mesh and the sphere remain
+        let geoms = &skeleton.parts[1].geoms;
+        assert_eq!(geoms.len(), 2);
+        match &geoms[0].shape {
+            ShapeDesc::Mesh { path, scale } => {
+                assert_eq!(Path::new(path), dir.join("meshes/upper.obj"));
+                assert_eq!(*scale, [1.; 3]);
+            }
+            other => panic!("expected a mesh, got {:?}", other),
+        }
+        assert!(matches!(geoms[1].shape, ShapeDesc::Sphere { .. }));
+    }
+
+    #[test]
+    fn parses_revolute_joints() {
+        let (skeleton, _) = parse_model("joints");
+        let joint = skeleton.parts[1].joint.as_ref().unwrap();
+        assert_close(joint.anchor1[1], 0.3);
+        assert_eq!(joint.axes.len(), 1);
+        let axis = &joint.axes[0];
+        assert_eq!(axis.axis, JointAxis::AngX);
+        let [lower, upper] = axis.limits.unwrap();
+        assert_close(lower, -1_f32.to_degrees());
+        assert_close(upper, 1_f32.to_degrees());
+        assert_eq!(axis.max_force, 50.);
+    }
+
+    #[test]
+    fn rotated_joint_origins_turn_the_child_frame() {
+        let model = r#"
+<robot name="arm">
+  <link name="base"><collision><geometry><sphere radius="0.1"/></geometry></collision></link>
+  <link name="upper"><collision><geometry><sphere radius="0.1"/></geometry></collision></link>
+  <link name="fore"><collision><geometry><sphere radius="0.1"/></geometry></collision></link>
+  <joint name="shoulder" type="revolute">
+    <parent link="base"/>
+    <child link="upper"/>
+    <origin xyz="0.1 0 0" rpy="0 0 1.5707963"/>
+    <axis xyz="1 0 0"/>
+  </joint>
+  <joint name="elbow" type="revolute">
+    <parent link="upper"/>
+    <child link="fore"/>
+    <origin xyz="0.1 0 0"/>
+    <axis xyz="1 0 0"/>
+  </joint>
+</robot>"#;
+        let skeleton = parse(model, Path::new(".")).unwrap();
+        // the shoulder origin is in the base frame, its axis in the upper frame turned by the yaw
+        let shoulder = skeleton.parts[1].joint.as_ref().unwrap();
+        assert_close(&shoulder.anchor1, &[0.1, 0., 0.]);
+        assert_close(&shoulder.axis, &[0., 0., -1.]);
+        // the elbow origin is in the turned upper frame
+        let elbow = skeleton.parts[2].joint.as_ref().unwrap();
+        assert_close(&elbow.anchor1, &[0., 0., -0.1]);
+        assert_close(&elbow.axis, &[0., 0., -1.]);
+    }
+
+    #[test]
+    fn rejects_links_without_geometry() {
+        let dir = Path::new(".");
+        assert!(parse("<robot/>", dir).is_err());
+        assert!(parse(r#"<robot><link name="a"/></robot>"#, dir).is_err());
+    }
+}
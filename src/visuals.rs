@@ -0,0 +1,125 @@
+//! Meshes and materials for the physics entities, only built with the `render` feature.
+use bevy::{prelude::*, render::mesh::PrimitiveTopology};
+
+use crate::{
+    ground::Ground,
+    humanoid::{load_obj_mesh, Humanoid, PartGeom},
+    skeleton::ShapeDesc,
+};
+
+pub fn ground_visuals_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    query: Query<(Entity, &Ground), Added<Ground>>,
+) {
+    for (entity, ground) in query.iter() {
+        let hs = ground.half_size;
+        commands
+            .entity(entity)
+            .insert(meshes.add(Mesh::from(shape::Box {
+                max_x: hs.x,
+                min_x: -hs.x,
+                max_y: hs.y,
+                min_y: -hs.y,
+                max_z: hs.z,
+                min_z: -hs.z,
+            })))
+            .insert(materials.add(Color::rgb(0.2, 0.4, 0.15).into()))
+            .insert_bundle(VisibilityBundle::default());
+    }
+}
+
+pub fn humanoid_visuals_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    humanoids: Query<&Humanoid, Added<Humanoid>>,
+    query: Query<(Entity, &PartGeom), Added<PartGeom>>,
+) {
+    for humanoid in humanoids.iter() {
+        for part in humanoid.parts.iter() {
+            commands
+                .entity(*part)
+                .insert_bundle(VisibilityBundle::default());
+        }
+    }
+    for (entity, geom) in query.iter() {
+        let [r, g, b, a] = geom.color;
+        commands
+            .entity(entity)
+            .insert(meshes.add(Mesh::from_shape(&geom.shape)))
+            .insert(materials.add(Color::rgba(r, g, b, a).into()))
+            .insert_bundle(VisibilityBundle::default());
+    }
+}
+
+trait BodyPartMesh {
+    fn from_shape(shape: &ShapeDesc) -> Self;
+}
+impl BodyPartMesh for Mesh {
+    fn from_shape(shape: &ShapeDesc) -> Self {
+        match shape {
+            ShapeDesc::RoundBox(hs) => Mesh::from(shape::Box {
+                max_x: hs.hw,
+                min_x: -hs.hw,
+                max_y: hs.hh,
+                min_y: -hs.hh,
+                max_z: hs.hl,
+                min_z: -hs.hl,
+            }),
+            ShapeDesc::Capsule {
+                half_height,
+                radius,
+            } => Mesh::from(shape::Capsule {
+                radius: *radius,
+                depth: 2. * half_height,
+                ..default()
+            }),
+            ShapeDesc::Sphere { radius } => Mesh::from(shape::Icosphere {
+                radius: *radius,
+                subdivisions: 3,
+            }),
+            ShapeDesc::Cylinder {
+                half_height,
+                radius,
+            } => get_cylinder_mesh(*half_height, *radius, 24),
+            ShapeDesc::Mesh { path, scale } => {
+                let (vertices, indices) = load_obj_mesh(path, Vec3::from(*scale));
+                get_triangle_mesh(&vertices, &indices)
+            }
+        }
+    }
+}
+
+/// Unindexed triangle list with flat normals.
+fn get_triangle_mesh(vertices: &[Vec3], indices: &[[u32; 3]]) -> Mesh {
+    let positions: Vec<[f32; 3]> = indices
+        .iter()
+        .flat_map(|t| t.map(|i| vertices[i as usize].to_array()))
+        .collect();
+    let uvs = vec![[0., 0.]; positions.len()];
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.compute_flat_normals();
+    mesh
+}
+
+fn get_cylinder_mesh(half_height: f32, radius: f32, sectors: u32) -> Mesh {
+    let mut vertices = vec![Vec3::new(0., half_height, 0.), Vec3::new(0., -half_height, 0.)];
+    let mut indices: Vec<[u32; 3]> = vec![];
+    for i in 0..sectors {
+        let angle = i as f32 / sectors as f32 * std::f32::consts::TAU;
+        let (x, z) = (radius * angle.cos(), radius * angle.sin());
+        vertices.push(Vec3::new(x, half_height, z));
+        vertices.push(Vec3::new(x, -half_height, z));
+        let (top, bottom) = (2 + 2 * i, 3 + 2 * i);
+        let (next_top, next_bottom) = (2 + 2 * ((i + 1) % sectors), 3 + 2 * ((i + 1) % sectors));
+        indices.push([0, next_top, top]);
+        indices.push([1, bottom, next_bottom]);
+        indices.push([top, next_top, bottom]);
+        indices.push([bottom, next_top, next_bottom]);
+    }
+    get_triangle_mesh(&vertices, &indices)
+}